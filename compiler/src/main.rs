@@ -1,17 +1,11 @@
 #[macro_use]
 extern crate log;
 
-use std::fs::File;
-use std::io::Read;
-use std::path::PathBuf;
-use std::str::FromStr;
-use std::sync::Arc;
+use std::path::Path;
 
 use clap::{App, Arg, ArgMatches};
 
-use parser::errors::ParserError;
-use parser::io::Reader;
-use parser::parsers::{MosfetFile, ParserContext};
+use parser::ModuleGraph;
 
 fn main() {
     configure_logger();
@@ -19,28 +13,30 @@ fn main() {
     // Start CLI.
     let matches = run_cli();
 
-    // Get input file content.
-    let (file_path, content) = match read_input_file(matches.value_of("INPUT").unwrap()) {
-        Some(v) => v,
-        None => return,
-    };
-
-    let file_path = Arc::new(file_path);
-    let content = Arc::new(content);
-    info!("Parsing {:?}", file_path);
+    // Load the input file and all the modules it imports.
+    let input_path = Path::new(matches.value_of("INPUT").unwrap());
+    info!("Parsing {:?}", input_path);
 
-    let mut reader = Reader::new(Some(file_path.clone()), content);
-    let _parsed_file = match MosfetFile::parse(&mut reader, &ParserContext::default()) {
+    let graph = match ModuleGraph::load(input_path) {
         Ok(v) => v,
         Err(e) => {
-            error!(
-                "The file at {:?} cannot be parsed\n{}",
-                file_path,
-                e.print_error(&reader)
-            );
+            error!("Cannot read the file at {:?}: {}", input_path, e);
             return;
         }
     };
+
+    for module in graph.modules() {
+        for message in module.messages() {
+            println!("{}", message.to_ansi_text());
+        }
+    }
+
+    if graph.has_errors() {
+        error!("The program at {:?} cannot be parsed", input_path);
+        return;
+    }
+
+    info!("Parsed {} modules", graph.modules().len());
 }
 
 fn configure_logger() {
@@ -63,28 +59,3 @@ fn run_cli() -> ArgMatches {
         )
         .get_matches()
 }
-
-fn read_input_file(path: &str) -> Option<(String, String)> {
-    let mut file = match File::open(path) {
-        Ok(v) => v,
-        Err(e) => {
-            error!("Cannot open the file at '{}': {}", path, e);
-            return None;
-        }
-    };
-    let mut buffer = String::new();
-
-    if let Err(e) = file.read_to_string(&mut buffer) {
-        error!("Cannot read the file at '{}': {}", path, e);
-        return None;
-    }
-
-    let file_path = PathBuf::from_str(path)
-        .unwrap()
-        .canonicalize()
-        .unwrap()
-        .to_str()
-        .unwrap()
-        .to_string();
-    Some((file_path, buffer))
-}
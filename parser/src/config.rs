@@ -3,6 +3,8 @@
 pub struct ParserIgnoreConfig {
     pub number_leading_zeroes: bool,
     pub number_trailing_zeroes: bool,
+    pub map_duplicated_keys: bool,
+    pub statement_without_effect: bool,
 }
 
 impl ParserIgnoreConfig {
@@ -13,6 +15,8 @@ impl ParserIgnoreConfig {
         ParserIgnoreConfig {
             number_leading_zeroes: false,
             number_trailing_zeroes: false,
+            map_duplicated_keys: false,
+            statement_without_effect: false,
         }
     }
 }
@@ -1,5 +1,9 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
 use doclog::Log;
 
+use crate::io::Span;
 use crate::ParserIgnoreConfig;
 
 /// The context of the parser that contains all contextual information of the parsing.
@@ -7,6 +11,8 @@ use crate::ParserIgnoreConfig;
 pub struct ParserContext {
     messages: Vec<Log>,
     ignore: ParserIgnoreConfig,
+    scopes: Vec<HashMap<String, Binding>>,
+    functions: Vec<FunctionFrame>,
 }
 
 impl ParserContext {
@@ -17,6 +23,8 @@ impl ParserContext {
         ParserContext {
             messages: Vec::new(),
             ignore,
+            scopes: vec![HashMap::new()],
+            functions: vec![FunctionFrame::default()],
         }
     }
 
@@ -30,11 +38,97 @@ impl ParserContext {
         &self.ignore
     }
 
+    /// Whether the parser is at the top level of the file, i.e. outside any block.
+    pub fn is_at_top_level(&self) -> bool {
+        self.scopes.len() == 1
+    }
+
+    /// Whether the parser is inside the body of a function or lambda.
+    pub fn is_in_function(&self) -> bool {
+        self.functions.len() > 1
+    }
+
+    /// The byte offset where the innermost function or lambda starts, if any.
+    pub fn function_start(&self) -> Option<usize> {
+        self.functions.last().unwrap().start_offset
+    }
+
+    /// Whether the parser is inside the body of a loop of the innermost function.
+    pub fn is_in_loop(&self) -> bool {
+        !self.functions.last().unwrap().loops.is_empty()
+    }
+
+    /// Whether `label` names a loop of the innermost function that contains
+    /// the current position.
+    pub fn is_loop_label_defined(&self, label: &str) -> bool {
+        self.functions
+            .last()
+            .unwrap()
+            .loops
+            .iter()
+            .any(|v| v.as_deref() == Some(label))
+    }
+
     // METHODS ----------------------------------------------------------------
 
     pub fn add_message(&mut self, log: Log) {
         self.messages.push(log);
     }
+
+    /// Removes the messages added after the first `length` ones, e.g. the ones reported
+    /// while looking ahead a node that is discarded.
+    pub fn truncate_messages(&mut self, length: usize) {
+        self.messages.truncate(length);
+    }
+
+    /// Opens a new nested scope, e.g. at the beginning of a block.
+    pub fn push_scope(&mut self) {
+        self.scopes.push(HashMap::new());
+    }
+
+    /// Closes the innermost scope discarding all its bindings.
+    /// The scope of the file is never closed.
+    pub fn pop_scope(&mut self) {
+        if self.scopes.len() > 1 {
+            self.scopes.pop();
+        }
+    }
+
+    /// Declares `name` in the innermost scope, shadowing any previous binding with the same name.
+    pub fn declare(&mut self, name: String, binding: Binding) {
+        self.scopes.last_mut().unwrap().insert(name, binding);
+    }
+
+    /// Marks the beginning of the body of a function or lambda that starts at `start_offset`.
+    /// The loops around it cannot be exited from inside the function.
+    pub fn enter_function(&mut self, start_offset: usize) {
+        self.functions.push(FunctionFrame {
+            start_offset: Some(start_offset),
+            loops: Vec::new(),
+        });
+    }
+
+    /// Marks the end of the body of a function or lambda.
+    pub fn exit_function(&mut self) {
+        if self.functions.len() > 1 {
+            self.functions.pop();
+        }
+    }
+
+    /// Marks the beginning of the body of a loop with an optional `label`.
+    pub fn enter_loop(&mut self, label: Option<String>) {
+        self.functions.last_mut().unwrap().loops.push(label);
+    }
+
+    /// Marks the end of the body of the innermost loop.
+    pub fn exit_loop(&mut self) {
+        self.functions.last_mut().unwrap().loops.pop();
+    }
+
+    /// Finds the binding of `name` starting from the innermost scope.
+    pub fn find_binding(&self, name: &str) -> Option<&Binding> {
+        self.scopes.iter().rev().find_map(|scope| scope.get(name))
+    }
 }
 
 impl Default for ParserContext {
@@ -42,3 +136,55 @@ impl Default for ParserContext {
         Self::new(ParserIgnoreConfig::default())
     }
 }
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+/// The file or a function being parsed with the labels of the loops that enclose
+/// the current position, from the outermost to the innermost.
+#[derive(Debug, Default)]
+struct FunctionFrame {
+    start_offset: Option<usize>,
+    loops: Vec<Option<String>>,
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+/// Whether a binding can be reassigned or not.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum Mutability {
+    Immutable,
+    Mutable,
+    /// Immutable and evaluated at compile time.
+    Constant,
+}
+
+/// A name declared in a scope, e.g. by a variable declaration.
+#[derive(Debug, Clone)]
+pub struct Binding {
+    span: Arc<Span>,
+    mutability: Mutability,
+}
+
+impl Binding {
+    // CONSTRUCTORS -----------------------------------------------------------
+
+    /// Builds a new `Binding` declared at `span`.
+    pub fn new(span: Arc<Span>, mutability: Mutability) -> Binding {
+        Binding { span, mutability }
+    }
+
+    // GETTERS ----------------------------------------------------------------
+
+    /// The span of the name in its declaration.
+    pub fn span(&self) -> &Arc<Span> {
+        &self.span
+    }
+
+    pub fn mutability(&self) -> Mutability {
+        self.mutability
+    }
+}
@@ -5,12 +5,114 @@ pub enum ParserError {
 
     NumberWithSeparatorAfterPrefix,
     NumberWithoutDigitsAfterPrefix,
+    NumberWithoutDigitsInExponent,
+    NumberWithExponentInWrongRadix,
+    NumberWithTooLargeExponent,
+    NumberWithUnknownSuffix,
+    NumberWithIntegerSuffixAndDecimals,
 
-    MissingNameInVariableDeclaration,
+    StringWithoutEndToken,
+    StringWithInvalidEscape,
+    StringWithInvalidUnicodeEscape,
+    StringInterpolationWithoutExpression,
+    StringInterpolationWithoutEndToken,
+
+    MissingExpressionInUnaryExpression,
+    MissingRightOperandInBinaryExpression,
+    MissingEndInInclusiveRange,
+
+    ParenthesisWithoutEndToken,
+    MissingCommaInTuple,
+
+    ListWithoutEndToken,
+    MissingCommaInList,
+
+    MapWithoutEndToken,
+    MissingCommaInMap,
+    MissingColonInMapEntry,
+    MissingValueInMapEntry,
+
+    ArgumentsWithoutEndToken,
+    MissingCommaInArguments,
+    MissingValueInNamedArgument,
+    PositionalArgumentAfterNamedArgument,
+    DuplicatedNamedArgument,
+
+    MissingMemberNameAfterDot,
+    MissingExpressionInIndex,
+    IndexWithoutEndToken,
+
+    ParametersWithoutEndToken,
+    MissingCommaInParameters,
+    MissingBodyInLambda,
+
+    BlockWithoutEndToken,
+
+    MissingConditionInIfExpression,
+    MissingBlockInIfExpression,
+    DanglingElse,
+
+    MissingSubjectInMatchExpression,
+    MissingArmsInMatchExpression,
+    MatchWithoutEndToken,
+    MissingCommaInMatch,
+    MissingArrowInMatchArm,
+    MissingExpressionInMatchArm,
+    MissingConditionInMatchGuard,
+
+    MissingEndInRangePattern,
+    MissingPatternInMapPatternEntry,
+    MisplacedRestPattern,
+    DuplicatedBindingInPattern,
+
+    GenericArgumentsWithoutEndToken,
+    MissingCommaInGenericArguments,
+    EmptyGenericArguments,
+    MissingReturnTypeInFunctionType,
+
+    MissingPatternInVariableDeclaration,
+    MissingTypeInVariableDeclaration,
     MissingAssignOperatorInVariableDeclaration,
     MissingExpressionInVariableDeclaration,
+    DuplicatedModifierInVariableDeclaration,
+    MisplacedModifierInVariableDeclaration,
+
+    InvalidAssignmentTarget,
+    MissingExpressionInAssignment,
+    ReassignmentOfImmutableVariable,
 
     MissingExpressionInReturnStatement,
+    ReturnOutsideFunction,
+
+    MissingNameInFunctionDeclaration,
+    MissingParametersInFunctionDeclaration,
+    MissingReturnTypeInFunctionDeclaration,
+    MissingBodyInFunctionDeclaration,
+    MissingTypeInParameter,
+    MissingDefaultValueInParameter,
+    RequiredParameterAfterDefaultParameter,
+    DuplicatedParameterInFunctionDeclaration,
+
+    MissingConditionInWhileStatement,
+    MissingPatternInForStatement,
+    MissingInKeywordInForStatement,
+    MissingIterableInForStatement,
+    MissingBlockInLoop,
+    LabelWithoutLoop,
+    BreakOutsideLoop,
+    ContinueOutsideLoop,
+    UndefinedLoopLabel,
+
+    MissingPathInImportStatement,
+    MissingNameInImportPath,
+    ImportItemsWithoutEndToken,
+    MissingCommaInImportItems,
+    EmptyImportItems,
+    MissingAliasInImportItem,
+    MisplacedImportStatement,
+    ModuleNotFound,
+    UnreadableModule,
+    ImportCycle,
 
     NotAMosfetFile,
     ExpectedEOFInFile,
@@ -3,6 +3,7 @@ use std::sync::Arc;
 pub use config::*;
 pub use context::*;
 pub use errors::*;
+pub use modules::*;
 pub use warnings::*;
 
 use crate::io::Span;
@@ -12,6 +13,7 @@ mod constants;
 mod context;
 mod errors;
 pub mod io;
+mod modules;
 pub mod parsers;
 #[cfg(test)]
 pub mod test;
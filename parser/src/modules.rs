@@ -0,0 +1,442 @@
+use std::collections::HashSet;
+use std::fs;
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use doclog::{Color, Log};
+
+use crate::context::ParserContext;
+use crate::io::Reader;
+use crate::parsers::file::MosfetFile;
+use crate::parsers::statements::{ImportPath, ImportStatement};
+use crate::parsers::utils::{generate_error_log, generate_source_code};
+use crate::{ParserError, ParserNode};
+
+/// The extension of Mosfet files.
+pub static MOSFET_FILE_EXTENSION: &str = "mos";
+
+/// A Mosfet file loaded from disk along with the messages reported while loading it.
+#[derive(Debug)]
+pub struct Module {
+    path: PathBuf,
+    reader: Reader,
+    context: ParserContext,
+    file: Option<MosfetFile>,
+    has_errors: bool,
+}
+
+impl Module {
+    // GETTERS ----------------------------------------------------------------
+
+    /// The canonical path of the file.
+    pub fn path(&self) -> &PathBuf {
+        &self.path
+    }
+
+    pub fn reader(&self) -> &Reader {
+        &self.reader
+    }
+
+    /// The errors and warnings reported in this file, including the errors of its
+    /// imports that cannot be loaded. The messages of the imported modules are kept
+    /// in those modules.
+    pub fn messages(&self) -> &Vec<Log> {
+        self.context.messages()
+    }
+
+    /// The parsed file or `None` if it contains syntax errors.
+    pub fn file(&self) -> &Option<MosfetFile> {
+        &self.file
+    }
+
+    pub fn has_errors(&self) -> bool {
+        self.has_errors
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+/// The modules of a program, loaded from its entry file following their imports.
+///
+/// Absolute imports are resolved from the directory of the entry file and relative
+/// ones from the directory of the file that contains them, e.g. `import a.b` loads
+/// `a/b.mos` and `import ..a` loads `a.mos` from the parent directory.
+#[derive(Debug)]
+pub struct ModuleGraph {
+    root_directory: PathBuf,
+    modules: Vec<Module>,
+}
+
+impl ModuleGraph {
+    // GETTERS ----------------------------------------------------------------
+
+    /// The directory of the entry file.
+    pub fn root_directory(&self) -> &PathBuf {
+        &self.root_directory
+    }
+
+    /// The loaded modules in the order they were found, starting with the entry file.
+    pub fn modules(&self) -> &Vec<Module> {
+        &self.modules
+    }
+
+    pub fn has_errors(&self) -> bool {
+        self.modules.iter().any(|v| v.has_errors)
+    }
+
+    // STATIC METHODS ---------------------------------------------------------
+
+    /// Loads the file at `entry_path` and, recursively, every module it imports.
+    /// It only fails if the entry file cannot be read, any other error is reported
+    /// in the module that contains the offending import.
+    pub fn load(entry_path: &Path) -> std::io::Result<ModuleGraph> {
+        let entry_path = entry_path.canonicalize()?;
+        let content = fs::read_to_string(&entry_path)?;
+        let root_directory = entry_path
+            .parent()
+            .map(|v| v.to_path_buf())
+            .unwrap_or_default();
+
+        let mut graph = ModuleGraph {
+            root_directory,
+            modules: Vec::new(),
+        };
+        graph.load_module(entry_path, content, &mut HashSet::new(), &mut Vec::new());
+
+        Ok(graph)
+    }
+
+    // METHODS ----------------------------------------------------------------
+
+    /// Parses a module and loads its imports. `loading` contains the modules whose
+    /// imports are being loaded, from the entry file to the importer of this one.
+    fn load_module(
+        &mut self,
+        path: PathBuf,
+        content: String,
+        loaded: &mut HashSet<PathBuf>,
+        loading: &mut Vec<PathBuf>,
+    ) {
+        let mut reader = Reader::new(
+            Some(Arc::new(path.to_string_lossy().to_string())),
+            Arc::new(content),
+        );
+        let mut context = ParserContext::default();
+        let file = MosfetFile::parse(&mut reader, &mut context).ok();
+
+        let imports: Vec<Arc<ImportStatement>> = match &file {
+            Some(file) => file.imports().into_iter().cloned().collect(),
+            None => Vec::new(),
+        };
+
+        let index = self.modules.len();
+        loaded.insert(path.clone());
+        self.modules.push(Module {
+            path: path.clone(),
+            reader,
+            context,
+            has_errors: file.is_none(),
+            file,
+        });
+
+        loading.push(path);
+
+        for import in imports {
+            self.load_import(index, &import, loaded, loading);
+        }
+
+        loading.pop();
+    }
+
+    /// Loads the module imported by `import` in the module at `index`.
+    fn load_import(
+        &mut self,
+        index: usize,
+        import: &ImportStatement,
+        loaded: &mut HashSet<PathBuf>,
+        loading: &mut Vec<PathBuf>,
+    ) {
+        let path = self.resolve(&self.modules[index].path, import.path());
+
+        let path = match path.canonicalize() {
+            Ok(v) => v,
+            Err(e) if e.kind() == ErrorKind::NotFound => {
+                self.report(
+                    index,
+                    import,
+                    ParserError::ModuleNotFound,
+                    format!("The module '{}' cannot be found", import.path().content()),
+                    format!("There is no file at '{}'", path.display()),
+                );
+                return;
+            }
+            Err(e) => {
+                self.report_unreadable(index, import, e);
+                return;
+            }
+        };
+
+        if let Some(position) = loading.iter().position(|v| v == &path) {
+            let cycle = loading[position..]
+                .iter()
+                .chain(std::iter::once(&path))
+                .map(|v| self.display_path(v))
+                .collect::<Vec<_>>()
+                .join(" -> ");
+
+            self.report(
+                index,
+                import,
+                ParserError::ImportCycle,
+                format!(
+                    "The import of '{}' creates a cycle",
+                    import.path().content()
+                ),
+                format!("This import closes the cycle: {}", cycle),
+            );
+            return;
+        }
+
+        if loaded.contains(&path) {
+            return;
+        }
+
+        match fs::read_to_string(&path) {
+            Ok(content) => self.load_module(path, content, loaded, loading),
+            Err(e) => self.report_unreadable(index, import, e),
+        }
+    }
+
+    /// Gets the file of the module imported from `importer` through `path`.
+    fn resolve(&self, importer: &Path, path: &ImportPath) -> PathBuf {
+        let mut result = if path.is_relative() {
+            let mut directory = importer
+                .parent()
+                .unwrap_or(&self.root_directory)
+                .to_path_buf();
+
+            // The first dot refers to the directory of the importer.
+            for _ in 1..path.leading_dots() {
+                directory.pop();
+            }
+
+            directory
+        } else {
+            self.root_directory.clone()
+        };
+
+        for segment in path.segments() {
+            result.push(segment.content());
+        }
+
+        result.set_extension(MOSFET_FILE_EXTENSION);
+        result
+    }
+
+    /// Formats `path` relative to the root directory if possible.
+    fn display_path(&self, path: &Path) -> String {
+        path.strip_prefix(&self.root_directory)
+            .unwrap_or(path)
+            .display()
+            .to_string()
+    }
+
+    fn report_unreadable(&mut self, index: usize, import: &ImportStatement, error: std::io::Error) {
+        self.report(
+            index,
+            import,
+            ParserError::UnreadableModule,
+            format!("The module '{}' cannot be read", import.path().content()),
+            format!("The file cannot be read: {}", error),
+        );
+    }
+
+    /// Reports an error in the module at `index` highlighting the path of `import`.
+    fn report(
+        &mut self,
+        index: usize,
+        import: &ImportStatement,
+        error: ParserError,
+        message: String,
+        hint: String,
+    ) {
+        let module = &mut self.modules[index];
+        let path_span = import.path().span();
+        let log = generate_error_log(error, message, |log| {
+            generate_source_code(log, &module.reader, |doc| {
+                doc.highlight_section(
+                    path_span.start_cursor().byte_offset()..path_span.end_cursor().byte_offset(),
+                    Some(Arc::new(hint)),
+                    Some(Color::Magenta),
+                )
+            })
+        });
+
+        module.context.add_message(log);
+        module.has_errors = true;
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use std::ops::Deref;
+
+    use crate::test::assert_error_message;
+
+    use super::*;
+
+    /// A temporary directory that is removed when dropped.
+    struct TempDirectory(PathBuf);
+
+    impl Deref for TempDirectory {
+        type Target = Path;
+
+        fn deref(&self) -> &Path {
+            &self.0
+        }
+    }
+
+    impl Drop for TempDirectory {
+        fn drop(&mut self) {
+            let _ = fs::remove_dir_all(&self.0);
+        }
+    }
+
+    /// Writes `files` into a new temporary directory named after `name`.
+    fn create_files(name: &str, files: &[(&str, &str)]) -> TempDirectory {
+        let directory = TempDirectory(std::env::temp_dir().join(format!(
+            "mosfet-modules-{}-{}",
+            name,
+            std::process::id()
+        )));
+        let _ = fs::remove_dir_all(&*directory);
+        fs::create_dir_all(&*directory).unwrap();
+
+        for (path, content) in files {
+            let path = directory.join(path);
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(path, content).unwrap();
+        }
+
+        directory
+    }
+
+    #[test]
+    fn test_load() {
+        let directory = create_files(
+            "load",
+            &[
+                ("main.mos", "import a.b\nimport shared.{x, y as z}"),
+                ("a/b.mos", "import .c\nimport ..shared"),
+                ("a/c.mos", "let c = 1"),
+                ("shared.mos", "let x = 1\nlet y = 2"),
+            ],
+        );
+
+        let graph =
+            ModuleGraph::load(&directory.join("main.mos")).expect("The loader must succeed");
+
+        assert!(!graph.has_errors(), "The graph must not have errors");
+        assert_eq!(
+            graph
+                .modules()
+                .iter()
+                .map(|v| graph.display_path(v.path()))
+                .collect::<Vec<_>>(),
+            vec!["main.mos", "a/b.mos", "a/c.mos", "shared.mos"],
+            "The modules are incorrect"
+        );
+    }
+
+    #[test]
+    fn test_load_err_missing_entry() {
+        let directory = create_files("missing-entry", &[]);
+
+        ModuleGraph::load(&directory.join("main.mos")).expect_err("The loader must not succeed");
+    }
+
+    #[test]
+    fn test_load_err_module_not_found() {
+        let directory = create_files(
+            "not-found",
+            &[("main.mos", "import a\nimport .b"), ("a.mos", "import c.d")],
+        );
+
+        let graph =
+            ModuleGraph::load(&directory.join("main.mos")).expect("The loader must succeed");
+        let modules = graph.modules();
+
+        assert!(graph.has_errors(), "The graph must have errors");
+        assert_eq!(modules.len(), 2, "The modules length is incorrect");
+
+        for (module, errors) in modules.iter().zip(&[1, 1]) {
+            assert_eq!(
+                module.messages().len(),
+                *errors,
+                "The messages length is incorrect"
+            );
+            assert_error_message(&module.messages()[0], ParserError::ModuleNotFound);
+        }
+    }
+
+    #[test]
+    fn test_load_err_import_cycle() {
+        let directory = create_files(
+            "cycle",
+            &[
+                ("main.mos", "import a"),
+                ("a.mos", "import b"),
+                ("b.mos", "import .main"),
+            ],
+        );
+
+        let graph =
+            ModuleGraph::load(&directory.join("main.mos")).expect("The loader must succeed");
+        let modules = graph.modules();
+
+        assert_eq!(modules.len(), 3, "The modules length is incorrect");
+        assert!(
+            !modules[0].has_errors(),
+            "The entry file must not have errors"
+        );
+        assert!(!modules[1].has_errors(), "The a file must not have errors");
+        assert_eq!(
+            modules[2].messages().len(),
+            1,
+            "The messages length is incorrect"
+        );
+        assert_error_message(&modules[2].messages()[0], ParserError::ImportCycle);
+    }
+
+    #[test]
+    fn test_load_err_in_imported_module() {
+        let directory = create_files(
+            "syntax",
+            &[
+                ("main.mos", "import a\nimport b"),
+                ("a.mos", "let = 1"),
+                ("b.mos", ""),
+            ],
+        );
+
+        let graph =
+            ModuleGraph::load(&directory.join("main.mos")).expect("The loader must succeed");
+        let modules = graph.modules();
+
+        assert_eq!(modules.len(), 3, "The modules length is incorrect");
+        assert!(
+            !modules[0].has_errors(),
+            "The entry file must not have errors"
+        );
+        assert!(modules[1].has_errors(), "The a file must have errors");
+        assert!(modules[1].file().is_none(), "The a file must not be parsed");
+        assert!(!modules[2].has_errors(), "The b file must not have errors");
+    }
+}
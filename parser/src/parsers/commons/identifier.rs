@@ -12,6 +12,11 @@ use crate::ParserNode;
 pub static HEAD_CHARS: [RangeInclusive<char>; 3] = ['A'..='Z', '_'..='_', 'a'..='z'];
 // FIXME(juliotpaez): use Unicode classifications.
 pub static BODY_CHARS: [RangeInclusive<char>; 4] = ['0'..='9', 'A'..='Z', '_'..='_', 'a'..='z'];
+/// The keywords that cannot be used as identifiers.
+pub static RESERVED_KEYWORDS: &[&str] = &[
+    "and", "as", "break", "const", "continue", "else", "false", "fn", "for", "if", "import", "in",
+    "let", "loop", "match", "mut", "nil", "not", "or", "return", "true", "while",
+];
 
 /// A valid name in the Mosfet language.
 #[derive(Debug)]
@@ -28,24 +33,23 @@ impl Identifier {
 
     // STATIC METHODS ---------------------------------------------------------
 
-    /// Parses an `Identifier`.
-    pub fn parse(reader: &mut Reader, _context: &mut ParserContext) -> ParserResult<Identifier> {
-        cursor_manager(reader, |reader, init_cursor| {
-            if let None = reader.read_one_of(&HEAD_CHARS) {
+    /// Parses an `Identifier` that is not a reserved keyword.
+    pub fn parse(reader: &mut Reader, context: &mut ParserContext) -> ParserResult<Identifier> {
+        cursor_manager(reader, |reader, _init_cursor| {
+            let identifier = Self::parse_name(reader, context)?;
+
+            if RESERVED_KEYWORDS.contains(&identifier.content()) {
                 return Err(ParserResultError::NotFound);
             }
 
-            reader.read_many_of(&BODY_CHARS);
-
-            let span = Arc::new(reader.substring_to_current(&init_cursor));
-            Ok(Identifier { span })
+            Ok(identifier)
         })
     }
 
     /// Parses a keyword.
     pub fn parse_keyword(reader: &mut Reader, _context: &mut ParserContext, keyword: &str) -> bool {
         let init_cursor = reader.save_cursor();
-        let id = match Identifier::parse(reader, _context) {
+        let id = match Identifier::parse_name(reader, _context) {
             Ok(v) => v,
             Err(_) => {
                 return false;
@@ -59,6 +63,20 @@ impl Identifier {
             false
         }
     }
+
+    /// Parses any name, including reserved keywords.
+    fn parse_name(reader: &mut Reader, _context: &mut ParserContext) -> ParserResult<Identifier> {
+        cursor_manager(reader, |reader, init_cursor| {
+            if let None = reader.read_one_of(&HEAD_CHARS) {
+                return Err(ParserResultError::NotFound);
+            }
+
+            reader.read_many_of(&BODY_CHARS);
+
+            let span = Arc::new(reader.substring_to_current(&init_cursor));
+            Ok(Identifier { span })
+        })
+    }
 }
 
 impl ParserNode for Identifier {
@@ -128,6 +146,30 @@ mod tests {
         assert_not_found(&context, &error, 0);
     }
 
+    #[test]
+    fn test_parse_err_statement_keyword() {
+        for text in &["let = 1", "return = 1"] {
+            let mut reader = Reader::from_str(text);
+            let mut context = ParserContext::default();
+            let error = Identifier::parse(&mut reader, &mut context)
+                .expect_err("The parser must not succeed");
+
+            assert_not_found(&context, &error, 0);
+        }
+    }
+
+    #[test]
+    fn test_parse_err_reserved_keyword() {
+        for keyword in RESERVED_KEYWORDS {
+            let mut reader = Reader::from_str(keyword);
+            let mut context = ParserContext::default();
+            let error = Identifier::parse(&mut reader, &mut context)
+                .expect_err("The parser must not succeed");
+
+            assert_not_found(&context, &error, 0);
+        }
+    }
+
     #[test]
     fn test_parse_keyword() {
         let mut reader = Reader::from_str("let me test it");
@@ -145,4 +187,13 @@ mod tests {
 
         assert_eq!(result, false, "The result is incorrect");
     }
+
+    #[test]
+    fn test_parse_keyword_reserved() {
+        let mut reader = Reader::from_str("true");
+        let mut context = ParserContext::default();
+        let result = Identifier::parse_keyword(&mut reader, &mut context, "true");
+
+        assert_eq!(result, true, "The result is incorrect");
+    }
 }
@@ -1,3 +1,4 @@
 pub mod comments;
 pub mod identifier;
+pub mod sequences;
 pub mod whitespaces;
@@ -0,0 +1,430 @@
+use std::sync::Arc;
+
+use doclog::Color;
+
+use crate::context::ParserContext;
+use crate::io::{Reader, Span};
+use crate::parsers::commons::whitespaces::Whitespace;
+use crate::parsers::result::ParserResult;
+use crate::parsers::utils::{cursor_manager, generate_error_log, generate_source_code};
+use crate::parsers::ParserResultError;
+use crate::{ParserError, ParserNode};
+
+pub static SEPARATOR_TOKEN: &str = ",";
+
+/// The tokens and errors that define a specific kind of `Sequence`.
+#[derive(Debug)]
+pub struct SequenceDefinition {
+    pub open_token: &'static str,
+    pub close_token: &'static str,
+    pub name: &'static str,
+    pub missing_separator_error: ParserError,
+    pub without_end_token_error: ParserError,
+}
+
+/// A list of comma-separated nodes enclosed between an open and a close token, e.g. `(a, b)`.
+/// A trailing comma is allowed and the elements can be split across lines.
+#[derive(Debug)]
+pub struct Sequence<T> {
+    span: Arc<Span>,
+    elements: Vec<SequenceElement<T>>,
+    has_trailing_separator: bool,
+    pre_close_token_whitespace: Arc<Whitespace>,
+}
+
+/// An element of a `Sequence` with its surrounding whitespaces.
+#[derive(Debug)]
+pub struct SequenceElement<T> {
+    node: Arc<T>,
+    pre_node_whitespace: Arc<Whitespace>,
+    post_node_whitespace: Arc<Whitespace>,
+}
+
+impl<T: ParserNode> Sequence<T> {
+    // GETTERS ----------------------------------------------------------------
+
+    pub fn elements(&self) -> &Vec<SequenceElement<T>> {
+        &self.elements
+    }
+
+    /// Whether the last element is followed by a comma or not.
+    pub fn has_trailing_separator(&self) -> bool {
+        self.has_trailing_separator
+    }
+
+    /// The whitespace between the last comma and the close token or, in empty sequences,
+    /// between the open and the close tokens.
+    pub fn pre_close_token_whitespace(&self) -> &Arc<Whitespace> {
+        &self.pre_close_token_whitespace
+    }
+
+    // STATIC METHODS ---------------------------------------------------------
+
+    /// Parses a `Sequence` using `parser` to read each of its elements.
+    pub fn parse<F>(
+        reader: &mut Reader,
+        context: &mut ParserContext,
+        definition: &SequenceDefinition,
+        parser: F,
+    ) -> ParserResult<Sequence<T>>
+    where
+        F: Fn(&mut Reader, &mut ParserContext) -> ParserResult<T>,
+    {
+        Self::parse_with_config(reader, context, definition, parser, true)
+    }
+
+    /// Parses a `Sequence` like `parse` but returning `NotFound` instead of reporting
+    /// an error when it is malformed, so that it can be used to look ahead.
+    /// Errors from `parser` are still propagated.
+    pub fn parse_without_errors<F>(
+        reader: &mut Reader,
+        context: &mut ParserContext,
+        definition: &SequenceDefinition,
+        parser: F,
+    ) -> ParserResult<Sequence<T>>
+    where
+        F: Fn(&mut Reader, &mut ParserContext) -> ParserResult<T>,
+    {
+        Self::parse_with_config(reader, context, definition, parser, false)
+    }
+
+    fn parse_with_config<F>(
+        reader: &mut Reader,
+        context: &mut ParserContext,
+        definition: &SequenceDefinition,
+        parser: F,
+        report_errors: bool,
+    ) -> ParserResult<Sequence<T>>
+    where
+        F: Fn(&mut Reader, &mut ParserContext) -> ParserResult<T>,
+    {
+        cursor_manager(reader, |reader, init_cursor| {
+            if !reader.read(definition.open_token) {
+                return Err(ParserResultError::NotFound);
+            }
+
+            let mut elements = Vec::new();
+            let mut has_trailing_separator = false;
+
+            loop {
+                let pre_node_cursor = reader.save_cursor();
+                let pre_node_whitespace = Whitespace::parse_multiline_or_default(reader, context);
+
+                let node = match parser(reader, context) {
+                    Ok(v) => v,
+                    Err(ParserResultError::NotFound) => {
+                        reader.restore(pre_node_cursor);
+                        break;
+                    }
+                    Err(ParserResultError::Error) => return Err(ParserResultError::Error),
+                };
+
+                let post_node_whitespace = Whitespace::parse_multiline_or_default(reader, context);
+
+                elements.push(SequenceElement {
+                    node: Arc::new(node),
+                    pre_node_whitespace: Arc::new(pre_node_whitespace),
+                    post_node_whitespace: Arc::new(post_node_whitespace),
+                });
+
+                if reader.read(SEPARATOR_TOKEN) {
+                    has_trailing_separator = true;
+                    continue;
+                }
+
+                has_trailing_separator = false;
+
+                if reader.continues_with(definition.close_token) {
+                    break;
+                }
+
+                // Check whether the next element exists to report a more accurate error.
+                // The messages of the discarded element are removed.
+                let separator_cursor = reader.save_cursor();
+                let messages_length = context.messages().len();
+                match parser(reader, context) {
+                    Ok(_) => {
+                        context.truncate_messages(messages_length);
+
+                        if !report_errors {
+                            return Err(ParserResultError::NotFound);
+                        }
+
+                        reader.restore(separator_cursor);
+                        context.add_message(generate_error_log(
+                            definition.missing_separator_error,
+                            format!(
+                                "The elements of a {} must be separated by commas",
+                                definition.name
+                            ),
+                            |log| {
+                                generate_source_code(log, reader, |doc| {
+                                    let last_element = elements.last().unwrap();
+                                    let node_span = last_element.node.span();
+                                    let separator_position = last_element
+                                        .post_node_whitespace
+                                        .span()
+                                        .start_cursor()
+                                        .byte_offset();
+
+                                    doc.highlight_section(
+                                        node_span.start_cursor().byte_offset()
+                                            ..node_span.end_cursor().byte_offset(),
+                                        None,
+                                        Some(Color::Magenta),
+                                    )
+                                    .highlight_cursor(
+                                        separator_position,
+                                        Some(Arc::new(format!(
+                                            "Insert a comma '{}' here",
+                                            SEPARATOR_TOKEN
+                                        ))),
+                                        None,
+                                    )
+                                })
+                            },
+                        ));
+
+                        return Err(ParserResultError::Error);
+                    }
+                    Err(ParserResultError::NotFound) => break,
+                    Err(ParserResultError::Error) => return Err(ParserResultError::Error),
+                }
+            }
+
+            let pre_close_token_whitespace =
+                Whitespace::parse_multiline_or_default(reader, context);
+
+            if !reader.read(definition.close_token) {
+                if !report_errors {
+                    return Err(ParserResultError::NotFound);
+                }
+
+                context.add_message(generate_error_log(
+                    definition.without_end_token_error,
+                    format!(
+                        "The close token '{}' was expected to close the {}",
+                        definition.close_token, definition.name
+                    ),
+                    |log| {
+                        generate_source_code(log, reader, |doc| {
+                            doc.highlight_section(
+                                init_cursor.byte_offset()
+                                    ..init_cursor.byte_offset() + definition.open_token.len(),
+                                None,
+                                Some(Color::Magenta),
+                            )
+                            .highlight_cursor(
+                                reader.byte_offset(),
+                                Some(Arc::new(format!(
+                                    "Insert here the close token '{}'",
+                                    definition.close_token
+                                ))),
+                                None,
+                            )
+                        })
+                    },
+                ));
+
+                return Err(ParserResultError::Error);
+            }
+
+            let span = Arc::new(reader.substring_to_current(init_cursor));
+            Ok(Sequence {
+                span,
+                elements,
+                has_trailing_separator,
+                pre_close_token_whitespace: Arc::new(pre_close_token_whitespace),
+            })
+        })
+    }
+}
+
+impl<T> ParserNode for Sequence<T> {
+    fn span(&self) -> &Arc<Span> {
+        &self.span
+    }
+}
+
+impl<T> SequenceElement<T> {
+    // GETTERS ----------------------------------------------------------------
+
+    pub fn node(&self) -> &Arc<T> {
+        &self.node
+    }
+
+    pub fn pre_node_whitespace(&self) -> &Arc<Whitespace> {
+        &self.pre_node_whitespace
+    }
+
+    pub fn post_node_whitespace(&self) -> &Arc<Whitespace> {
+        &self.post_node_whitespace
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use crate::parsers::commons::identifier::Identifier;
+    use crate::test::{assert_error, assert_not_found};
+
+    use super::*;
+
+    static DEFINITION: SequenceDefinition = SequenceDefinition {
+        open_token: "(",
+        close_token: ")",
+        name: "tuple",
+        missing_separator_error: ParserError::MissingCommaInTuple,
+        without_end_token_error: ParserError::ParenthesisWithoutEndToken,
+    };
+
+    #[test]
+    fn test_parse() {
+        let mut reader = Reader::from_str("( a ,\n # comment\n b,c )");
+        let mut context = ParserContext::default();
+        let sequence = Sequence::parse(&mut reader, &mut context, &DEFINITION, Identifier::parse)
+            .expect("The parser must succeed");
+
+        assert_eq!(
+            sequence.content(),
+            "( a ,\n # comment\n b,c )",
+            "The content is incorrect"
+        );
+        assert_eq!(
+            sequence.elements.len(),
+            3,
+            "The elements length is incorrect"
+        );
+        assert!(
+            !sequence.has_trailing_separator,
+            "The has_trailing_separator is incorrect"
+        );
+
+        let element = &sequence.elements[0];
+        assert_eq!(element.node.content(), "a", "The node is incorrect");
+        assert_eq!(
+            element.pre_node_whitespace.content(),
+            " ",
+            "The pre_node_whitespace is incorrect"
+        );
+        assert_eq!(
+            element.post_node_whitespace.content(),
+            " ",
+            "The post_node_whitespace is incorrect"
+        );
+
+        let element = &sequence.elements[1];
+        assert_eq!(element.node.content(), "b", "The node is incorrect");
+        assert_eq!(
+            element.pre_node_whitespace.content(),
+            "\n # comment\n ",
+            "The pre_node_whitespace is incorrect"
+        );
+
+        let element = &sequence.elements[2];
+        assert_eq!(element.node.content(), "c", "The node is incorrect");
+        assert_eq!(
+            element.post_node_whitespace.content(),
+            " ",
+            "The post_node_whitespace is incorrect"
+        );
+        assert_eq!(
+            sequence.pre_close_token_whitespace.content(),
+            "",
+            "The pre_close_token_whitespace is incorrect"
+        );
+    }
+
+    #[test]
+    fn test_parse_trailing_separator() {
+        let mut reader = Reader::from_str("(a,\n  b,\n)");
+        let mut context = ParserContext::default();
+        let sequence = Sequence::parse(&mut reader, &mut context, &DEFINITION, Identifier::parse)
+            .expect("The parser must succeed");
+
+        assert_eq!(
+            sequence.elements.len(),
+            2,
+            "The elements length is incorrect"
+        );
+        assert!(
+            sequence.has_trailing_separator,
+            "The has_trailing_separator is incorrect"
+        );
+        assert_eq!(
+            sequence.pre_close_token_whitespace.content(),
+            "\n",
+            "The pre_close_token_whitespace is incorrect"
+        );
+    }
+
+    #[test]
+    fn test_parse_empty() {
+        let mut reader = Reader::from_str("(  )");
+        let mut context = ParserContext::default();
+        let sequence = Sequence::parse(&mut reader, &mut context, &DEFINITION, Identifier::parse)
+            .expect("The parser must succeed");
+
+        assert!(
+            sequence.elements.is_empty(),
+            "The elements length is incorrect"
+        );
+        assert_eq!(
+            sequence.pre_close_token_whitespace.content(),
+            "  ",
+            "The pre_close_token_whitespace is incorrect"
+        );
+    }
+
+    #[test]
+    fn test_parse_without_errors() {
+        for text in &["(a b)", "(a, b", "(a, 1)"] {
+            let mut reader = Reader::from_str(text);
+            let mut context = ParserContext::default();
+            let error = Sequence::parse_without_errors(
+                &mut reader,
+                &mut context,
+                &DEFINITION,
+                Identifier::parse,
+            )
+            .expect_err("The parser must not succeed");
+
+            assert_not_found(&context, &error, 0);
+        }
+    }
+
+    #[test]
+    fn test_parse_err_not_found() {
+        let mut reader = Reader::from_str("[a]");
+        let mut context = ParserContext::default();
+        let error = Sequence::parse(&mut reader, &mut context, &DEFINITION, Identifier::parse)
+            .expect_err("The parser must not succeed");
+
+        assert_not_found(&context, &error, 0);
+    }
+
+    #[test]
+    fn test_parse_err_missing_separator() {
+        let mut reader = Reader::from_str("(a b)");
+        let mut context = ParserContext::default();
+        let error = Sequence::parse(&mut reader, &mut context, &DEFINITION, Identifier::parse)
+            .expect_err("The parser must not succeed");
+
+        assert_error(&context, &error, ParserError::MissingCommaInTuple);
+    }
+
+    #[test]
+    fn test_parse_err_without_end_token() {
+        for text in &["(a, b", "(a, b\n", "(a,,b)", "(a ]"] {
+            let mut reader = Reader::from_str(text);
+            let mut context = ParserContext::default();
+            let error = Sequence::parse(&mut reader, &mut context, &DEFINITION, Identifier::parse)
+                .expect_err("The parser must not succeed");
+
+            assert_error(&context, &error, ParserError::ParenthesisWithoutEndToken);
+        }
+    }
+}
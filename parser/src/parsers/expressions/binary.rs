@@ -0,0 +1,465 @@
+use std::sync::Arc;
+
+use doclog::Color;
+
+use crate::context::ParserContext;
+use crate::io::{Reader, Span};
+use crate::parsers::commons::identifier::Identifier;
+use crate::parsers::commons::whitespaces::Whitespace;
+use crate::parsers::expressions::Expression;
+use crate::parsers::utils::{cursor_manager, generate_error_log, generate_source_code};
+use crate::parsers::{ParserResult, ParserResultError};
+use crate::{ParserError, ParserNode};
+
+static ASSIGN_TOKEN: &str = "=";
+static ARROW_TOKEN: &str = "->";
+
+/// The symbolic operators sorted so that longer tokens are read first.
+static SYMBOL_OPERATORS: &[(&str, BinaryOperator)] = &[
+    ("**", BinaryOperator::Power),
+    ("*", BinaryOperator::Multiply),
+    ("/", BinaryOperator::Divide),
+    ("%", BinaryOperator::Remainder),
+    ("+", BinaryOperator::Add),
+    ("-", BinaryOperator::Subtract),
+    ("<<", BinaryOperator::ShiftLeft),
+    (">>", BinaryOperator::ShiftRight),
+    ("<=", BinaryOperator::LessOrEqual),
+    (">=", BinaryOperator::GreaterOrEqual),
+    ("<", BinaryOperator::Less),
+    (">", BinaryOperator::Greater),
+    ("==", BinaryOperator::Equal),
+    ("!=", BinaryOperator::NotEqual),
+    ("&", BinaryOperator::BitAnd),
+    ("|", BinaryOperator::BitOr),
+    ("^", BinaryOperator::BitXor),
+];
+static KEYWORD_OPERATORS: &[(&str, BinaryOperator)] =
+    &[("and", BinaryOperator::And), ("or", BinaryOperator::Or)];
+
+/// The associativity of a `BinaryOperator`.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum Associativity {
+    Left,
+    Right,
+}
+
+/// The operators that can be used in a `BinaryExpression`.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum BinaryOperator {
+    // Arithmetic.
+    Add,
+    Subtract,
+    Multiply,
+    Divide,
+    Remainder,
+    Power,
+
+    // Comparison.
+    Equal,
+    NotEqual,
+    Less,
+    LessOrEqual,
+    Greater,
+    GreaterOrEqual,
+
+    // Logical.
+    And,
+    Or,
+
+    // Bitwise.
+    BitAnd,
+    BitOr,
+    BitXor,
+    ShiftLeft,
+    ShiftRight,
+}
+
+impl BinaryOperator {
+    // GETTERS ----------------------------------------------------------------
+
+    /// The operator as str.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            BinaryOperator::Add => "+",
+            BinaryOperator::Subtract => "-",
+            BinaryOperator::Multiply => "*",
+            BinaryOperator::Divide => "/",
+            BinaryOperator::Remainder => "%",
+            BinaryOperator::Power => "**",
+            BinaryOperator::Equal => "==",
+            BinaryOperator::NotEqual => "!=",
+            BinaryOperator::Less => "<",
+            BinaryOperator::LessOrEqual => "<=",
+            BinaryOperator::Greater => ">",
+            BinaryOperator::GreaterOrEqual => ">=",
+            BinaryOperator::And => "and",
+            BinaryOperator::Or => "or",
+            BinaryOperator::BitAnd => "&",
+            BinaryOperator::BitOr => "|",
+            BinaryOperator::BitXor => "^",
+            BinaryOperator::ShiftLeft => "<<",
+            BinaryOperator::ShiftRight => ">>",
+        }
+    }
+
+    /// The precedence of the operator. Higher values bind tighter.
+    ///
+    /// | Precedence | Operators                    | Associativity |
+    /// |------------|------------------------------|---------------|
+    /// | 10         | `**`                         | Right         |
+    /// | 9          | `*` `/` `%`                  | Left          |
+    /// | 8          | `+` `-`                      | Left          |
+    /// | 7          | `<<` `>>`                    | Left          |
+    /// | 6          | `&`                          | Left          |
+    /// | 5          | `^`                          | Left          |
+    /// | 4          | `\|`                         | Left          |
+    /// | 3          | `==` `!=` `<` `<=` `>` `>=`  | Left          |
+    /// | 2          | `and`                        | Left          |
+    /// | 1          | `or`                         | Left          |
+    ///
+    /// The prefix operators `-` and `~` bind between `**` and `*`, and `not` between
+    /// the comparisons and `and`. See `UnaryExpression`.
+    pub fn precedence(&self) -> u8 {
+        match self {
+            BinaryOperator::Power => 10,
+            BinaryOperator::Multiply | BinaryOperator::Divide | BinaryOperator::Remainder => 9,
+            BinaryOperator::Add | BinaryOperator::Subtract => 8,
+            BinaryOperator::ShiftLeft | BinaryOperator::ShiftRight => 7,
+            BinaryOperator::BitAnd => 6,
+            BinaryOperator::BitXor => 5,
+            BinaryOperator::BitOr => 4,
+            BinaryOperator::Equal
+            | BinaryOperator::NotEqual
+            | BinaryOperator::Less
+            | BinaryOperator::LessOrEqual
+            | BinaryOperator::Greater
+            | BinaryOperator::GreaterOrEqual => 3,
+            BinaryOperator::And => 2,
+            BinaryOperator::Or => 1,
+        }
+    }
+
+    /// The associativity of the operator.
+    pub fn associativity(&self) -> Associativity {
+        match self {
+            BinaryOperator::Power => Associativity::Right,
+            _ => Associativity::Left,
+        }
+    }
+
+    /// Whether the operator is a comparison one or not.
+    pub fn is_comparison(&self) -> bool {
+        self.precedence() == BinaryOperator::Equal.precedence()
+    }
+
+    // STATIC METHODS ---------------------------------------------------------
+
+    /// Parses a `BinaryOperator`.
+    pub fn parse(reader: &mut Reader, context: &mut ParserContext) -> ParserResult<BinaryOperator> {
+        cursor_manager(reader, |reader, _init_cursor| {
+            // Prevent reading arrows '->' as a subtraction.
+            if reader.continues_with(ARROW_TOKEN) {
+                return Err(ParserResultError::NotFound);
+            }
+
+            for (token, operator) in SYMBOL_OPERATORS {
+                if !reader.read(token) {
+                    continue;
+                }
+
+                // Prevent reading compound assignments, e.g. '+='.
+                if !operator.is_comparison() && reader.continues_with(ASSIGN_TOKEN) {
+                    return Err(ParserResultError::NotFound);
+                }
+
+                return Ok(*operator);
+            }
+
+            for (keyword, operator) in KEYWORD_OPERATORS {
+                if Identifier::parse_keyword(reader, context, keyword) {
+                    return Ok(*operator);
+                }
+            }
+
+            Err(ParserResultError::NotFound)
+        })
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+/// A binary operation between two expressions, e.g. `a + b`.
+///
+/// The operator must be placed in the same line as the left operand but the right operand
+/// can be placed in the next lines, so that a statement can continue across lines:
+///
+/// ```text
+/// let x = a +
+///     b
+/// ```
+#[derive(Debug)]
+pub struct BinaryExpression {
+    span: Arc<Span>,
+    left: Arc<Expression>,
+    operator: BinaryOperator,
+    operator_span: Arc<Span>,
+    right: Arc<Expression>,
+    pre_operator_whitespace: Arc<Whitespace>,
+    pre_right_whitespace: Arc<Whitespace>,
+}
+
+impl BinaryExpression {
+    // GETTERS ----------------------------------------------------------------
+
+    pub fn left(&self) -> &Arc<Expression> {
+        &self.left
+    }
+
+    pub fn operator(&self) -> &BinaryOperator {
+        &self.operator
+    }
+
+    pub fn operator_span(&self) -> &Arc<Span> {
+        &self.operator_span
+    }
+
+    pub fn right(&self) -> &Arc<Expression> {
+        &self.right
+    }
+
+    pub fn pre_operator_whitespace(&self) -> &Arc<Whitespace> {
+        &self.pre_operator_whitespace
+    }
+
+    pub fn pre_right_whitespace(&self) -> &Arc<Whitespace> {
+        &self.pre_right_whitespace
+    }
+
+    // STATIC METHODS ---------------------------------------------------------
+
+    /// Parses a chain of binary operations whose operators have, at least, `min_precedence`
+    /// using precedence climbing. If there is no operator, the operand is returned alone.
+    pub fn parse_with_precedence(
+        reader: &mut Reader,
+        context: &mut ParserContext,
+        min_precedence: u8,
+    ) -> ParserResult<Expression> {
+        cursor_manager(reader, |reader, init_cursor| {
+            let mut left =
+                Expression::parse_operand_with_precedence(reader, context, min_precedence)?;
+
+            loop {
+                let pre_operator_cursor = reader.save_cursor();
+                let pre_operator_whitespace = Whitespace::parse_inline_or_default(reader, context);
+
+                let operator_cursor = reader.save_cursor();
+                let operator = match BinaryOperator::parse(reader, context) {
+                    Ok(v) if v.precedence() >= min_precedence => v,
+                    Ok(_) | Err(ParserResultError::NotFound) => {
+                        reader.restore(pre_operator_cursor);
+                        break;
+                    }
+                    Err(ParserResultError::Error) => return Err(ParserResultError::Error),
+                };
+                let operator_span = Arc::new(reader.substring_to_current(&operator_cursor));
+
+                let pre_right_whitespace = Whitespace::parse_multiline_or_default(reader, context);
+
+                let next_min_precedence = match operator.associativity() {
+                    Associativity::Left => operator.precedence() + 1,
+                    Associativity::Right => operator.precedence(),
+                };
+
+                let right = match Self::parse_with_precedence(reader, context, next_min_precedence)
+                {
+                    Ok(v) => v,
+                    Err(ParserResultError::NotFound) => {
+                        context.add_message(generate_error_log(
+                            ParserError::MissingRightOperandInBinaryExpression,
+                            format!(
+                                "An expression was expected after the operator '{}'",
+                                operator.as_str()
+                            ),
+                            |log| {
+                                generate_source_code(log, reader, |doc| {
+                                    doc.highlight_section(
+                                        operator_span.start_cursor().byte_offset()
+                                            ..operator_span.end_cursor().byte_offset(),
+                                        None,
+                                        Some(Color::Magenta),
+                                    )
+                                    .highlight_cursor_str(
+                                        reader.byte_offset(),
+                                        Some("Insert an expression here"),
+                                        None,
+                                    )
+                                })
+                            },
+                        ));
+
+                        return Err(ParserResultError::Error);
+                    }
+                    Err(ParserResultError::Error) => return Err(ParserResultError::Error),
+                };
+
+                left = Expression::Binary(Arc::new(BinaryExpression {
+                    span: Arc::new(reader.substring_to_current(init_cursor)),
+                    left: Arc::new(left),
+                    operator,
+                    operator_span,
+                    right: Arc::new(right),
+                    pre_operator_whitespace: Arc::new(pre_operator_whitespace),
+                    pre_right_whitespace: Arc::new(pre_right_whitespace),
+                }));
+            }
+
+            Ok(left)
+        })
+    }
+}
+
+impl ParserNode for BinaryExpression {
+    fn span(&self) -> &Arc<Span> {
+        &self.span
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use crate::test::{assert_error, expression_to_string};
+
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let mut reader = Reader::from_str("a  +  b rest");
+        let mut context = ParserContext::default();
+        let expression = BinaryExpression::parse_with_precedence(&mut reader, &mut context, 0)
+            .expect("The parser must succeed");
+
+        if let Expression::Binary(binary) = expression {
+            assert_eq!(binary.content(), "a  +  b", "The content is incorrect");
+            assert_eq!(
+                binary.operator,
+                BinaryOperator::Add,
+                "The operator is incorrect"
+            );
+            assert_eq!(
+                binary.operator_span.content(),
+                "+",
+                "The operator_span is incorrect"
+            );
+            assert_eq!(binary.left.content(), "a", "The left operand is incorrect");
+            assert_eq!(
+                binary.right.content(),
+                "b",
+                "The right operand is incorrect"
+            );
+            assert_eq!(
+                binary.pre_operator_whitespace.content(),
+                "  ",
+                "The pre_operator_whitespace is incorrect"
+            );
+            assert_eq!(
+                binary.pre_right_whitespace.content(),
+                "  ",
+                "The pre_right_whitespace is incorrect"
+            );
+        } else {
+            panic!("The expression type is incorrect");
+        }
+    }
+
+    #[test]
+    fn test_parse_without_operator() {
+        let mut reader = Reader::from_str("a b");
+        let mut context = ParserContext::default();
+        let expression = BinaryExpression::parse_with_precedence(&mut reader, &mut context, 0)
+            .expect("The parser must succeed");
+
+        assert_eq!(expression.content(), "a", "The content is incorrect");
+        assert_eq!(reader.byte_offset(), 1, "The offset is incorrect");
+    }
+
+    #[test]
+    fn test_parse_precedence() {
+        for (text, result) in &[
+            ("1 + 2 * 3", "(1 + (2 * 3))"),
+            ("1 * 2 + 3", "((1 * 2) + 3)"),
+            ("1 - 2 - 3", "((1 - 2) - 3)"),
+            ("2 ** 3 ** 2", "(2 ** (3 ** 2))"),
+            ("a or b and c", "(a or (b and c))"),
+            ("a and b or c", "((a and b) or c)"),
+            ("a == b + 1 and c", "((a == (b + 1)) and c)"),
+            ("a | b ^ c & d", "(a | (b ^ (c & d)))"),
+            ("1 << 2 + 3", "(1 << (2 + 3))"),
+            ("a < b != c >= d", "(((a < b) != c) >= d)"),
+            ("a%b/c", "((a % b) / c)"),
+        ] {
+            let mut reader = Reader::from_str(text);
+            let mut context = ParserContext::default();
+            let expression = BinaryExpression::parse_with_precedence(&mut reader, &mut context, 0)
+                .expect("The parser must succeed");
+
+            assert_eq!(
+                expression_to_string(&expression),
+                *result,
+                "The expression is incorrect"
+            );
+        }
+    }
+
+    #[test]
+    fn test_parse_multiline() {
+        let mut reader = Reader::from_str("a +\n  b *\n\n  c\n+ d");
+        let mut context = ParserContext::default();
+        let expression = BinaryExpression::parse_with_precedence(&mut reader, &mut context, 0)
+            .expect("The parser must succeed");
+
+        assert_eq!(
+            expression_to_string(&expression),
+            "(a + (b * c))",
+            "The expression is incorrect"
+        );
+        assert_eq!(
+            expression.content(),
+            "a +\n  b *\n\n  c",
+            "The content is incorrect"
+        );
+    }
+
+    #[test]
+    fn test_parse_ignores_other_tokens() {
+        for text in &["a += 1", "a -> b", "a = b", "a **= b", "a <<= b", "a andy"] {
+            let mut reader = Reader::from_str(text);
+            let mut context = ParserContext::default();
+            let expression = BinaryExpression::parse_with_precedence(&mut reader, &mut context, 0)
+                .expect("The parser must succeed");
+
+            assert_eq!(expression.content(), "a", "The content is incorrect");
+        }
+    }
+
+    #[test]
+    fn test_parse_err_missing_right_operand() {
+        for text in &["a +", "a and", "a * \n  "] {
+            let mut reader = Reader::from_str(text);
+            let mut context = ParserContext::default();
+            let error = BinaryExpression::parse_with_precedence(&mut reader, &mut context, 0)
+                .expect_err("The parser must not succeed");
+
+            assert_error(
+                &context,
+                &error,
+                ParserError::MissingRightOperandInBinaryExpression,
+            );
+        }
+    }
+}
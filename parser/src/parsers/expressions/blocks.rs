@@ -0,0 +1,250 @@
+use std::sync::Arc;
+
+use doclog::Color;
+
+use crate::context::ParserContext;
+use crate::io::{Reader, Span};
+use crate::parsers::commons::whitespaces::Whitespace;
+use crate::parsers::expressions::Expression;
+use crate::parsers::statements::Statement;
+use crate::parsers::utils::{cursor_manager, generate_error_log, generate_source_code};
+use crate::parsers::{ParserResult, ParserResultError};
+use crate::{ParserError, ParserNode};
+
+pub static BLOCK_OPEN_TOKEN: &str = "{";
+pub static BLOCK_CLOSE_TOKEN: &str = "}";
+
+/// A list of statements enclosed between braces, e.g. `{ let a = 1 }`.
+/// Statements follow the same rules as in a file, i.e. they must be separated by new lines.
+///
+/// The block can end with an expression that is the value of the whole block,
+/// e.g. `{ let a = 1\n a + 1 }`, and it can be used anywhere an expression is accepted.
+/// `{}` is an empty map, not a block.
+#[derive(Debug)]
+pub struct Block {
+    span: Arc<Span>,
+    statements: Vec<Statement>,
+    expression: Option<Arc<Expression>>,
+}
+
+impl Block {
+    // GETTERS ----------------------------------------------------------------
+
+    pub fn statements(&self) -> &Vec<Statement> {
+        &self.statements
+    }
+
+    /// The last expression of the block that acts as its value.
+    pub fn expression(&self) -> &Option<Arc<Expression>> {
+        &self.expression
+    }
+
+    // STATIC METHODS ---------------------------------------------------------
+
+    /// Parses a `Block`.
+    pub fn parse(reader: &mut Reader, context: &mut ParserContext) -> ParserResult<Block> {
+        cursor_manager(reader, |reader, init_cursor| {
+            if !reader.read(BLOCK_OPEN_TOKEN) {
+                return Err(ParserResultError::NotFound);
+            }
+
+            context.push_scope();
+            let result = Statement::parse_list(reader, context);
+            context.pop_scope();
+            let (statements, expression) = result?;
+
+            if expression.is_some() {
+                let _ = Whitespace::parse_multiline_or_default(reader, context);
+            }
+
+            if !reader.read(BLOCK_CLOSE_TOKEN) {
+                context.add_message(generate_error_log(
+                    ParserError::BlockWithoutEndToken,
+                    format!(
+                        "The close token '{}' was expected to close the block",
+                        BLOCK_CLOSE_TOKEN
+                    ),
+                    |log| {
+                        generate_source_code(log, reader, |doc| {
+                            doc.highlight_section(
+                                init_cursor.byte_offset()
+                                    ..init_cursor.byte_offset() + BLOCK_OPEN_TOKEN.len(),
+                                None,
+                                Some(Color::Magenta),
+                            )
+                            .highlight_cursor(
+                                reader.byte_offset(),
+                                Some(Arc::new(format!(
+                                    "Insert here the close token '{}'",
+                                    BLOCK_CLOSE_TOKEN
+                                ))),
+                                None,
+                            )
+                        })
+                    },
+                ));
+
+                return Err(ParserResultError::Error);
+            }
+
+            let span = Arc::new(reader.substring_to_current(init_cursor));
+            Ok(Block {
+                span,
+                statements,
+                expression,
+            })
+        })
+    }
+}
+
+impl ParserNode for Block {
+    fn span(&self) -> &Arc<Span> {
+        &self.span
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use crate::parsers::file::MosfetFile;
+    use crate::test::{assert_error, assert_not_found};
+
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let mut reader = Reader::from_str("{\n  let a = 1\n  let b = a\n}/rest");
+        let mut context = ParserContext::default();
+        let block = Block::parse(&mut reader, &mut context).expect("The parser must succeed");
+
+        assert_eq!(
+            block.content(),
+            "{\n  let a = 1\n  let b = a\n}",
+            "The content is incorrect"
+        );
+        assert_eq!(
+            block.statements.len(),
+            2,
+            "The statements length is incorrect"
+        );
+    }
+
+    #[test]
+    fn test_parse_with_expression() {
+        for (text, statements) in &[("{ a + 1 }", 0), ("{\n  let a = 1\n  a + 1\n}", 1)] {
+            let mut reader = Reader::from_str(text);
+            let mut context = ParserContext::default();
+            let block = Block::parse(&mut reader, &mut context).expect("The parser must succeed");
+
+            assert_eq!(block.content(), *text, "The content is incorrect");
+            assert_eq!(
+                block.statements.len(),
+                *statements,
+                "The statements length is incorrect"
+            );
+            assert_eq!(
+                block.expression.as_ref().map(|v| v.content()),
+                Some("a + 1"),
+                "The expression is incorrect"
+            );
+        }
+    }
+
+    #[test]
+    fn test_parse_as_expression() {
+        for (text, is_block) in &[
+            ("{ a }", true),
+            ("{\n  let b = 1\n  b + 1\n}", true),
+            ("{ { a } }", true),
+            ("{ \"a\" }", true),
+            ("{ a: 1 }", false),
+            ("{ \"a\": 1 }", false),
+            ("{}", false),
+        ] {
+            let mut reader = Reader::from_str(text);
+            let mut context = ParserContext::default();
+            let expression =
+                Expression::parse(&mut reader, &mut context).expect("The parser must succeed");
+
+            assert_eq!(expression.content(), *text, "The content is incorrect");
+            assert_eq!(
+                matches!(expression, Expression::Block(_)),
+                *is_block,
+                "The expression type is incorrect for: {}",
+                text
+            );
+        }
+
+        let mut reader = Reader::from_str("let a = {\n  let b = 1\n  b * 2\n} + 1\nlet c = a");
+        let mut context = ParserContext::default();
+        let file = MosfetFile::parse(&mut reader, &mut context).expect("The parser must succeed");
+
+        assert_eq!(
+            file.statements().len(),
+            2,
+            "The statements length is incorrect"
+        );
+    }
+
+    #[test]
+    fn test_parse_nested() {
+        // Expressions must be parsed only once, otherwise this takes exponential time.
+        let text = format!("{}a{}", "{ ".repeat(64), " }".repeat(64));
+        let mut reader = Reader::from_str(&text);
+        let mut context = ParserContext::default();
+        let block = Block::parse(&mut reader, &mut context).expect("The parser must succeed");
+
+        assert_eq!(block.content(), text, "The content is incorrect");
+    }
+
+    #[test]
+    fn test_parse_empty() {
+        for text in &["{}", "{ \n }"] {
+            let mut reader = Reader::from_str(text);
+            let mut context = ParserContext::default();
+            let block = Block::parse(&mut reader, &mut context).expect("The parser must succeed");
+
+            assert!(
+                block.statements.is_empty(),
+                "The statements length is incorrect"
+            );
+        }
+    }
+
+    #[test]
+    fn test_parse_err_not_found() {
+        let mut reader = Reader::from_str("(a)");
+        let mut context = ParserContext::default();
+        let error =
+            Block::parse(&mut reader, &mut context).expect_err("The parser must not succeed");
+
+        assert_not_found(&context, &error, 0);
+    }
+
+    #[test]
+    fn test_parse_err_two_statements_same_line() {
+        for text in &["{ let a = 1 let b = a }", "{ let a = 1 a }"] {
+            let mut reader = Reader::from_str(text);
+            let mut context = ParserContext::default();
+            let error =
+                Block::parse(&mut reader, &mut context).expect_err("The parser must not succeed");
+
+            assert_error(&context, &error, ParserError::TwoStatementsInSameLineInFile);
+        }
+    }
+
+    #[test]
+    fn test_parse_err_without_end_token() {
+        for text in &["{", "{\n  let a = 1\n", "{ a ) }"] {
+            let mut reader = Reader::from_str(text);
+            let mut context = ParserContext::default();
+            let error =
+                Block::parse(&mut reader, &mut context).expect_err("The parser must not succeed");
+
+            assert_error(&context, &error, ParserError::BlockWithoutEndToken);
+        }
+    }
+}
@@ -0,0 +1,441 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use doclog::Color;
+
+use crate::context::ParserContext;
+use crate::io::{Reader, Span};
+use crate::parsers::commons::identifier::Identifier;
+use crate::parsers::commons::sequences::{Sequence, SequenceDefinition, SequenceElement};
+use crate::parsers::commons::whitespaces::Whitespace;
+use crate::parsers::expressions::Expression;
+use crate::parsers::utils::{cursor_manager, generate_error_log, generate_source_code};
+use crate::parsers::{ParserResult, ParserResultError};
+use crate::{ParserError, ParserNode};
+
+pub static ARGUMENTS_DEFINITION: SequenceDefinition = SequenceDefinition {
+    open_token: "(",
+    close_token: ")",
+    name: "argument list",
+    missing_separator_error: ParserError::MissingCommaInArguments,
+    without_end_token_error: ParserError::ArgumentsWithoutEndToken,
+};
+pub static NAMED_ARGUMENT_SEPARATOR: &str = ":";
+
+/// A call to a function, e.g. `print(x)` or `make(width: 3, height: 4)`.
+///
+/// The argument list must be placed just after the callee, without whitespaces.
+#[derive(Debug)]
+pub struct CallExpression {
+    span: Arc<Span>,
+    callee: Arc<Expression>,
+    arguments: Sequence<Argument>,
+}
+
+impl CallExpression {
+    // GETTERS ----------------------------------------------------------------
+
+    pub fn callee(&self) -> &Arc<Expression> {
+        &self.callee
+    }
+
+    /// The arguments of the call with their surrounding whitespaces.
+    pub fn arguments(&self) -> &Vec<SequenceElement<Argument>> {
+        self.arguments.elements()
+    }
+
+    pub fn argument_sequence(&self) -> &Sequence<Argument> {
+        &self.arguments
+    }
+
+    // STATIC METHODS ---------------------------------------------------------
+
+    /// Parses the argument list of a call to `callee`, which must be placed just before
+    /// the current position of the reader.
+    pub fn parse(
+        reader: &mut Reader,
+        context: &mut ParserContext,
+        callee: Expression,
+    ) -> ParserResult<CallExpression> {
+        let arguments = Self::parse_arguments(reader, context)?;
+
+        Ok(CallExpression {
+            span: Arc::new(reader.substring_to_current(callee.span().start_cursor())),
+            callee: Arc::new(callee),
+            arguments,
+        })
+    }
+
+    /// Parses an argument list checking that named arguments are placed after positional ones
+    /// and that they are not repeated.
+    pub fn parse_arguments(
+        reader: &mut Reader,
+        context: &mut ParserContext,
+    ) -> ParserResult<Sequence<Argument>> {
+        let arguments = Sequence::parse(reader, context, &ARGUMENTS_DEFINITION, Argument::parse)?;
+
+        let mut first_named: Option<&Arc<NamedArgument>> = None;
+        let mut names: HashMap<&str, &Arc<NamedArgument>> = HashMap::new();
+
+        for element in arguments.elements() {
+            match element.node().as_ref() {
+                Argument::Positional(positional) => {
+                    if let Some(named) = first_named {
+                        context.add_message(generate_error_log(
+                            ParserError::PositionalArgumentAfterNamedArgument,
+                            "Positional arguments must be placed before named arguments"
+                                .to_string(),
+                            |log| {
+                                generate_source_code(log, reader, |doc| {
+                                    doc.highlight_section_str(
+                                        named.span().start_cursor().byte_offset()
+                                            ..named.span().end_cursor().byte_offset(),
+                                        Some("First named argument"),
+                                        None,
+                                    )
+                                    .highlight_section_str(
+                                        positional.span().start_cursor().byte_offset()
+                                            ..positional.span().end_cursor().byte_offset(),
+                                        Some("Move this argument before the named ones"),
+                                        Some(Color::Magenta),
+                                    )
+                                })
+                            },
+                        ));
+
+                        return Err(ParserResultError::Error);
+                    }
+                }
+                Argument::Named(named) => {
+                    if first_named.is_none() {
+                        first_named = Some(named);
+                    }
+
+                    if let Some(previous) = names.insert(named.name.content(), named) {
+                        context.add_message(generate_error_log(
+                            ParserError::DuplicatedNamedArgument,
+                            format!(
+                                "The argument '{}' is specified more than once",
+                                named.name.content()
+                            ),
+                            |log| {
+                                generate_source_code(log, reader, |doc| {
+                                    doc.highlight_section_str(
+                                        previous.span().start_cursor().byte_offset()
+                                            ..previous.span().end_cursor().byte_offset(),
+                                        Some("First specified here"),
+                                        None,
+                                    )
+                                    .highlight_section_str(
+                                        named.span().start_cursor().byte_offset()
+                                            ..named.span().end_cursor().byte_offset(),
+                                        Some("Remove this argument"),
+                                        Some(Color::Magenta),
+                                    )
+                                })
+                            },
+                        ));
+
+                        return Err(ParserResultError::Error);
+                    }
+                }
+            }
+        }
+
+        Ok(arguments)
+    }
+}
+
+impl ParserNode for CallExpression {
+    fn span(&self) -> &Arc<Span> {
+        &self.span
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+/// An argument of a `CallExpression`.
+#[derive(Debug)]
+pub enum Argument {
+    Positional(Arc<Expression>),
+    Named(Arc<NamedArgument>),
+}
+
+impl Argument {
+    // STATIC METHODS ---------------------------------------------------------
+
+    /// Parses an `Argument`.
+    pub fn parse(reader: &mut Reader, context: &mut ParserContext) -> ParserResult<Argument> {
+        match NamedArgument::parse(reader, context) {
+            Ok(node) => return Ok(Argument::Named(Arc::new(node))),
+            Err(ParserResultError::NotFound) => { /* Ignore because not found */ }
+            Err(ParserResultError::Error) => return Err(ParserResultError::Error),
+        }
+
+        match Expression::parse(reader, context) {
+            Ok(node) => return Ok(Argument::Positional(Arc::new(node))),
+            Err(ParserResultError::NotFound) => { /* Ignore because not found */ }
+            Err(ParserResultError::Error) => return Err(ParserResultError::Error),
+        }
+
+        Err(ParserResultError::NotFound)
+    }
+}
+
+impl ParserNode for Argument {
+    fn span(&self) -> &Arc<Span> {
+        match self {
+            Argument::Positional(n) => n.span(),
+            Argument::Named(n) => n.span(),
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+/// An argument of a `CallExpression` that is bound to a parameter by its name,
+/// e.g. `width: 3`.
+#[derive(Debug)]
+pub struct NamedArgument {
+    span: Arc<Span>,
+    name: Arc<Identifier>,
+    value: Arc<Expression>,
+    pre_separator_whitespace: Arc<Whitespace>,
+    pre_value_whitespace: Arc<Whitespace>,
+}
+
+impl NamedArgument {
+    // GETTERS ----------------------------------------------------------------
+
+    pub fn name(&self) -> &Arc<Identifier> {
+        &self.name
+    }
+
+    pub fn value(&self) -> &Arc<Expression> {
+        &self.value
+    }
+
+    pub fn pre_separator_whitespace(&self) -> &Arc<Whitespace> {
+        &self.pre_separator_whitespace
+    }
+
+    pub fn pre_value_whitespace(&self) -> &Arc<Whitespace> {
+        &self.pre_value_whitespace
+    }
+
+    // STATIC METHODS ---------------------------------------------------------
+
+    /// Parses a `NamedArgument`.
+    pub fn parse(reader: &mut Reader, context: &mut ParserContext) -> ParserResult<NamedArgument> {
+        cursor_manager(reader, |reader, init_cursor| {
+            let name = Identifier::parse(reader, context)?;
+
+            let pre_separator_whitespace = Whitespace::parse_multiline_or_default(reader, context);
+
+            if !reader.read(NAMED_ARGUMENT_SEPARATOR) {
+                return Err(ParserResultError::NotFound);
+            }
+
+            let pre_value_whitespace = Whitespace::parse_multiline_or_default(reader, context);
+
+            let value = match Expression::parse(reader, context) {
+                Ok(v) => v,
+                Err(ParserResultError::NotFound) => {
+                    context.add_message(generate_error_log(
+                        ParserError::MissingValueInNamedArgument,
+                        format!(
+                            "An expression is expected as the value of the argument '{}'",
+                            name.content()
+                        ),
+                        |log| {
+                            generate_source_code(log, reader, |doc| {
+                                doc.highlight_section(
+                                    init_cursor.byte_offset()
+                                        ..pre_value_whitespace.span().start_cursor().byte_offset(),
+                                    None,
+                                    Some(Color::Magenta),
+                                )
+                                .highlight_cursor_str(
+                                    pre_value_whitespace.span().start_cursor().byte_offset(),
+                                    Some("Insert an expression here"),
+                                    None,
+                                )
+                            })
+                        },
+                    ));
+
+                    return Err(ParserResultError::Error);
+                }
+                Err(ParserResultError::Error) => return Err(ParserResultError::Error),
+            };
+
+            let span = Arc::new(reader.substring_to_current(init_cursor));
+            Ok(NamedArgument {
+                span,
+                name: Arc::new(name),
+                value: Arc::new(value),
+                pre_separator_whitespace: Arc::new(pre_separator_whitespace),
+                pre_value_whitespace: Arc::new(pre_value_whitespace),
+            })
+        })
+    }
+}
+
+impl ParserNode for NamedArgument {
+    fn span(&self) -> &Arc<Span> {
+        &self.span
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use crate::test::assert_error;
+
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let mut reader = Reader::from_str("make(1, \n  a + b, width: 3,height :4)/rest");
+        let mut context = ParserContext::default();
+        let expression =
+            Expression::parse_operand(&mut reader, &mut context).expect("The parser must succeed");
+
+        let call = match expression {
+            Expression::Call(v) => v,
+            _ => panic!("The expression type is incorrect"),
+        };
+
+        assert_eq!(
+            call.content(),
+            "make(1, \n  a + b, width: 3,height :4)",
+            "The content is incorrect"
+        );
+        assert_eq!(call.callee.content(), "make", "The callee is incorrect");
+
+        let arguments = call.arguments();
+        assert_eq!(arguments.len(), 4, "The arguments length is incorrect");
+
+        match arguments[1].node().as_ref() {
+            Argument::Positional(v) => {
+                assert_eq!(v.content(), "a + b", "The second argument is incorrect")
+            }
+            _ => panic!("The second argument must be positional"),
+        }
+        assert_eq!(
+            arguments[1].pre_node_whitespace().content(),
+            " \n  ",
+            "The second argument whitespace is incorrect"
+        );
+
+        match arguments[3].node().as_ref() {
+            Argument::Named(v) => {
+                assert_eq!(v.content(), "height :4", "The fourth argument is incorrect");
+                assert_eq!(v.name.content(), "height", "The name is incorrect");
+                assert_eq!(v.value.content(), "4", "The value is incorrect");
+                assert_eq!(
+                    v.pre_separator_whitespace.content(),
+                    " ",
+                    "The pre_separator_whitespace is incorrect"
+                );
+            }
+            _ => panic!("The fourth argument must be named"),
+        }
+    }
+
+    #[test]
+    fn test_parse_chained() {
+        let mut reader = Reader::from_str("f()(a)");
+        let mut context = ParserContext::default();
+        let expression =
+            Expression::parse_operand(&mut reader, &mut context).expect("The parser must succeed");
+
+        let call = match expression {
+            Expression::Call(v) => v,
+            _ => panic!("The expression type is incorrect"),
+        };
+
+        assert_eq!(call.content(), "f()(a)", "The content is incorrect");
+        assert_eq!(call.callee.content(), "f()", "The callee is incorrect");
+        assert_eq!(
+            call.arguments().len(),
+            1,
+            "The arguments length is incorrect"
+        );
+    }
+
+    #[test]
+    fn test_parse_requires_adjacent_arguments() {
+        for text in &["f (a)", "f\n(a)"] {
+            let mut reader = Reader::from_str(text);
+            let mut context = ParserContext::default();
+            let expression = Expression::parse_operand(&mut reader, &mut context)
+                .expect("The parser must succeed");
+
+            assert_eq!(expression.content(), "f", "The content is incorrect");
+        }
+    }
+
+    #[test]
+    fn test_parse_err_positional_after_named() {
+        let mut reader = Reader::from_str("f(a: 1, 2)");
+        let mut context = ParserContext::default();
+        let error = Expression::parse_operand(&mut reader, &mut context)
+            .expect_err("The parser must not succeed");
+
+        assert_error(
+            &context,
+            &error,
+            ParserError::PositionalArgumentAfterNamedArgument,
+        );
+    }
+
+    #[test]
+    fn test_parse_err_duplicated_named_argument() {
+        let mut reader = Reader::from_str("f(a: 1, b: 2, a: 3)");
+        let mut context = ParserContext::default();
+        let error = Expression::parse_operand(&mut reader, &mut context)
+            .expect_err("The parser must not succeed");
+
+        assert_error(&context, &error, ParserError::DuplicatedNamedArgument);
+    }
+
+    #[test]
+    fn test_parse_err_missing_value() {
+        let mut reader = Reader::from_str("f(a: )");
+        let mut context = ParserContext::default();
+        let error = Expression::parse_operand(&mut reader, &mut context)
+            .expect_err("The parser must not succeed");
+
+        assert_error(&context, &error, ParserError::MissingValueInNamedArgument);
+    }
+
+    #[test]
+    fn test_parse_err_missing_comma() {
+        let mut reader = Reader::from_str("f(a b)");
+        let mut context = ParserContext::default();
+        let error = Expression::parse_operand(&mut reader, &mut context)
+            .expect_err("The parser must not succeed");
+
+        assert_error(&context, &error, ParserError::MissingCommaInArguments);
+    }
+
+    #[test]
+    fn test_parse_err_without_end_token() {
+        for text in &["f(", "f(a, b", "f(a: 1"] {
+            let mut reader = Reader::from_str(text);
+            let mut context = ParserContext::default();
+            let error = Expression::parse_operand(&mut reader, &mut context)
+                .expect_err("The parser must not succeed");
+
+            assert_error(&context, &error, ParserError::ArgumentsWithoutEndToken);
+        }
+    }
+}
@@ -0,0 +1,394 @@
+use std::sync::Arc;
+
+use doclog::Color;
+
+use crate::context::ParserContext;
+use crate::io::{Cursor, Reader, Span};
+use crate::parsers::commons::identifier::Identifier;
+use crate::parsers::commons::whitespaces::Whitespace;
+use crate::parsers::expressions::blocks::{Block, BLOCK_OPEN_TOKEN};
+use crate::parsers::expressions::Expression;
+use crate::parsers::utils::{cursor_manager, generate_error_log, generate_source_code};
+use crate::parsers::{ParserResult, ParserResultError};
+use crate::{ParserError, ParserNode};
+
+pub static IF_KEYWORD: &str = "if";
+pub static ELSE_KEYWORD: &str = "else";
+
+/// A conditional expression, e.g. `if a { b } else if c { d } else { e }`.
+///
+/// The value of the expression is the value of the executed block.
+#[derive(Debug)]
+pub struct IfExpression {
+    span: Arc<Span>,
+    condition: Arc<Expression>,
+    then_block: Arc<Block>,
+    else_branch: Option<ElseBranch>,
+    pre_condition_whitespace: Arc<Whitespace>,
+    pre_then_block_whitespace: Arc<Whitespace>,
+    pre_else_whitespace: Option<Arc<Whitespace>>,
+    pre_else_branch_whitespace: Option<Arc<Whitespace>>,
+}
+
+impl IfExpression {
+    // GETTERS ----------------------------------------------------------------
+
+    pub fn condition(&self) -> &Arc<Expression> {
+        &self.condition
+    }
+
+    pub fn then_block(&self) -> &Arc<Block> {
+        &self.then_block
+    }
+
+    pub fn else_branch(&self) -> &Option<ElseBranch> {
+        &self.else_branch
+    }
+
+    pub fn pre_condition_whitespace(&self) -> &Arc<Whitespace> {
+        &self.pre_condition_whitespace
+    }
+
+    pub fn pre_then_block_whitespace(&self) -> &Arc<Whitespace> {
+        &self.pre_then_block_whitespace
+    }
+
+    pub fn pre_else_whitespace(&self) -> &Option<Arc<Whitespace>> {
+        &self.pre_else_whitespace
+    }
+
+    pub fn pre_else_branch_whitespace(&self) -> &Option<Arc<Whitespace>> {
+        &self.pre_else_branch_whitespace
+    }
+
+    // STATIC METHODS ---------------------------------------------------------
+
+    /// Parses an `IfExpression`.
+    pub fn parse(reader: &mut Reader, context: &mut ParserContext) -> ParserResult<IfExpression> {
+        cursor_manager(reader, |reader, init_cursor| {
+            if !Identifier::parse_keyword(reader, context, IF_KEYWORD) {
+                return Err(ParserResultError::NotFound);
+            }
+
+            let pre_condition_whitespace = Whitespace::parse_multiline_or_default(reader, context);
+
+            let condition = match Expression::parse_before_block(reader, context) {
+                Ok(v) => v,
+                Err(ParserResultError::NotFound) => {
+                    context.add_message(generate_error_log(
+                        ParserError::MissingConditionInIfExpression,
+                        "A condition is expected after the 'if' keyword".to_string(),
+                        |log| {
+                            generate_source_code(log, reader, |doc| {
+                                doc.highlight_section(
+                                    init_cursor.byte_offset()
+                                        ..pre_condition_whitespace
+                                            .span()
+                                            .start_cursor()
+                                            .byte_offset(),
+                                    None,
+                                    Some(Color::Magenta),
+                                )
+                                .highlight_cursor_str(
+                                    pre_condition_whitespace.span().start_cursor().byte_offset(),
+                                    Some("Insert an expression here"),
+                                    None,
+                                )
+                            })
+                        },
+                    ));
+
+                    return Err(ParserResultError::Error);
+                }
+                Err(ParserResultError::Error) => return Err(ParserResultError::Error),
+            };
+
+            let pre_then_block_whitespace = Whitespace::parse_multiline_or_default(reader, context);
+            let then_block = Self::parse_block(reader, context, init_cursor)?;
+
+            // Else branch.
+            let pre_else_cursor = reader.save_cursor();
+            let pre_else_whitespace = Whitespace::parse_multiline_or_default(reader, context);
+
+            if !Identifier::parse_keyword(reader, context, ELSE_KEYWORD) {
+                reader.restore(pre_else_cursor);
+
+                return Ok(IfExpression {
+                    span: Arc::new(reader.substring_to_current(init_cursor)),
+                    condition: Arc::new(condition),
+                    then_block: Arc::new(then_block),
+                    else_branch: None,
+                    pre_condition_whitespace: Arc::new(pre_condition_whitespace),
+                    pre_then_block_whitespace: Arc::new(pre_then_block_whitespace),
+                    pre_else_whitespace: None,
+                    pre_else_branch_whitespace: None,
+                });
+            }
+
+            let else_cursor = pre_else_whitespace.span().end_cursor().clone();
+            let pre_else_branch_whitespace =
+                Whitespace::parse_multiline_or_default(reader, context);
+
+            let else_branch = match IfExpression::parse(reader, context) {
+                Ok(v) => ElseBranch::If(Arc::new(v)),
+                Err(ParserResultError::NotFound) => {
+                    ElseBranch::Block(Arc::new(Self::parse_block(reader, context, &else_cursor)?))
+                }
+                Err(ParserResultError::Error) => return Err(ParserResultError::Error),
+            };
+
+            let span = Arc::new(reader.substring_to_current(init_cursor));
+            Ok(IfExpression {
+                span,
+                condition: Arc::new(condition),
+                then_block: Arc::new(then_block),
+                else_branch: Some(else_branch),
+                pre_condition_whitespace: Arc::new(pre_condition_whitespace),
+                pre_then_block_whitespace: Arc::new(pre_then_block_whitespace),
+                pre_else_whitespace: Some(Arc::new(pre_else_whitespace)),
+                pre_else_branch_whitespace: Some(Arc::new(pre_else_branch_whitespace)),
+            })
+        })
+    }
+
+    /// Parses a compulsory block reporting the error from `init_cursor`, i.e. the keyword
+    /// that requires it.
+    fn parse_block(
+        reader: &mut Reader,
+        context: &mut ParserContext,
+        init_cursor: &Cursor,
+    ) -> ParserResult<Block> {
+        match Block::parse(reader, context) {
+            Ok(v) => Ok(v),
+            Err(ParserResultError::NotFound) => {
+                context.add_message(generate_error_log(
+                    ParserError::MissingBlockInIfExpression,
+                    format!(
+                        "A block starting with '{}' is expected here",
+                        BLOCK_OPEN_TOKEN
+                    ),
+                    |log| {
+                        generate_source_code(log, reader, |doc| {
+                            doc.highlight_section(
+                                init_cursor.byte_offset()..reader.byte_offset(),
+                                None,
+                                Some(Color::Magenta),
+                            )
+                            .highlight_cursor_str(
+                                reader.byte_offset(),
+                                Some("Insert a block here"),
+                                None,
+                            )
+                        })
+                    },
+                ));
+
+                Err(ParserResultError::Error)
+            }
+            Err(ParserResultError::Error) => Err(ParserResultError::Error),
+        }
+    }
+
+    /// Reports an 'else' keyword that does not follow an `IfExpression`,
+    /// returning `NotFound` if there is no such keyword.
+    pub fn check_dangling_else(
+        reader: &mut Reader,
+        context: &mut ParserContext,
+    ) -> ParserResult<()> {
+        let init_cursor = reader.save_cursor();
+        if !Identifier::parse_keyword(reader, context, ELSE_KEYWORD) {
+            return Err(ParserResultError::NotFound);
+        }
+
+        context.add_message(generate_error_log(
+            ParserError::DanglingElse,
+            "This 'else' does not belong to any 'if' expression".to_string(),
+            |log| {
+                generate_source_code(log, reader, |doc| {
+                    doc.highlight_section_str(
+                        init_cursor.byte_offset()..reader.byte_offset(),
+                        Some("Remove this 'else' or place it just after the block of an 'if'"),
+                        Some(Color::Magenta),
+                    )
+                })
+            },
+        ));
+
+        Err(ParserResultError::Error)
+    }
+}
+
+impl ParserNode for IfExpression {
+    fn span(&self) -> &Arc<Span> {
+        &self.span
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+/// The branch executed when the condition of an `IfExpression` is false.
+#[derive(Debug)]
+pub enum ElseBranch {
+    Block(Arc<Block>),
+    If(Arc<IfExpression>),
+}
+
+impl ParserNode for ElseBranch {
+    fn span(&self) -> &Arc<Span> {
+        match self {
+            ElseBranch::Block(n) => n.span(),
+            ElseBranch::If(n) => n.span(),
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use crate::parsers::file::MosfetFile;
+    use crate::test::{assert_error, assert_not_found};
+
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let mut reader = Reader::from_str("if a > 1 { b }/rest");
+        let mut context = ParserContext::default();
+        let expression =
+            IfExpression::parse(&mut reader, &mut context).expect("The parser must succeed");
+
+        assert_eq!(
+            expression.content(),
+            "if a > 1 { b }",
+            "The content is incorrect"
+        );
+        assert_eq!(
+            expression.condition.content(),
+            "a > 1",
+            "The condition is incorrect"
+        );
+        assert_eq!(
+            expression.then_block.content(),
+            "{ b }",
+            "The then_block is incorrect"
+        );
+        assert!(
+            expression.else_branch.is_none(),
+            "The else_branch is incorrect"
+        );
+    }
+
+    #[test]
+    fn test_parse_else() {
+        let mut reader = Reader::from_str("if a {\n  b\n}\nelse {\n  c\n}");
+        let mut context = ParserContext::default();
+        let expression =
+            IfExpression::parse(&mut reader, &mut context).expect("The parser must succeed");
+
+        assert_eq!(
+            expression.content(),
+            "if a {\n  b\n}\nelse {\n  c\n}",
+            "The content is incorrect"
+        );
+
+        if let Some(ElseBranch::Block(block)) = &expression.else_branch {
+            assert_eq!(block.content(), "{\n  c\n}", "The else block is incorrect");
+        } else {
+            panic!("The else_branch type is incorrect");
+        }
+
+        assert_eq!(
+            expression.pre_else_whitespace.as_ref().map(|v| v.content()),
+            Some("\n"),
+            "The pre_else_whitespace is incorrect"
+        );
+    }
+
+    #[test]
+    fn test_parse_else_if() {
+        let mut reader = Reader::from_str("if a { 1 } else if b { 2 } else { 3 }");
+        let mut context = ParserContext::default();
+        let expression =
+            IfExpression::parse(&mut reader, &mut context).expect("The parser must succeed");
+
+        if let Some(ElseBranch::If(else_if)) = &expression.else_branch {
+            assert_eq!(
+                else_if.content(),
+                "if b { 2 } else { 3 }",
+                "The else if is incorrect"
+            );
+            assert!(
+                matches!(else_if.else_branch, Some(ElseBranch::Block(_))),
+                "The last else_branch type is incorrect"
+            );
+        } else {
+            panic!("The else_branch type is incorrect");
+        }
+    }
+
+    #[test]
+    fn test_parse_as_expression() {
+        let mut reader = Reader::from_str("let x = if a { 1 } else { 2 } + 3");
+        let mut context = ParserContext::default();
+        let file = MosfetFile::parse(&mut reader, &mut context).expect("The parser must succeed");
+
+        assert_eq!(
+            file.statements().len(),
+            1,
+            "The statements length is incorrect"
+        );
+    }
+
+    #[test]
+    fn test_parse_err_not_found() {
+        let mut reader = Reader::from_str("iff a { b }");
+        let mut context = ParserContext::default();
+        let error = IfExpression::parse(&mut reader, &mut context)
+            .expect_err("The parser must not succeed");
+
+        assert_not_found(&context, &error, 0);
+    }
+
+    #[test]
+    fn test_parse_err_missing_condition() {
+        let mut reader = Reader::from_str("if { b }");
+        let mut context = ParserContext::default();
+        let error = IfExpression::parse(&mut reader, &mut context)
+            .expect_err("The parser must not succeed");
+
+        assert_error(
+            &context,
+            &error,
+            ParserError::MissingConditionInIfExpression,
+        );
+    }
+
+    #[test]
+    fn test_parse_err_missing_block() {
+        for text in &["if a", "if a b", "if a { b } else", "if a { b } else c"] {
+            let mut reader = Reader::from_str(text);
+            let mut context = ParserContext::default();
+            let error = IfExpression::parse(&mut reader, &mut context)
+                .expect_err("The parser must not succeed");
+
+            assert_error(&context, &error, ParserError::MissingBlockInIfExpression);
+        }
+    }
+
+    #[test]
+    fn test_parse_err_dangling_else() {
+        for text in &["else { b }", "let a = 1\nelse { b }"] {
+            let mut reader = Reader::from_str(text);
+            let mut context = ParserContext::default();
+            let error = MosfetFile::parse(&mut reader, &mut context)
+                .expect_err("The parser must not succeed");
+
+            assert_error(&context, &error, ParserError::DanglingElse);
+        }
+    }
+}
@@ -0,0 +1,211 @@
+use std::sync::Arc;
+
+use doclog::Color;
+
+use crate::context::ParserContext;
+use crate::io::{Reader, Span};
+use crate::parsers::commons::whitespaces::Whitespace;
+use crate::parsers::expressions::Expression;
+use crate::parsers::utils::{generate_error_log, generate_source_code};
+use crate::parsers::{ParserResult, ParserResultError};
+use crate::{ParserError, ParserNode};
+
+pub static INDEX_OPEN_TOKEN: &str = "[";
+pub static INDEX_CLOSE_TOKEN: &str = "]";
+
+/// An access to an element of an expression by its index or key, e.g. `list[i]`.
+///
+/// The open bracket must be placed just after the indexed expression, without whitespaces.
+#[derive(Debug)]
+pub struct IndexExpression {
+    span: Arc<Span>,
+    object: Arc<Expression>,
+    index: Arc<Expression>,
+    pre_index_whitespace: Arc<Whitespace>,
+    post_index_whitespace: Arc<Whitespace>,
+}
+
+impl IndexExpression {
+    // GETTERS ----------------------------------------------------------------
+
+    pub fn object(&self) -> &Arc<Expression> {
+        &self.object
+    }
+
+    pub fn index(&self) -> &Arc<Expression> {
+        &self.index
+    }
+
+    pub fn pre_index_whitespace(&self) -> &Arc<Whitespace> {
+        &self.pre_index_whitespace
+    }
+
+    pub fn post_index_whitespace(&self) -> &Arc<Whitespace> {
+        &self.post_index_whitespace
+    }
+
+    // STATIC METHODS ---------------------------------------------------------
+
+    /// Parses an index over `object`, which must be placed just before the current position
+    /// of the reader.
+    pub fn parse(
+        reader: &mut Reader,
+        context: &mut ParserContext,
+        object: Expression,
+    ) -> ParserResult<IndexExpression> {
+        let open_cursor = reader.save_cursor();
+        if !reader.read(INDEX_OPEN_TOKEN) {
+            return Err(ParserResultError::NotFound);
+        }
+
+        let pre_index_whitespace = Whitespace::parse_multiline_or_default(reader, context);
+
+        let index = match Expression::parse(reader, context) {
+            Ok(v) => v,
+            Err(ParserResultError::NotFound) => {
+                context.add_message(generate_error_log(
+                    ParserError::MissingExpressionInIndex,
+                    "An expression is expected between the brackets of the index".to_string(),
+                    |log| {
+                        generate_source_code(log, reader, |doc| {
+                            doc.highlight_section(
+                                open_cursor.byte_offset()..reader.byte_offset(),
+                                None,
+                                Some(Color::Magenta),
+                            )
+                            .highlight_cursor_str(
+                                reader.byte_offset(),
+                                Some("Insert an expression here"),
+                                None,
+                            )
+                        })
+                    },
+                ));
+
+                return Err(ParserResultError::Error);
+            }
+            Err(ParserResultError::Error) => return Err(ParserResultError::Error),
+        };
+
+        let post_index_whitespace = Whitespace::parse_multiline_or_default(reader, context);
+
+        if !reader.read(INDEX_CLOSE_TOKEN) {
+            context.add_message(generate_error_log(
+                ParserError::IndexWithoutEndToken,
+                format!(
+                    "The close token '{}' was expected to close the index",
+                    INDEX_CLOSE_TOKEN
+                ),
+                |log| {
+                    generate_source_code(log, reader, |doc| {
+                        doc.highlight_section(
+                            open_cursor.byte_offset()
+                                ..open_cursor.byte_offset() + INDEX_OPEN_TOKEN.len(),
+                            None,
+                            Some(Color::Magenta),
+                        )
+                        .highlight_cursor(
+                            reader.byte_offset(),
+                            Some(Arc::new(format!(
+                                "Insert here the close token '{}'",
+                                INDEX_CLOSE_TOKEN
+                            ))),
+                            None,
+                        )
+                    })
+                },
+            ));
+
+            return Err(ParserResultError::Error);
+        }
+
+        Ok(IndexExpression {
+            span: Arc::new(reader.substring_to_current(object.span().start_cursor())),
+            object: Arc::new(object),
+            index: Arc::new(index),
+            pre_index_whitespace: Arc::new(pre_index_whitespace),
+            post_index_whitespace: Arc::new(post_index_whitespace),
+        })
+    }
+}
+
+impl ParserNode for IndexExpression {
+    fn span(&self) -> &Arc<Span> {
+        &self.span
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use crate::test::assert_error;
+
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let mut reader = Reader::from_str("list[\n  i + 1 ]/rest");
+        let mut context = ParserContext::default();
+        let expression =
+            Expression::parse_operand(&mut reader, &mut context).expect("The parser must succeed");
+
+        if let Expression::Index(index) = expression {
+            assert_eq!(
+                index.content(),
+                "list[\n  i + 1 ]",
+                "The content is incorrect"
+            );
+            assert_eq!(index.object.content(), "list", "The object is incorrect");
+            assert_eq!(index.index.content(), "i + 1", "The index is incorrect");
+            assert_eq!(
+                index.pre_index_whitespace.content(),
+                "\n  ",
+                "The pre_index_whitespace is incorrect"
+            );
+            assert_eq!(
+                index.post_index_whitespace.content(),
+                " ",
+                "The post_index_whitespace is incorrect"
+            );
+        } else {
+            panic!("The expression type is incorrect");
+        }
+    }
+
+    #[test]
+    fn test_parse_requires_adjacent_brackets() {
+        for text in &["a [0]", "a\n[0]"] {
+            let mut reader = Reader::from_str(text);
+            let mut context = ParserContext::default();
+            let expression = Expression::parse_operand(&mut reader, &mut context)
+                .expect("The parser must succeed");
+
+            assert_eq!(expression.content(), "a", "The content is incorrect");
+        }
+    }
+
+    #[test]
+    fn test_parse_err_missing_expression() {
+        let mut reader = Reader::from_str("a[ ]");
+        let mut context = ParserContext::default();
+        let error = Expression::parse_operand(&mut reader, &mut context)
+            .expect_err("The parser must not succeed");
+
+        assert_error(&context, &error, ParserError::MissingExpressionInIndex);
+    }
+
+    #[test]
+    fn test_parse_err_without_end_token() {
+        for text in &["a[0", "a[0, 1]"] {
+            let mut reader = Reader::from_str(text);
+            let mut context = ParserContext::default();
+            let error = Expression::parse_operand(&mut reader, &mut context)
+                .expect_err("The parser must not succeed");
+
+            assert_error(&context, &error, ParserError::IndexWithoutEndToken);
+        }
+    }
+}
@@ -0,0 +1,436 @@
+use std::sync::Arc;
+
+use doclog::Color;
+
+use crate::context::{Binding, Mutability, ParserContext};
+use crate::io::{Cursor, Reader, Span};
+use crate::parsers::commons::identifier::Identifier;
+use crate::parsers::commons::sequences::{Sequence, SequenceDefinition, SequenceElement};
+use crate::parsers::commons::whitespaces::Whitespace;
+use crate::parsers::expressions::blocks::{Block, BLOCK_OPEN_TOKEN};
+use crate::parsers::expressions::Expression;
+use crate::parsers::types::TypeExpression;
+use crate::parsers::utils::{cursor_manager, generate_error_log, generate_source_code};
+use crate::parsers::{ParserResult, ParserResultError};
+use crate::{ParserError, ParserNode};
+
+pub static PARAMETERS_DEFINITION: SequenceDefinition = SequenceDefinition {
+    open_token: "(",
+    close_token: ")",
+    name: "parameter list",
+    missing_separator_error: ParserError::MissingCommaInParameters,
+    without_end_token_error: ParserError::ParametersWithoutEndToken,
+};
+pub static ARROW_TOKEN: &str = "->";
+pub static TYPE_SEPARATOR: &str = ":";
+
+/// An anonymous function, e.g. `(a, b) -> a + b` or `(a: Int) -> { return a }`.
+///
+/// The parameter list is read without reporting errors until the arrow is found,
+/// so it can be told apart from tuples and parenthesized expressions by looking ahead
+/// only once. A body starting with a brace is always a block.
+#[derive(Debug)]
+pub struct LambdaExpression {
+    span: Arc<Span>,
+    parameters: Sequence<LambdaParameter>,
+    body: LambdaBody,
+    pre_arrow_whitespace: Arc<Whitespace>,
+    pre_body_whitespace: Arc<Whitespace>,
+}
+
+impl LambdaExpression {
+    // GETTERS ----------------------------------------------------------------
+
+    /// The parameters of the lambda with their surrounding whitespaces.
+    pub fn parameters(&self) -> &Vec<SequenceElement<LambdaParameter>> {
+        self.parameters.elements()
+    }
+
+    pub fn parameter_sequence(&self) -> &Sequence<LambdaParameter> {
+        &self.parameters
+    }
+
+    pub fn body(&self) -> &LambdaBody {
+        &self.body
+    }
+
+    pub fn pre_arrow_whitespace(&self) -> &Arc<Whitespace> {
+        &self.pre_arrow_whitespace
+    }
+
+    pub fn pre_body_whitespace(&self) -> &Arc<Whitespace> {
+        &self.pre_body_whitespace
+    }
+
+    // STATIC METHODS ---------------------------------------------------------
+
+    /// Parses a `LambdaExpression`.
+    pub fn parse(
+        reader: &mut Reader,
+        context: &mut ParserContext,
+    ) -> ParserResult<LambdaExpression> {
+        cursor_manager(reader, |reader, init_cursor| {
+            let parameters = Sequence::parse_without_errors(
+                reader,
+                context,
+                &PARAMETERS_DEFINITION,
+                LambdaParameter::parse,
+            )?;
+
+            let pre_arrow_whitespace = Whitespace::parse_inline_or_default(reader, context);
+
+            if !reader.read(ARROW_TOKEN) {
+                return Err(ParserResultError::NotFound);
+            }
+
+            let pre_body_whitespace = Whitespace::parse_multiline_or_default(reader, context);
+
+            // The parameters are only visible inside the body.
+            context.push_scope();
+
+            for parameter in parameters.elements() {
+                let name = parameter.node().name();
+                context.declare(
+                    name.content().to_string(),
+                    Binding::new(name.span().clone(), Mutability::Immutable),
+                );
+            }
+
+            context.enter_function(init_cursor.byte_offset());
+            let body = Self::parse_body(reader, context, init_cursor, &pre_body_whitespace);
+            context.exit_function();
+            context.pop_scope();
+            let body = body?;
+
+            let span = Arc::new(reader.substring_to_current(init_cursor));
+            Ok(LambdaExpression {
+                span,
+                parameters,
+                body,
+                pre_arrow_whitespace: Arc::new(pre_arrow_whitespace),
+                pre_body_whitespace: Arc::new(pre_body_whitespace),
+            })
+        })
+    }
+
+    /// Parses the body of the lambda, i.e. a block or an expression.
+    fn parse_body(
+        reader: &mut Reader,
+        context: &mut ParserContext,
+        init_cursor: &Cursor,
+        pre_body_whitespace: &Whitespace,
+    ) -> ParserResult<LambdaBody> {
+        if reader.continues_with(BLOCK_OPEN_TOKEN) {
+            Ok(LambdaBody::Block(Arc::new(Block::parse(reader, context)?)))
+        } else {
+            match Expression::parse(reader, context) {
+                Ok(v) => Ok(LambdaBody::Expression(Arc::new(v))),
+                Err(ParserResultError::NotFound) => {
+                    context.add_message(generate_error_log(
+                        ParserError::MissingBodyInLambda,
+                        "An expression or a block is expected as the body of the lambda"
+                            .to_string(),
+                        |log| {
+                            generate_source_code(log, reader, |doc| {
+                                doc.highlight_section(
+                                    init_cursor.byte_offset()
+                                        ..pre_body_whitespace.span().start_cursor().byte_offset(),
+                                    None,
+                                    Some(Color::Magenta),
+                                )
+                                .highlight_cursor_str(
+                                    pre_body_whitespace.span().start_cursor().byte_offset(),
+                                    Some("Insert the body here"),
+                                    None,
+                                )
+                            })
+                        },
+                    ));
+
+                    Err(ParserResultError::Error)
+                }
+                Err(ParserResultError::Error) => Err(ParserResultError::Error),
+            }
+        }
+    }
+}
+
+impl ParserNode for LambdaExpression {
+    fn span(&self) -> &Arc<Span> {
+        &self.span
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+/// The body of a `LambdaExpression`.
+#[derive(Debug)]
+pub enum LambdaBody {
+    Expression(Arc<Expression>),
+    Block(Arc<Block>),
+}
+
+impl ParserNode for LambdaBody {
+    fn span(&self) -> &Arc<Span> {
+        match self {
+            LambdaBody::Expression(n) => n.span(),
+            LambdaBody::Block(n) => n.span(),
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+/// A parameter of a `LambdaExpression` with an optional type, e.g. `a` or `a: Int`.
+#[derive(Debug)]
+pub struct LambdaParameter {
+    span: Arc<Span>,
+    name: Arc<Identifier>,
+    type_annotation: Option<Arc<TypeExpression>>,
+    pre_colon_whitespace: Option<Arc<Whitespace>>,
+    pre_type_whitespace: Option<Arc<Whitespace>>,
+}
+
+impl LambdaParameter {
+    // GETTERS ----------------------------------------------------------------
+
+    pub fn name(&self) -> &Arc<Identifier> {
+        &self.name
+    }
+
+    pub fn type_annotation(&self) -> &Option<Arc<TypeExpression>> {
+        &self.type_annotation
+    }
+
+    pub fn pre_colon_whitespace(&self) -> &Option<Arc<Whitespace>> {
+        &self.pre_colon_whitespace
+    }
+
+    pub fn pre_type_whitespace(&self) -> &Option<Arc<Whitespace>> {
+        &self.pre_type_whitespace
+    }
+
+    // STATIC METHODS ---------------------------------------------------------
+
+    /// Parses a `LambdaParameter`. It is used to look ahead, so it only reports errors
+    /// inside malformed types, which cannot appear in tuples or parenthesized expressions.
+    pub fn parse(
+        reader: &mut Reader,
+        context: &mut ParserContext,
+    ) -> ParserResult<LambdaParameter> {
+        cursor_manager(reader, |reader, init_cursor| {
+            let name = Identifier::parse(reader, context)?;
+
+            let pre_type_cursor = reader.save_cursor();
+            let pre_colon_whitespace = Whitespace::parse_multiline_or_default(reader, context);
+
+            if !reader.read(TYPE_SEPARATOR) {
+                reader.restore(pre_type_cursor);
+
+                return Ok(LambdaParameter {
+                    span: Arc::new(reader.substring_to_current(init_cursor)),
+                    name: Arc::new(name),
+                    type_annotation: None,
+                    pre_colon_whitespace: None,
+                    pre_type_whitespace: None,
+                });
+            }
+
+            let pre_type_whitespace = Whitespace::parse_multiline_or_default(reader, context);
+            let type_annotation = TypeExpression::parse(reader, context)?;
+
+            let span = Arc::new(reader.substring_to_current(init_cursor));
+            Ok(LambdaParameter {
+                span,
+                name: Arc::new(name),
+                type_annotation: Some(Arc::new(type_annotation)),
+                pre_colon_whitespace: Some(Arc::new(pre_colon_whitespace)),
+                pre_type_whitespace: Some(Arc::new(pre_type_whitespace)),
+            })
+        })
+    }
+}
+
+impl ParserNode for LambdaParameter {
+    fn span(&self) -> &Arc<Span> {
+        &self.span
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use crate::parsers::expressions::calls::Argument;
+    use crate::test::{assert_error, assert_not_found};
+
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let mut reader = Reader::from_str("(a, b: Int,) ->\n  a + b/rest");
+        let mut context = ParserContext::default();
+        let lambda =
+            LambdaExpression::parse(&mut reader, &mut context).expect("The parser must succeed");
+
+        assert_eq!(
+            lambda.content(),
+            "(a, b: Int,) ->\n  a + b/rest",
+            "The content is incorrect"
+        );
+
+        let parameters = lambda.parameters();
+        assert_eq!(parameters.len(), 2, "The parameters length is incorrect");
+        assert_eq!(
+            parameters[0].node().name.content(),
+            "a",
+            "The first parameter is incorrect"
+        );
+        assert!(
+            parameters[0].node().type_annotation.is_none(),
+            "The first parameter type is incorrect"
+        );
+        assert_eq!(
+            parameters[1].node().content(),
+            "b: Int",
+            "The second parameter is incorrect"
+        );
+        assert_eq!(
+            parameters[1]
+                .node()
+                .type_annotation
+                .as_ref()
+                .map(|v| v.content()),
+            Some("Int"),
+            "The second parameter type is incorrect"
+        );
+        assert_eq!(
+            lambda.pre_arrow_whitespace.content(),
+            " ",
+            "The pre_arrow_whitespace is incorrect"
+        );
+        assert_eq!(
+            lambda.pre_body_whitespace.content(),
+            "\n  ",
+            "The pre_body_whitespace is incorrect"
+        );
+
+        if let LambdaBody::Expression(body) = &lambda.body {
+            assert_eq!(body.content(), "a + b/rest", "The body is incorrect");
+        } else {
+            panic!("The body type is incorrect");
+        }
+    }
+
+    #[test]
+    fn test_parse_parameter_types() {
+        let mut reader = Reader::from_str("(f: (Int) -> Int, xs: List<Int>?) -> f");
+        let mut context = ParserContext::default();
+        let lambda =
+            LambdaExpression::parse(&mut reader, &mut context).expect("The parser must succeed");
+
+        assert_eq!(
+            lambda
+                .parameters()
+                .iter()
+                .map(|v| v.node().type_annotation.as_ref().map(|v| v.content()))
+                .collect::<Vec<_>>(),
+            vec![Some("(Int) -> Int"), Some("List<Int>?")],
+            "The parameter types are incorrect"
+        );
+    }
+
+    #[test]
+    fn test_parse_block_body() {
+        let mut reader = Reader::from_str("() -> {\n  let a = 1\n  return a\n}");
+        let mut context = ParserContext::default();
+        let lambda =
+            LambdaExpression::parse(&mut reader, &mut context).expect("The parser must succeed");
+
+        assert!(
+            lambda.parameters().is_empty(),
+            "The parameters length is incorrect"
+        );
+
+        if let LambdaBody::Block(body) = &lambda.body {
+            assert_eq!(
+                body.statements().len(),
+                2,
+                "The statements length is incorrect"
+            );
+        } else {
+            panic!("The body type is incorrect");
+        }
+    }
+
+    #[test]
+    fn test_parse_as_expression() {
+        for (text, content) in &[
+            ("(a) -> a", "(a) -> a"),
+            ("f((a) -> a)", "(a) -> a"),
+            ("((a) -> a)", "(a) -> a"),
+        ] {
+            let mut reader = Reader::from_str(text);
+            let mut context = ParserContext::default();
+            let expression =
+                Expression::parse(&mut reader, &mut context).expect("The parser must succeed");
+
+            let lambda = match &expression {
+                Expression::Lambda(v) => v.clone(),
+                Expression::Call(v) => match v.arguments()[0].node().as_ref() {
+                    Argument::Positional(v) => match v.as_ref() {
+                        Expression::Lambda(v) => v.clone(),
+                        _ => panic!("The argument type is incorrect"),
+                    },
+                    _ => panic!("The argument type is incorrect"),
+                },
+                Expression::Parenthesized(v) => match v.expression().as_ref() {
+                    Expression::Lambda(v) => v.clone(),
+                    _ => panic!("The inner expression type is incorrect"),
+                },
+                _ => panic!("The expression type is incorrect"),
+            };
+
+            assert_eq!(lambda.content(), *content, "The lambda is incorrect");
+        }
+    }
+
+    #[test]
+    fn test_parse_tuples_are_not_lambdas() {
+        for text in &["(a, b)", "(a)", "(a: Int)", "(a)\n-> b", "(a + b)"] {
+            let mut reader = Reader::from_str(text);
+            let mut context = ParserContext::default();
+            let error = LambdaExpression::parse(&mut reader, &mut context)
+                .expect_err("The parser must not succeed");
+
+            assert_not_found(&context, &error, 0);
+        }
+    }
+
+    #[test]
+    fn test_parse_deeply_nested_parentheses() {
+        let text = format!("{}a{}", "(".repeat(64), ")".repeat(64));
+        let mut reader = Reader::from_str(&text);
+        let mut context = ParserContext::default();
+        let expression =
+            Expression::parse(&mut reader, &mut context).expect("The parser must succeed");
+
+        assert_eq!(expression.content(), text, "The content is incorrect");
+    }
+
+    #[test]
+    fn test_parse_err_missing_body() {
+        let mut reader = Reader::from_str("(a) -> ");
+        let mut context = ParserContext::default();
+        let error = LambdaExpression::parse(&mut reader, &mut context)
+            .expect_err("The parser must not succeed");
+
+        assert_error(&context, &error, ParserError::MissingBodyInLambda);
+    }
+}
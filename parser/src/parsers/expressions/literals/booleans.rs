@@ -0,0 +1,94 @@
+use std::sync::Arc;
+
+use crate::context::ParserContext;
+use crate::io::{Reader, Span};
+use crate::parsers::commons::identifier::Identifier;
+use crate::parsers::utils::cursor_manager;
+use crate::parsers::{ParserResult, ParserResultError};
+use crate::ParserNode;
+
+pub static TRUE_KEYWORD: &str = "true";
+pub static FALSE_KEYWORD: &str = "false";
+
+/// A boolean literal in the Mosfet language, i.e. `true` or `false`.
+#[derive(Debug)]
+pub struct BooleanLiteral {
+    span: Arc<Span>,
+    value: bool,
+}
+
+impl BooleanLiteral {
+    // GETTERS ----------------------------------------------------------------
+
+    pub fn value(&self) -> bool {
+        self.value
+    }
+
+    // STATIC METHODS ---------------------------------------------------------
+
+    /// Parses a `BooleanLiteral`.
+    pub fn parse(reader: &mut Reader, context: &mut ParserContext) -> ParserResult<BooleanLiteral> {
+        cursor_manager(reader, |reader, init_cursor| {
+            let value = if Identifier::parse_keyword(reader, context, TRUE_KEYWORD) {
+                true
+            } else if Identifier::parse_keyword(reader, context, FALSE_KEYWORD) {
+                false
+            } else {
+                return Err(ParserResultError::NotFound);
+            };
+
+            Ok(BooleanLiteral {
+                span: Arc::new(reader.substring_to_current(init_cursor)),
+                value,
+            })
+        })
+    }
+}
+
+impl ParserNode for BooleanLiteral {
+    fn span(&self) -> &Arc<Span> {
+        &self.span
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use crate::test::assert_not_found;
+
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let mut reader = Reader::from_str("true/rest");
+        let mut context = ParserContext::default();
+        let literal =
+            BooleanLiteral::parse(&mut reader, &mut context).expect("The parser must succeed");
+
+        assert_eq!(literal.content(), "true", "The content is incorrect");
+        assert!(literal.value, "The value is incorrect");
+
+        let mut reader = Reader::from_str("false/rest");
+        let mut context = ParserContext::default();
+        let literal =
+            BooleanLiteral::parse(&mut reader, &mut context).expect("The parser must succeed");
+
+        assert_eq!(literal.content(), "false", "The content is incorrect");
+        assert!(!literal.value, "The value is incorrect");
+    }
+
+    #[test]
+    fn test_parse_err_not_found() {
+        for content in &["-", "truest", "false_", "True"] {
+            let mut reader = Reader::from_str(content);
+            let mut context = ParserContext::default();
+            let error = BooleanLiteral::parse(&mut reader, &mut context)
+                .expect_err("The parser must not succeed");
+
+            assert_not_found(&context, &error, 0);
+        }
+    }
+}
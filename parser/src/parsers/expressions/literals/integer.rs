@@ -2,6 +2,7 @@ use std::ops::RangeInclusive;
 use std::sync::Arc;
 
 use doclog::Color;
+use num_bigint::BigUint;
 
 use crate::context::ParserContext;
 use crate::io::{Reader, Span};
@@ -42,6 +43,16 @@ impl Radix {
         }
     }
 
+    /// The base of the radix, e.g. 16 for hexadecimal.
+    pub fn base(&self) -> u32 {
+        match self {
+            Radix::Binary => 2,
+            Radix::Octal => 8,
+            Radix::Decimal => 10,
+            Radix::Hexadecimal => 16,
+        }
+    }
+
     pub fn digit_chars(&self) -> &[RangeInclusive<char>] {
         match self {
             Radix::Binary => BINARY_DIGIT_CHARS,
@@ -91,6 +102,13 @@ impl IntegerNumber {
         &self.digits
     }
 
+    /// The value the number represents.
+    pub fn value(&self) -> BigUint {
+        let digits = remove_separators(self.digits.content());
+        BigUint::parse_bytes(digits.as_bytes(), self.radix.base())
+            .expect("The digits must be valid for the radix")
+    }
+
     // STATIC METHODS ---------------------------------------------------------
 
     /// Parses a prefixed `IntegerNumber` or a decimal without prefix.
@@ -366,6 +384,14 @@ impl ParserNode for IntegerNumber {
     }
 }
 
+/// Removes the separators from a sequence of digits, e.g. `1_000` -> `1000`.
+pub fn remove_separators(digits: &str) -> String {
+    digits
+        .chars()
+        .filter(|c| !SEPARATOR_RANGE.iter().any(|range| range.contains(c)))
+        .collect()
+}
+
 // ----------------------------------------------------------------------------
 // ----------------------------------------------------------------------------
 // ----------------------------------------------------------------------------
@@ -650,6 +676,42 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_value() {
+        for (text, value) in &[
+            ("0", 0u64),
+            ("1_000", 1000),
+            ("0b1010_1010", 0b1010_1010),
+            ("0o7_7", 0o77),
+            ("0d0_255", 255),
+            ("0xFF", 0xFF),
+            ("0xdead_BEEF", 0xDEAD_BEEF),
+        ] {
+            let mut reader = Reader::from_str(text);
+            let mut context = ParserContext::default();
+            let number =
+                IntegerNumber::parse(&mut reader, &mut context).expect("The parser must succeed");
+
+            assert_eq!(
+                number.value(),
+                BigUint::from(*value),
+                "The value is incorrect"
+            );
+        }
+
+        // Bigger than u64.
+        let mut reader = Reader::from_str("0x1_0000_0000_0000_0000");
+        let mut context = ParserContext::default();
+        let number =
+            IntegerNumber::parse(&mut reader, &mut context).expect("The parser must succeed");
+
+        assert_eq!(
+            number.value(),
+            BigUint::from(u64::MAX) + 1u32,
+            "The value is incorrect"
+        );
+    }
+
     #[test]
     fn test_number_with_separator_after_prefix() {
         for prefix in &[
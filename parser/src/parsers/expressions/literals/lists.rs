@@ -0,0 +1,163 @@
+use std::sync::Arc;
+
+use crate::context::ParserContext;
+use crate::io::{Reader, Span};
+use crate::parsers::commons::sequences::{Sequence, SequenceDefinition, SequenceElement};
+use crate::parsers::expressions::Expression;
+use crate::parsers::ParserResult;
+use crate::{ParserError, ParserNode};
+
+pub static LIST_DEFINITION: SequenceDefinition = SequenceDefinition {
+    open_token: "[",
+    close_token: "]",
+    name: "list",
+    missing_separator_error: ParserError::MissingCommaInList,
+    without_end_token_error: ParserError::ListWithoutEndToken,
+};
+
+/// A list of expressions, e.g. `[a, b, c]`.
+#[derive(Debug)]
+pub struct ListLiteral {
+    sequence: Sequence<Expression>,
+}
+
+impl ListLiteral {
+    // GETTERS ----------------------------------------------------------------
+
+    /// The elements of the list with their surrounding whitespaces.
+    pub fn elements(&self) -> &Vec<SequenceElement<Expression>> {
+        self.sequence.elements()
+    }
+
+    pub fn sequence(&self) -> &Sequence<Expression> {
+        &self.sequence
+    }
+
+    // STATIC METHODS ---------------------------------------------------------
+
+    /// Parses a `ListLiteral`.
+    pub fn parse(reader: &mut Reader, context: &mut ParserContext) -> ParserResult<ListLiteral> {
+        let sequence = Sequence::parse(reader, context, &LIST_DEFINITION, Expression::parse)?;
+
+        Ok(ListLiteral { sequence })
+    }
+}
+
+impl ParserNode for ListLiteral {
+    fn span(&self) -> &Arc<Span> {
+        self.sequence.span()
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use crate::test::{assert_error, assert_not_found};
+
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let mut reader = Reader::from_str("[\n  1, # first\n  a + b,\n  \"c\",\n]/rest");
+        let mut context = ParserContext::default();
+        let literal =
+            ListLiteral::parse(&mut reader, &mut context).expect("The parser must succeed");
+
+        assert_eq!(
+            literal.content(),
+            "[\n  1, # first\n  a + b,\n  \"c\",\n]",
+            "The content is incorrect"
+        );
+
+        let elements = literal.elements();
+        assert_eq!(elements.len(), 3, "The elements length is incorrect");
+        assert_eq!(
+            elements[0].node().content(),
+            "1",
+            "The first element is incorrect"
+        );
+        assert_eq!(
+            elements[1].node().content(),
+            "a + b",
+            "The second element is incorrect"
+        );
+        assert_eq!(
+            elements[1].pre_node_whitespace().content(),
+            " # first\n  ",
+            "The second element whitespace is incorrect"
+        );
+        assert_eq!(
+            elements[2].node().content(),
+            "\"c\"",
+            "The third element is incorrect"
+        );
+        assert!(
+            literal.sequence.has_trailing_separator(),
+            "The has_trailing_separator is incorrect"
+        );
+    }
+
+    #[test]
+    fn test_parse_empty() {
+        let mut reader = Reader::from_str("[]");
+        let mut context = ParserContext::default();
+        let literal =
+            ListLiteral::parse(&mut reader, &mut context).expect("The parser must succeed");
+
+        assert!(
+            literal.elements().is_empty(),
+            "The elements length is incorrect"
+        );
+    }
+
+    #[test]
+    fn test_parse_nested() {
+        let mut reader = Reader::from_str("[[1, 2], [], [(3)]]");
+        let mut context = ParserContext::default();
+        let literal =
+            ListLiteral::parse(&mut reader, &mut context).expect("The parser must succeed");
+
+        assert_eq!(
+            literal.elements().len(),
+            3,
+            "The elements length is incorrect"
+        );
+    }
+
+    #[test]
+    fn test_parse_err_not_found() {
+        let mut reader = Reader::from_str("(1, 2)");
+        let mut context = ParserContext::default();
+        let error =
+            ListLiteral::parse(&mut reader, &mut context).expect_err("The parser must not succeed");
+
+        assert_not_found(&context, &error, 0);
+    }
+
+    #[test]
+    fn test_parse_err_missing_comma() {
+        for text in &["[1, 2\n 3]", "[a {x: 1, x: 2}]"] {
+            let mut reader = Reader::from_str(text);
+            let mut context = ParserContext::default();
+            let error = ListLiteral::parse(&mut reader, &mut context)
+                .expect_err("The parser must not succeed");
+
+            assert_error(&context, &error, ParserError::MissingCommaInList);
+        }
+    }
+
+    #[test]
+    fn test_parse_err_without_end_token() {
+        for text in &["[", "[1, 2", "[1, 2)"] {
+            let mut reader = Reader::from_str(text);
+            let mut context = ParserContext::default();
+            let error = ListLiteral::parse(&mut reader, &mut context)
+                .expect_err("The parser must not succeed");
+
+            assert_error(&context, &error, ParserError::ListWithoutEndToken);
+        }
+    }
+}
@@ -0,0 +1,481 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use doclog::Color;
+
+use crate::context::ParserContext;
+use crate::io::{Reader, Span};
+use crate::parsers::commons::identifier::Identifier;
+use crate::parsers::commons::sequences::{Sequence, SequenceDefinition, SequenceElement};
+use crate::parsers::commons::whitespaces::Whitespace;
+use crate::parsers::expressions::literals::StringLiteral;
+use crate::parsers::expressions::Expression;
+use crate::parsers::utils::{
+    cursor_manager, generate_error_log, generate_source_code, generate_warning_log,
+};
+use crate::parsers::{ParserResult, ParserResultError};
+use crate::{ParserError, ParserNode, ParserWarning};
+
+pub static MAP_DEFINITION: SequenceDefinition = SequenceDefinition {
+    open_token: "{",
+    close_token: "}",
+    name: "map",
+    missing_separator_error: ParserError::MissingCommaInMap,
+    without_end_token_error: ParserError::MapWithoutEndToken,
+};
+pub static KEY_VALUE_SEPARATOR: &str = ":";
+
+/// A map of keys to expressions, e.g. `{ a: 1, "b c": 2 }`.
+///
+/// Braces are only a map when empty or when the first element is a key followed by
+/// a colon, otherwise they are a `Block`.
+#[derive(Debug)]
+pub struct MapLiteral {
+    sequence: Sequence<MapEntry>,
+}
+
+impl MapLiteral {
+    // GETTERS ----------------------------------------------------------------
+
+    /// The entries of the map with their surrounding whitespaces.
+    pub fn entries(&self) -> &Vec<SequenceElement<MapEntry>> {
+        self.sequence.elements()
+    }
+
+    pub fn sequence(&self) -> &Sequence<MapEntry> {
+        &self.sequence
+    }
+
+    // STATIC METHODS ---------------------------------------------------------
+
+    /// Parses a `MapLiteral`.
+    pub fn parse(reader: &mut Reader, context: &mut ParserContext) -> ParserResult<MapLiteral> {
+        if !Self::continues_with_map(reader, context)? {
+            return Err(ParserResultError::NotFound);
+        }
+
+        let sequence = Sequence::parse(reader, context, &MAP_DEFINITION, MapEntry::parse)?;
+        let map = MapLiteral { sequence };
+
+        map.check_duplicated_keys(reader, context);
+
+        Ok(map)
+    }
+
+    /// Checks whether the brace at the current position opens a map instead of a `Block`,
+    /// i.e. the map is empty or its first element is a key followed by a colon.
+    fn continues_with_map(reader: &mut Reader, context: &mut ParserContext) -> ParserResult<bool> {
+        let init_cursor = reader.save_cursor();
+        if !reader.read(MAP_DEFINITION.open_token) {
+            return Ok(false);
+        }
+
+        let _ = Whitespace::parse_multiline_or_default(reader, context);
+
+        let result = if reader.continues_with(MAP_DEFINITION.close_token) {
+            true
+        } else {
+            match MapKey::parse(reader, context) {
+                Ok(_) => {
+                    let _ = Whitespace::parse_multiline_or_default(reader, context);
+                    reader.continues_with(KEY_VALUE_SEPARATOR)
+                }
+                Err(ParserResultError::NotFound) => false,
+                Err(ParserResultError::Error) => return Err(ParserResultError::Error),
+            }
+        };
+
+        reader.restore(init_cursor);
+        Ok(result)
+    }
+
+    fn check_duplicated_keys(&self, reader: &Reader, context: &mut ParserContext) {
+        if context.ignore().map_duplicated_keys {
+            return;
+        }
+
+        let mut keys: HashMap<String, &MapKey> = HashMap::new();
+
+        for entry in self.entries() {
+            let key = entry.node().key();
+            let name = match key.name() {
+                Some(v) => v,
+                None => continue,
+            };
+
+            let first_key = match keys.get(&name) {
+                Some(v) => *v,
+                None => {
+                    keys.insert(name, key);
+                    continue;
+                }
+            };
+
+            context.add_message(generate_warning_log(
+                ParserWarning::MapWithDuplicatedKey,
+                format!("The key '{}' is defined more than once in the map", name),
+                |log| {
+                    generate_source_code(log, reader, |doc| {
+                        doc.highlight_section_str(
+                            first_key.span().start_cursor().byte_offset()
+                                ..first_key.span().end_cursor().byte_offset(),
+                            Some("First defined here"),
+                            None,
+                        )
+                        .highlight_section_str(
+                            key.span().start_cursor().byte_offset()
+                                ..key.span().end_cursor().byte_offset(),
+                            Some("This entry overrides the previous one"),
+                            Some(Color::Magenta),
+                        )
+                    })
+                },
+            ));
+        }
+    }
+}
+
+impl ParserNode for MapLiteral {
+    fn span(&self) -> &Arc<Span> {
+        self.sequence.span()
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+/// An entry of a `MapLiteral`, e.g. `key: value`.
+#[derive(Debug)]
+pub struct MapEntry {
+    span: Arc<Span>,
+    key: MapKey,
+    value: Arc<Expression>,
+    pre_separator_whitespace: Arc<Whitespace>,
+    pre_value_whitespace: Arc<Whitespace>,
+}
+
+impl MapEntry {
+    // GETTERS ----------------------------------------------------------------
+
+    pub fn key(&self) -> &MapKey {
+        &self.key
+    }
+
+    pub fn value(&self) -> &Arc<Expression> {
+        &self.value
+    }
+
+    pub fn pre_separator_whitespace(&self) -> &Arc<Whitespace> {
+        &self.pre_separator_whitespace
+    }
+
+    pub fn pre_value_whitespace(&self) -> &Arc<Whitespace> {
+        &self.pre_value_whitespace
+    }
+
+    // STATIC METHODS ---------------------------------------------------------
+
+    /// Parses a `MapEntry`.
+    pub fn parse(reader: &mut Reader, context: &mut ParserContext) -> ParserResult<MapEntry> {
+        cursor_manager(reader, |reader, init_cursor| {
+            let key = MapKey::parse(reader, context)?;
+
+            let pre_separator_whitespace = Whitespace::parse_multiline_or_default(reader, context);
+
+            if !reader.read(KEY_VALUE_SEPARATOR) {
+                context.add_message(generate_error_log(
+                    ParserError::MissingColonInMapEntry,
+                    "The key of a map entry must be followed by a colon".to_string(),
+                    |log| {
+                        generate_source_code(log, reader, |doc| {
+                            doc.highlight_section(
+                                init_cursor.byte_offset()..key.span().end_cursor().byte_offset(),
+                                None,
+                                Some(Color::Magenta),
+                            )
+                            .highlight_cursor(
+                                key.span().end_cursor().byte_offset(),
+                                Some(Arc::new(format!(
+                                    "Insert the colon '{}' here",
+                                    KEY_VALUE_SEPARATOR
+                                ))),
+                                None,
+                            )
+                        })
+                    },
+                ));
+
+                return Err(ParserResultError::Error);
+            }
+
+            let pre_value_whitespace = Whitespace::parse_multiline_or_default(reader, context);
+
+            let value = match Expression::parse(reader, context) {
+                Ok(v) => v,
+                Err(ParserResultError::NotFound) => {
+                    context.add_message(generate_error_log(
+                        ParserError::MissingValueInMapEntry,
+                        "An expression is expected as the value of the map entry".to_string(),
+                        |log| {
+                            generate_source_code(log, reader, |doc| {
+                                doc.highlight_section(
+                                    init_cursor.byte_offset()
+                                        ..pre_value_whitespace.span().start_cursor().byte_offset(),
+                                    None,
+                                    Some(Color::Magenta),
+                                )
+                                .highlight_cursor_str(
+                                    pre_value_whitespace.span().start_cursor().byte_offset(),
+                                    Some("Insert an expression here"),
+                                    None,
+                                )
+                            })
+                        },
+                    ));
+
+                    return Err(ParserResultError::Error);
+                }
+                Err(ParserResultError::Error) => return Err(ParserResultError::Error),
+            };
+
+            let span = Arc::new(reader.substring_to_current(init_cursor));
+            Ok(MapEntry {
+                span,
+                key,
+                value: Arc::new(value),
+                pre_separator_whitespace: Arc::new(pre_separator_whitespace),
+                pre_value_whitespace: Arc::new(pre_value_whitespace),
+            })
+        })
+    }
+}
+
+impl ParserNode for MapEntry {
+    fn span(&self) -> &Arc<Span> {
+        &self.span
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+/// The key of a `MapEntry`.
+#[derive(Debug)]
+pub enum MapKey {
+    Identifier(Arc<Identifier>),
+    String(Arc<StringLiteral>),
+}
+
+impl MapKey {
+    // GETTERS ----------------------------------------------------------------
+
+    /// The name of the key if it can be known statically, i.e. it is not
+    /// an interpolated string.
+    pub fn name(&self) -> Option<String> {
+        match self {
+            MapKey::Identifier(v) => Some(v.content().to_string()),
+            MapKey::String(v) => v.value(),
+        }
+    }
+
+    // STATIC METHODS ---------------------------------------------------------
+
+    /// Parses a `MapKey`.
+    pub fn parse(reader: &mut Reader, context: &mut ParserContext) -> ParserResult<MapKey> {
+        match Identifier::parse(reader, context) {
+            Ok(node) => return Ok(MapKey::Identifier(Arc::new(node))),
+            Err(ParserResultError::NotFound) => { /* Ignore because not found */ }
+            Err(ParserResultError::Error) => return Err(ParserResultError::Error),
+        }
+
+        match StringLiteral::parse(reader, context) {
+            Ok(node) => return Ok(MapKey::String(Arc::new(node))),
+            Err(ParserResultError::NotFound) => { /* Ignore because not found */ }
+            Err(ParserResultError::Error) => return Err(ParserResultError::Error),
+        }
+
+        Err(ParserResultError::NotFound)
+    }
+}
+
+impl ParserNode for MapKey {
+    fn span(&self) -> &Arc<Span> {
+        match self {
+            MapKey::Identifier(n) => n.span(),
+            MapKey::String(n) => n.span(),
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use crate::test::{assert_error, assert_not_found, assert_warning};
+    use crate::ParserIgnoreConfig;
+
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let mut reader = Reader::from_str("{ a: 1, \"quoted key\" :\n  b + c, }/rest");
+        let mut context = ParserContext::default();
+        let literal =
+            MapLiteral::parse(&mut reader, &mut context).expect("The parser must succeed");
+
+        assert_eq!(
+            literal.content(),
+            "{ a: 1, \"quoted key\" :\n  b + c, }",
+            "The content is incorrect"
+        );
+
+        let entries = literal.entries();
+        assert_eq!(entries.len(), 2, "The entries length is incorrect");
+
+        let entry = entries[0].node();
+        assert_eq!(
+            entry.key.name().as_deref(),
+            Some("a"),
+            "The first key is incorrect"
+        );
+        assert_eq!(entry.value.content(), "1", "The first value is incorrect");
+
+        let entry = entries[1].node();
+        assert_eq!(
+            entry.key.name().as_deref(),
+            Some("quoted key"),
+            "The second key is incorrect"
+        );
+        assert_eq!(
+            entry.value.content(),
+            "b + c",
+            "The second value is incorrect"
+        );
+        assert_eq!(
+            entry.pre_separator_whitespace.content(),
+            " ",
+            "The pre_separator_whitespace is incorrect"
+        );
+        assert_eq!(
+            entry.pre_value_whitespace.content(),
+            "\n  ",
+            "The pre_value_whitespace is incorrect"
+        );
+        assert_eq!(context.messages().len(), 0, "There must no be messages");
+    }
+
+    #[test]
+    fn test_parse_empty() {
+        let mut reader = Reader::from_str("{ }");
+        let mut context = ParserContext::default();
+        let literal =
+            MapLiteral::parse(&mut reader, &mut context).expect("The parser must succeed");
+
+        assert!(
+            literal.entries().is_empty(),
+            "The entries length is incorrect"
+        );
+    }
+
+    #[test]
+    fn test_parse_err_not_found() {
+        // Braces that do not start with an entry are blocks.
+        for text in &[
+            "[a: 1]",
+            "{",
+            "{ a }",
+            "{ a 1 }",
+            "{ \"a\" }",
+            "{\n  let a = 1\n}",
+        ] {
+            let mut reader = Reader::from_str(text);
+            let mut context = ParserContext::default();
+            let error = MapLiteral::parse(&mut reader, &mut context)
+                .expect_err("The parser must not succeed");
+
+            assert_not_found(&context, &error, 0);
+        }
+    }
+
+    #[test]
+    fn test_parse_err_missing_colon() {
+        let mut reader = Reader::from_str("{ a: 1, b 2 }");
+        let mut context = ParserContext::default();
+        let error =
+            MapLiteral::parse(&mut reader, &mut context).expect_err("The parser must not succeed");
+
+        assert_error(&context, &error, ParserError::MissingColonInMapEntry);
+    }
+
+    #[test]
+    fn test_parse_err_missing_value() {
+        let mut reader = Reader::from_str("{ a: }");
+        let mut context = ParserContext::default();
+        let error =
+            MapLiteral::parse(&mut reader, &mut context).expect_err("The parser must not succeed");
+
+        assert_error(&context, &error, ParserError::MissingValueInMapEntry);
+    }
+
+    #[test]
+    fn test_parse_err_missing_comma() {
+        let mut reader = Reader::from_str("{ a: 1\n b: 2 }");
+        let mut context = ParserContext::default();
+        let error =
+            MapLiteral::parse(&mut reader, &mut context).expect_err("The parser must not succeed");
+
+        assert_error(&context, &error, ParserError::MissingCommaInMap);
+    }
+
+    #[test]
+    fn test_parse_err_without_end_token() {
+        for text in &["{ a: 1,", "{ a: 1", "{ a: 1, 2 }"] {
+            let mut reader = Reader::from_str(text);
+            let mut context = ParserContext::default();
+            let error = MapLiteral::parse(&mut reader, &mut context)
+                .expect_err("The parser must not succeed");
+
+            assert_error(&context, &error, ParserError::MapWithoutEndToken);
+        }
+    }
+
+    #[test]
+    fn test_warning_duplicated_keys() {
+        for text in &[
+            "{ a: 1, a: 2 }",
+            "{ a: 1, \"a\": 2 }",
+            "{ \"a\": 1, b: 2, \"a\": 3 }",
+        ] {
+            let mut reader = Reader::from_str(text);
+            let mut context = ParserContext::default();
+            MapLiteral::parse(&mut reader, &mut context).expect("The parser must succeed");
+
+            assert_warning(&context, ParserWarning::MapWithDuplicatedKey);
+        }
+    }
+
+    #[test]
+    fn test_ignore_warning_duplicated_keys() {
+        let mut reader = Reader::from_str("{ a: 1, a: 2 }");
+        let mut ignore = ParserIgnoreConfig::new();
+        ignore.map_duplicated_keys = true;
+
+        let mut context = ParserContext::new(ignore);
+        MapLiteral::parse(&mut reader, &mut context).expect("The parser must succeed");
+
+        assert_eq!(context.messages().len(), 0, "There must no be messages");
+    }
+
+    #[test]
+    fn test_warning_duplicated_keys_ignores_interpolations() {
+        let mut reader = Reader::from_str("{ \"${a}\": 1, \"${a}\": 2 }");
+        let mut context = ParserContext::default();
+        MapLiteral::parse(&mut reader, &mut context).expect("The parser must succeed");
+
+        assert_eq!(context.messages().len(), 0, "There must no be messages");
+    }
+}
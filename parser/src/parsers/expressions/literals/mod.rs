@@ -1,19 +1,34 @@
 use std::sync::Arc;
 
+pub use booleans::*;
+pub use lists::*;
+pub use maps::*;
+pub use nil::*;
 pub use numbers::*;
+pub use strings::*;
 
 use crate::context::ParserContext;
 use crate::io::{Reader, Span};
 use crate::parsers::{ParserResult, ParserResultError};
 use crate::ParserNode;
 
+mod booleans;
 pub mod integer;
+mod lists;
+mod maps;
+mod nil;
 mod numbers;
+mod strings;
 
 /// A literal value in the Mosfet language, like a number, string, etc.
 #[derive(Debug)]
 pub enum Literal {
     Number(Arc<Number>),
+    String(Arc<StringLiteral>),
+    Boolean(Arc<BooleanLiteral>),
+    Nil(Arc<NilLiteral>),
+    List(Arc<ListLiteral>),
+    Map(Arc<MapLiteral>),
 }
 
 impl Literal {
@@ -27,6 +42,36 @@ impl Literal {
             Err(ParserResultError::Error) => return Err(ParserResultError::Error),
         }
 
+        match StringLiteral::parse(reader, context) {
+            Ok(node) => return Ok(Literal::String(Arc::new(node))),
+            Err(ParserResultError::NotFound) => { /* Ignore because not found */ }
+            Err(ParserResultError::Error) => return Err(ParserResultError::Error),
+        }
+
+        match BooleanLiteral::parse(reader, context) {
+            Ok(node) => return Ok(Literal::Boolean(Arc::new(node))),
+            Err(ParserResultError::NotFound) => { /* Ignore because not found */ }
+            Err(ParserResultError::Error) => return Err(ParserResultError::Error),
+        }
+
+        match NilLiteral::parse(reader, context) {
+            Ok(node) => return Ok(Literal::Nil(Arc::new(node))),
+            Err(ParserResultError::NotFound) => { /* Ignore because not found */ }
+            Err(ParserResultError::Error) => return Err(ParserResultError::Error),
+        }
+
+        match ListLiteral::parse(reader, context) {
+            Ok(node) => return Ok(Literal::List(Arc::new(node))),
+            Err(ParserResultError::NotFound) => { /* Ignore because not found */ }
+            Err(ParserResultError::Error) => return Err(ParserResultError::Error),
+        }
+
+        match MapLiteral::parse(reader, context) {
+            Ok(node) => return Ok(Literal::Map(Arc::new(node))),
+            Err(ParserResultError::NotFound) => { /* Ignore because not found */ }
+            Err(ParserResultError::Error) => return Err(ParserResultError::Error),
+        }
+
         Err(ParserResultError::NotFound)
     }
 }
@@ -35,6 +80,11 @@ impl ParserNode for Literal {
     fn span(&self) -> &Arc<Span> {
         match self {
             Literal::Number(n) => n.span(),
+            Literal::String(n) => n.span(),
+            Literal::Boolean(n) => n.span(),
+            Literal::Nil(n) => n.span(),
+            Literal::List(n) => n.span(),
+            Literal::Map(n) => n.span(),
         }
     }
 }
@@ -58,11 +108,75 @@ mod tests {
 
         if let Literal::Number(number) = literal {
             assert_eq!(number.span().content(), "25", "The span is incorrect");
+        } else {
+            panic!("The literal type is incorrect");
+        }
+    }
+
+    #[test]
+    fn test_parse_string() {
+        let mut reader = Reader::from_str("\"test\"/rest");
+        let mut context = ParserContext::default();
+        let literal = Literal::parse(&mut reader, &mut context).expect("The parser must succeed");
+
+        if let Literal::String(string) = literal {
+            assert_eq!(string.span().content(), "\"test\"", "The span is incorrect");
+        } else {
+            panic!("The literal type is incorrect");
+        }
+    }
+
+    #[test]
+    fn test_parse_boolean() {
+        let mut reader = Reader::from_str("false/rest");
+        let mut context = ParserContext::default();
+        let literal = Literal::parse(&mut reader, &mut context).expect("The parser must succeed");
+
+        if let Literal::Boolean(boolean) = literal {
+            assert_eq!(boolean.span().content(), "false", "The span is incorrect");
+            assert!(!boolean.value(), "The value is incorrect");
+        } else {
+            panic!("The literal type is incorrect");
+        }
+    }
+
+    #[test]
+    fn test_parse_nil() {
+        let mut reader = Reader::from_str("nil/rest");
+        let mut context = ParserContext::default();
+        let literal = Literal::parse(&mut reader, &mut context).expect("The parser must succeed");
+
+        if let Literal::Nil(nil) = literal {
+            assert_eq!(nil.span().content(), "nil", "The span is incorrect");
+        } else {
+            panic!("The literal type is incorrect");
+        }
+    }
+
+    #[test]
+    fn test_parse_list() {
+        let mut reader = Reader::from_str("[1, 2]/rest");
+        let mut context = ParserContext::default();
+        let literal = Literal::parse(&mut reader, &mut context).expect("The parser must succeed");
+
+        if let Literal::List(list) = literal {
+            assert_eq!(list.span().content(), "[1, 2]", "The span is incorrect");
+        } else {
+            panic!("The literal type is incorrect");
+        }
+    }
+
+    #[test]
+    fn test_parse_map() {
+        let mut reader = Reader::from_str("{a: 1}/rest");
+        let mut context = ParserContext::default();
+        let literal = Literal::parse(&mut reader, &mut context).expect("The parser must succeed");
+
+        if let Literal::Map(map) = literal {
+            assert_eq!(map.span().content(), "{a: 1}", "The span is incorrect");
+        } else {
+            panic!("The literal type is incorrect");
         }
-        // FIXME(juliotpaez): uncomment when there are more literals.
-        // else {
-        //     panic!("The literal type is incorrect");
-        // }
     }
 
     #[test]
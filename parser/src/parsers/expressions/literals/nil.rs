@@ -0,0 +1,72 @@
+use std::sync::Arc;
+
+use crate::context::ParserContext;
+use crate::io::{Reader, Span};
+use crate::parsers::commons::identifier::Identifier;
+use crate::parsers::utils::cursor_manager;
+use crate::parsers::{ParserResult, ParserResultError};
+use crate::ParserNode;
+
+pub static NIL_KEYWORD: &str = "nil";
+
+/// The literal that represents the absence of value in the Mosfet language, i.e. `nil`.
+#[derive(Debug)]
+pub struct NilLiteral {
+    span: Arc<Span>,
+}
+
+impl NilLiteral {
+    // STATIC METHODS ---------------------------------------------------------
+
+    /// Parses a `NilLiteral`.
+    pub fn parse(reader: &mut Reader, context: &mut ParserContext) -> ParserResult<NilLiteral> {
+        cursor_manager(reader, |reader, init_cursor| {
+            if !Identifier::parse_keyword(reader, context, NIL_KEYWORD) {
+                return Err(ParserResultError::NotFound);
+            }
+
+            Ok(NilLiteral {
+                span: Arc::new(reader.substring_to_current(init_cursor)),
+            })
+        })
+    }
+}
+
+impl ParserNode for NilLiteral {
+    fn span(&self) -> &Arc<Span> {
+        &self.span
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use crate::test::assert_not_found;
+
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let mut reader = Reader::from_str("nil/rest");
+        let mut context = ParserContext::default();
+        let literal =
+            NilLiteral::parse(&mut reader, &mut context).expect("The parser must succeed");
+
+        assert_eq!(literal.content(), "nil", "The content is incorrect");
+    }
+
+    #[test]
+    fn test_parse_err_not_found() {
+        for content in &["-", "nill", "Nil"] {
+            let mut reader = Reader::from_str(content);
+            let mut context = ParserContext::default();
+            let error = NilLiteral::parse(&mut reader, &mut context)
+                .expect_err("The parser must not succeed");
+
+            assert_not_found(&context, &error, 0);
+        }
+    }
+}
@@ -1,24 +1,131 @@
+use std::ops::RangeInclusive;
 use std::sync::Arc;
 
 use doclog::Color;
+use num_bigint::{BigInt, BigUint};
+use num_rational::BigRational;
+use num_traits::{One, ToPrimitive};
 
 use crate::context::ParserContext;
-use crate::io::{Reader, Span};
-use crate::parsers::expressions::literals::integer::{IntegerNumber, Radix, SEPARATOR_RANGE};
-use crate::parsers::utils::{cursor_manager, generate_source_code, generate_warning_log};
-use crate::parsers::ParserResult;
-use crate::{ParserNode, ParserWarning};
+use crate::io::{Cursor, Reader, Span};
+use crate::parsers::commons::identifier::{BODY_CHARS, HEAD_CHARS};
+use crate::parsers::expressions::literals::integer::{
+    remove_separators, IntegerNumber, Radix, DECIMAL_DIGIT_CHARS, SEPARATOR_RANGE,
+};
+use crate::parsers::expressions::ranges::RANGE_TOKEN;
+use crate::parsers::utils::{
+    cursor_manager, generate_error_log, generate_source_code, generate_warning_log,
+};
+use crate::parsers::{ParserResult, ParserResultError};
+use crate::{ParserError, ParserNode, ParserWarning};
 
 static DECIMAL_SEPARATOR: &str = ".";
+pub static DECIMAL_EXPONENT_CHARS: &[RangeInclusive<char>] = &['E'..='E', 'e'..='e'];
+pub static BINARY_EXPONENT_CHARS: &[RangeInclusive<char>] = &['P'..='P', 'p'..='p'];
+pub static EXPONENT_SIGN_CHARS: &[RangeInclusive<char>] = &['+'..='+', '-'..='-'];
+/// The maximum absolute value of an exponent so that numbers can be evaluated exactly.
+pub static MAX_EXPONENT: u32 = 65_535;
+static ALL_EXPONENT_CHARS: &[RangeInclusive<char>] = &['E'..='E', 'P'..='P', 'e'..='e', 'p'..='p'];
+
+/// The type suffix of a number, e.g. `u8` in `42u8`.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum NumericSuffix {
+    U8,
+    U16,
+    U32,
+    U64,
+    I8,
+    I16,
+    I32,
+    I64,
+    F32,
+    F64,
+}
+
+impl NumericSuffix {
+    // GETTERS ----------------------------------------------------------------
+
+    /// The suffix as str.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            NumericSuffix::U8 => "u8",
+            NumericSuffix::U16 => "u16",
+            NumericSuffix::U32 => "u32",
+            NumericSuffix::U64 => "u64",
+            NumericSuffix::I8 => "i8",
+            NumericSuffix::I16 => "i16",
+            NumericSuffix::I32 => "i32",
+            NumericSuffix::I64 => "i64",
+            NumericSuffix::F32 => "f32",
+            NumericSuffix::F64 => "f64",
+        }
+    }
+
+    /// Whether the suffix represents a floating-point type or not.
+    pub fn is_float(&self) -> bool {
+        matches!(self, NumericSuffix::F32 | NumericSuffix::F64)
+    }
+
+    // STATIC METHODS ---------------------------------------------------------
+
+    /// Gets the `NumericSuffix` that matches `name` if any.
+    pub fn from_name(name: &str) -> Option<NumericSuffix> {
+        match name {
+            "u8" => Some(NumericSuffix::U8),
+            "u16" => Some(NumericSuffix::U16),
+            "u32" => Some(NumericSuffix::U32),
+            "u64" => Some(NumericSuffix::U64),
+            "i8" => Some(NumericSuffix::I8),
+            "i16" => Some(NumericSuffix::I16),
+            "i32" => Some(NumericSuffix::I32),
+            "i64" => Some(NumericSuffix::I64),
+            "f32" => Some(NumericSuffix::F32),
+            "f64" => Some(NumericSuffix::F64),
+            _ => None,
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+/// The errors that can happen when converting a `Number` into a primitive type.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum NumberConversionError {
+    /// The value is out of the range of the type.
+    Overflow,
+    /// The value cannot be represented exactly by the type.
+    PrecisionLoss,
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
 
 /// A number in the Mosfet language.
 /// Can be written in binary(`0b`), octal(`0o`), decimal(`0d`) and hexadecimal(`0x`),
 /// using their own prefix. For decimal can be omitted.
+///
+/// Decimal numbers accept a base-10 exponent (`1.5e-10`) and hexadecimal ones a
+/// base-2 exponent (`0x1.8p3`). In both cases the exponent digits are written in decimal.
+///
+/// The decimal part must start with a digit between 0 and 9 in any radix, so that
+/// `0xFF.abs()` is a method call. Use an exponent to write other ones, e.g. `0xAp-4`
+/// instead of `0x0.A`.
+///
+/// Any number can end with a type suffix, e.g. `42u8` or `3.0f32`. Float suffixes cannot
+/// follow hexadecimal digits because `f` is one of them, e.g. `0x1f32` is the integer
+/// `0x1F32`, so an exponent must be placed between them, e.g. `0x1p0f32`.
 #[derive(Debug, Clone, Eq, PartialEq)]
 pub struct Number {
     span: Arc<Span>,
     integer: IntegerNumber,
     decimal_digits: Option<Arc<Span>>,
+    exponent_sign: Option<Arc<Span>>,
+    exponent_digits: Option<Arc<Span>>,
+    suffix: Option<NumericSuffix>,
+    suffix_span: Option<Arc<Span>>,
 }
 
 impl Number {
@@ -45,55 +152,363 @@ impl Number {
         &self.decimal_digits
     }
 
+    pub fn exponent_sign(&self) -> &Option<Arc<Span>> {
+        &self.exponent_sign
+    }
+
+    pub fn exponent_digits(&self) -> &Option<Arc<Span>> {
+        &self.exponent_digits
+    }
+
+    pub fn suffix(&self) -> &Option<NumericSuffix> {
+        &self.suffix
+    }
+
+    pub fn suffix_span(&self) -> &Option<Arc<Span>> {
+        &self.suffix_span
+    }
+
+    /// Whether the exponent is negative or not.
+    pub fn has_negative_exponent(&self) -> bool {
+        self.exponent_sign
+            .as_ref()
+            .map(|sign| sign.content() == "-")
+            .unwrap_or(false)
+    }
+
+    // METHODS ----------------------------------------------------------------
+
+    /// The exact value the number represents, ignoring its suffix.
+    pub fn value(&self) -> BigRational {
+        let base = BigInt::from(self.radix().base());
+        let mut numerator = BigInt::from(self.integer.value());
+        let mut denominator = BigInt::one();
+
+        if let Some(decimal_digits) = &self.decimal_digits {
+            let digits = remove_separators(decimal_digits.content());
+            let decimal_value = BigUint::parse_bytes(digits.as_bytes(), self.radix().base())
+                .expect("The digits must be valid for the radix");
+
+            denominator = base.pow(digits.len() as u32);
+            numerator = numerator * &denominator + BigInt::from(decimal_value);
+        }
+
+        if let Some(exponent_digits) = &self.exponent_digits {
+            let exponent = remove_separators(exponent_digits.content())
+                .parse::<u32>()
+                .expect("The exponent must be lower than MAX_EXPONENT");
+            let exponent_base = match self.radix() {
+                Radix::Hexadecimal => BigInt::from(2),
+                _ => BigInt::from(10),
+            };
+            let factor = exponent_base.pow(exponent);
+
+            if self.has_negative_exponent() {
+                denominator *= factor;
+            } else {
+                numerator *= factor;
+            }
+        }
+
+        BigRational::new(numerator, denominator)
+    }
+
+    /// Converts the number into an `i64`.
+    pub fn to_i64(&self) -> Result<i64, NumberConversionError> {
+        let value = self.value();
+        if !value.is_integer() {
+            return Err(NumberConversionError::PrecisionLoss);
+        }
+
+        value
+            .to_integer()
+            .to_i64()
+            .ok_or(NumberConversionError::Overflow)
+    }
+
+    /// Converts the number into an `u64`.
+    pub fn to_u64(&self) -> Result<u64, NumberConversionError> {
+        let value = self.value();
+        if !value.is_integer() {
+            return Err(NumberConversionError::PrecisionLoss);
+        }
+
+        value
+            .to_integer()
+            .to_u64()
+            .ok_or(NumberConversionError::Overflow)
+    }
+
+    /// Converts the number into the nearest `f64`, rounding ties to even.
+    pub fn to_f64(&self) -> Result<f64, NumberConversionError> {
+        match self.value().to_f64() {
+            Some(v) if v.is_finite() => Ok(v),
+            _ => Err(NumberConversionError::Overflow),
+        }
+    }
+
+    /// Converts the number into an `f64` only if it can be represented exactly,
+    /// e.g. `0.5` but not `0.1`.
+    pub fn to_f64_exact(&self) -> Result<f64, NumberConversionError> {
+        let value = self.value();
+        let result = self.to_f64()?;
+
+        if BigRational::from_float(result).as_ref() != Some(&value) {
+            return Err(NumberConversionError::PrecisionLoss);
+        }
+
+        Ok(result)
+    }
+
     // STATIC METHODS ---------------------------------------------------------
 
     /// Parses a prefixed `Number` or a decimal without prefix.
     pub fn parse(reader: &mut Reader, context: &mut ParserContext) -> ParserResult<Number> {
         cursor_manager(reader, |reader, init_cursor| {
             let integer_part = IntegerNumber::parse(reader, context)?;
+            let decimal_digits = Self::parse_decimal_digits(reader, integer_part.radix());
+            let (exponent_sign, exponent_digits) =
+                Self::parse_exponent(reader, context, integer_part.radix(), init_cursor)?;
+            let is_float = decimal_digits.is_some() || exponent_digits.is_some();
+            let (suffix, suffix_span) = Self::parse_suffix(reader, context, is_float, init_cursor)?;
+
+            let result = Number {
+                integer: integer_part,
+                decimal_digits,
+                exponent_sign,
+                exponent_digits,
+                suffix,
+                suffix_span,
+                span: Arc::new(reader.substring_to_current(init_cursor)),
+            };
+
+            if result.decimal_digits.is_some() {
+                Self::check_trailing_zeroes(reader, context, &result);
+            }
+
+            Ok(result)
+        })
+    }
+
+    /// Parses the decimal part of a number including its separator.
+    /// Returns the span of its digits if present.
+    fn parse_decimal_digits(reader: &mut Reader, radix: &Radix) -> Option<Arc<Span>> {
+        // The dot of a range operator is never a decimal separator, e.g. `1..5`.
+        if reader.continues_with(RANGE_TOKEN) {
+            return None;
+        }
+
+        let pre_decimal_cursor = reader.save_cursor();
+        if !reader.read(DECIMAL_SEPARATOR) {
+            return None;
+        }
+
+        let post_decimal_cursor = reader.save_cursor();
+        let digit_interval = radix.digit_chars();
+        if reader.continues_with_one_of(DECIMAL_DIGIT_CHARS).is_none()
+            || reader.read_many_of(digit_interval).is_none()
+        {
+            reader.restore(pre_decimal_cursor);
+            return None;
+        }
 
-            let pre_decimal_cursor = reader.save_cursor();
-            if !reader.read(DECIMAL_SEPARATOR) {
-                return Ok(Number {
-                    integer: integer_part,
-                    decimal_digits: None,
-                    span: Arc::new(reader.substring_to_current(init_cursor)),
-                });
+        loop {
+            let init_loop_cursor = reader.save_cursor();
+            if reader.read_many_of(SEPARATOR_RANGE).is_none() {
+                break;
             }
 
-            let post_decimal_cursor = reader.save_cursor();
-            let digit_interval = integer_part.radix().digit_chars();
-            if let None = reader.read_many_of(digit_interval) {
-                reader.restore(pre_decimal_cursor);
-                return Ok(Number {
-                    integer: integer_part,
-                    decimal_digits: None,
-                    span: Arc::new(reader.substring_to_current(init_cursor)),
-                });
+            if reader.read_many_of(digit_interval).is_none() {
+                reader.restore(init_loop_cursor);
+                break;
             }
+        }
+
+        Some(Arc::new(reader.substring_to_current(&post_decimal_cursor)))
+    }
+
+    /// Parses the exponent of a number.
+    /// Returns the spans of its sign and digits if present.
+    #[allow(clippy::type_complexity)]
+    fn parse_exponent(
+        reader: &mut Reader,
+        context: &mut ParserContext,
+        radix: &Radix,
+        init_cursor: &Cursor,
+    ) -> ParserResult<(Option<Arc<Span>>, Option<Arc<Span>>)> {
+        let exponent_cursor = reader.save_cursor();
+        let exponent_chars = match radix {
+            Radix::Decimal => DECIMAL_EXPONENT_CHARS,
+            Radix::Hexadecimal => BINARY_EXPONENT_CHARS,
+            Radix::Binary | Radix::Octal => &[],
+        };
 
-            loop {
-                let init_loop_cursor = reader.save_cursor();
-                if let None = reader.read_many_of(&SEPARATOR_RANGE) {
-                    break;
+        if reader.read_one_of(exponent_chars).is_none() {
+            // Without digits the letter is the beginning of a suffix, e.g. `1px`.
+            if reader.read_one_of(ALL_EXPONENT_CHARS).is_some() {
+                reader.read_one_of(EXPONENT_SIGN_CHARS);
+
+                if reader.continues_with_one_of(DECIMAL_DIGIT_CHARS).is_none() {
+                    reader.restore(exponent_cursor);
+                    return Ok((None, None));
                 }
 
-                if let None = reader.read_many_of(digit_interval) {
-                    reader.restore(init_loop_cursor);
-                    break;
+                context.add_message(generate_error_log(
+                    ParserError::NumberWithExponentInWrongRadix,
+                    "Exponents are only allowed in decimal (e) and hexadecimal (p) numbers"
+                        .to_string(),
+                    |log| {
+                        generate_source_code(log, reader, |doc| {
+                            doc.highlight_section(
+                                init_cursor.byte_offset()..exponent_cursor.byte_offset(),
+                                None,
+                                Some(Color::Magenta),
+                            )
+                            .highlight_section_str(
+                                exponent_cursor.byte_offset()..reader.byte_offset(),
+                                Some("Remove this exponent"),
+                                None,
+                            )
+                        })
+                    },
+                ));
+
+                return Err(ParserResultError::Error);
+            }
+
+            return Ok((None, None));
+        }
+
+        let sign_cursor = reader.save_cursor();
+        let exponent_sign = reader
+            .read_one_of(EXPONENT_SIGN_CHARS)
+            .map(|_| Arc::new(reader.substring_to_current(&sign_cursor)));
+
+        let exponent_digits = match IntegerNumber::parse_decimal(reader, context) {
+            Ok(v) => {
+                if v.value() > BigUint::from(MAX_EXPONENT) {
+                    context.add_message(generate_error_log(
+                        ParserError::NumberWithTooLargeExponent,
+                        format!("The exponent cannot be greater than {}", MAX_EXPONENT),
+                        |log| {
+                            generate_source_code(log, reader, |doc| {
+                                doc.highlight_section(
+                                    init_cursor.byte_offset()
+                                        ..v.span().start_cursor().byte_offset(),
+                                    None,
+                                    Some(Color::Magenta),
+                                )
+                                .highlight_section_str(
+                                    v.span().start_cursor().byte_offset()..reader.byte_offset(),
+                                    Some("Reduce this exponent"),
+                                    None,
+                                )
+                            })
+                        },
+                    ));
+
+                    return Err(ParserResultError::Error);
                 }
+
+                v.digits().clone()
             }
+            Err(ParserResultError::NotFound) => {
+                context.add_message(generate_error_log(
+                    ParserError::NumberWithoutDigitsInExponent,
+                    "At least one digit was expected in the exponent".to_string(),
+                    |log| {
+                        generate_source_code(log, reader, |doc| {
+                            doc.highlight_section(
+                                init_cursor.byte_offset()..reader.byte_offset(),
+                                None,
+                                Some(Color::Magenta),
+                            )
+                            .highlight_cursor_str(
+                                reader.byte_offset(),
+                                Some("Add a digit here, e.g. 0"),
+                                None,
+                            )
+                        })
+                    },
+                ));
+
+                return Err(ParserResultError::Error);
+            }
+            Err(ParserResultError::Error) => return Err(ParserResultError::Error),
+        };
 
-            let result = Number {
-                integer: integer_part,
-                decimal_digits: Some(Arc::new(reader.substring_to_current(&post_decimal_cursor))),
-                span: Arc::new(reader.substring_to_current(init_cursor)),
-            };
+        Ok((exponent_sign, Some(exponent_digits)))
+    }
 
-            Self::check_trailing_zeroes(reader, context, &result);
+    /// Parses the type suffix of a number.
+    /// Returns the suffix and its span if present.
+    fn parse_suffix(
+        reader: &mut Reader,
+        context: &mut ParserContext,
+        is_float: bool,
+        init_cursor: &Cursor,
+    ) -> ParserResult<(Option<NumericSuffix>, Option<Arc<Span>>)> {
+        let suffix_cursor = reader.save_cursor();
+        if reader.read_one_of(&HEAD_CHARS).is_none() {
+            return Ok((None, None));
+        }
 
-            Ok(result)
-        })
+        reader.read_many_of(&BODY_CHARS);
+
+        let suffix_span = Arc::new(reader.substring_to_current(&suffix_cursor));
+        let suffix = match NumericSuffix::from_name(suffix_span.content()) {
+            Some(v) => v,
+            None => {
+                context.add_message(generate_error_log(
+                    ParserError::NumberWithUnknownSuffix,
+                    format!("Unknown number suffix '{}'", suffix_span.content()),
+                    |log| {
+                        generate_source_code(log, reader, |doc| {
+                            doc.highlight_section(
+                                init_cursor.byte_offset()..suffix_cursor.byte_offset(),
+                                None,
+                                Some(Color::Magenta),
+                            )
+                            .highlight_section_str(
+                                suffix_cursor.byte_offset()..reader.byte_offset(),
+                                Some("Valid suffixes are: u8, u16, u32, u64, i8, i16, i32, i64, f32 and f64"),
+                                None,
+                            )
+                        })
+                    },
+                ));
+
+                return Err(ParserResultError::Error);
+            }
+        };
+
+        if is_float && !suffix.is_float() {
+            context.add_message(generate_error_log(
+                ParserError::NumberWithIntegerSuffixAndDecimals,
+                format!(
+                    "The integer suffix '{}' cannot be used with decimals or exponents",
+                    suffix.as_str()
+                ),
+                |log| {
+                    generate_source_code(log, reader, |doc| {
+                        doc.highlight_section(
+                            init_cursor.byte_offset()..suffix_cursor.byte_offset(),
+                            None,
+                            Some(Color::Magenta),
+                        )
+                        .highlight_section_str(
+                            suffix_cursor.byte_offset()..reader.byte_offset(),
+                            Some("Use a float suffix here, e.g. f64"),
+                            None,
+                        )
+                    })
+                },
+            ));
+
+            return Err(ParserResultError::Error);
+        }
+
+        Ok((Some(suffix), Some(suffix_span)))
     }
 
     fn check_trailing_zeroes(reader: &mut Reader, context: &mut ParserContext, number: &Number) {
@@ -162,12 +577,350 @@ mod tests {
     use crate::parsers::expressions::literals::integer::{
         BINARY_PREFIX, DECIMAL_PREFIX, HEXADECIMAL_PREFIX, OCTAL_PREFIX,
     };
-    use crate::test::assert_warning;
+    use crate::test::{assert_error, assert_warning};
     use crate::ParserIgnoreConfig;
 
     use super::*;
 
-    // TODO add tests
+    #[test]
+    fn test_parse() {
+        let mut reader = Reader::from_str("25/rest");
+        let mut context = ParserContext::default();
+        let number = Number::parse(&mut reader, &mut context).expect("The parser must succeed");
+
+        assert_eq!(number.content(), "25", "The content is incorrect");
+        assert_eq!(
+            number.integer_digits().content(),
+            "25",
+            "The integer_digits is incorrect"
+        );
+        assert_eq!(
+            number.decimal_digits, None,
+            "The decimal_digits is incorrect"
+        );
+        assert_eq!(number.exponent_sign, None, "The exponent_sign is incorrect");
+        assert_eq!(
+            number.exponent_digits, None,
+            "The exponent_digits is incorrect"
+        );
+    }
+
+    #[test]
+    fn test_parse_decimals() {
+        let mut reader = Reader::from_str("2_5.1_5/rest");
+        let mut context = ParserContext::default();
+        let number = Number::parse(&mut reader, &mut context).expect("The parser must succeed");
+
+        assert_eq!(number.content(), "2_5.1_5", "The content is incorrect");
+        assert_eq!(
+            number.decimal_digits.as_ref().unwrap().content(),
+            "1_5",
+            "The decimal_digits is incorrect"
+        );
+
+        // Without digits after the separator.
+        let mut reader = Reader::from_str("25.rest");
+        let mut context = ParserContext::default();
+        let number = Number::parse(&mut reader, &mut context).expect("The parser must succeed");
+
+        assert_eq!(number.content(), "25", "The content is incorrect");
+        assert_eq!(
+            number.decimal_digits, None,
+            "The decimal_digits is incorrect"
+        );
+
+        // Followed by a range operator or a spaced dot.
+        for (text, content, rest) in &[
+            ("1..5", "1", "..5"),
+            ("1..=5", "1", "..=5"),
+            ("0xA..0xF", "0xA", "..0xF"),
+            ("1.5..2", "1.5", "..2"),
+            ("1. .5", "1", ". .5"),
+            ("0xFF.abs", "0xFF", ".abs"),
+            ("0x1.A", "0x1", ".A"),
+            ("0x1.0A", "0x1.0A", ""),
+        ] {
+            let mut reader = Reader::from_str(text);
+            let mut context = ParserContext::default();
+            let number = Number::parse(&mut reader, &mut context).expect("The parser must succeed");
+
+            assert_eq!(number.content(), *content, "The content is incorrect");
+            assert_eq!(
+                reader.remaining_content(),
+                *rest,
+                "The remaining content is incorrect"
+            );
+        }
+    }
+
+    #[test]
+    fn test_parse_exponent() {
+        for (text, content, sign, digits) in &[
+            ("1.5e-10/rest", "1.5e-10", Some("-"), "10"),
+            ("1_000E3/rest", "1_000E3", None, "3"),
+            ("2e+5_0/rest", "2e+5_0", Some("+"), "5_0"),
+            ("0d1.5e3/rest", "0d1.5e3", None, "3"),
+            ("0x1.8p3/rest", "0x1.8p3", None, "3"),
+            ("0xFFP-2/rest", "0xFFP-2", Some("-"), "2"),
+        ] {
+            let mut reader = Reader::from_str(text);
+            let mut context = ParserContext::default();
+            let number = Number::parse(&mut reader, &mut context).expect("The parser must succeed");
+
+            assert_eq!(number.content(), *content, "The content is incorrect");
+            assert_eq!(
+                number.exponent_sign.as_ref().map(|v| v.content()),
+                *sign,
+                "The exponent_sign is incorrect"
+            );
+            assert_eq!(
+                number.exponent_digits.as_ref().unwrap().content(),
+                *digits,
+                "The exponent_digits is incorrect"
+            );
+        }
+    }
+
+    #[test]
+    fn test_parse_suffix() {
+        for (text, content, digits, suffix) in &[
+            ("42u8/rest", "42u8", "42", NumericSuffix::U8),
+            ("7i64/rest", "7i64", "7", NumericSuffix::I64),
+            ("3.0f32/rest", "3.0f32", "3", NumericSuffix::F32),
+            ("1e3f64/rest", "1e3f64", "1", NumericSuffix::F64),
+            ("0xFFu16/rest", "0xFFu16", "FF", NumericSuffix::U16),
+            ("0b1_0i8/rest", "0b1_0i8", "1_0", NumericSuffix::I8),
+        ] {
+            let mut reader = Reader::from_str(text);
+            let mut context = ParserContext::default();
+            let number = Number::parse(&mut reader, &mut context).expect("The parser must succeed");
+
+            assert_eq!(number.content(), *content, "The content is incorrect");
+            assert_eq!(
+                number.integer_digits().content(),
+                *digits,
+                "The integer_digits is incorrect"
+            );
+            assert_eq!(number.suffix, Some(*suffix), "The suffix is incorrect");
+            assert_eq!(
+                number.suffix_span.as_ref().unwrap().content(),
+                suffix.as_str(),
+                "The suffix_span is incorrect"
+            );
+        }
+    }
+
+    #[test]
+    fn test_parse_hexadecimal_float_suffix() {
+        for (text, digits, suffix) in &[
+            ("0x1f32", "1f32", None),
+            ("0x1p0f32", "1", Some(NumericSuffix::F32)),
+        ] {
+            let mut reader = Reader::from_str(text);
+            let mut context = ParserContext::default();
+            let number = Number::parse(&mut reader, &mut context).expect("The parser must succeed");
+
+            assert_eq!(number.content(), *text, "The content is incorrect");
+            assert_eq!(
+                number.integer_digits().content(),
+                *digits,
+                "The integer_digits is incorrect"
+            );
+            assert_eq!(number.suffix, *suffix, "The suffix is incorrect");
+        }
+    }
+
+    #[test]
+    fn test_parse_err_unknown_suffix() {
+        for text in &["42u7", "1x", "3.0f16", "0x1_g", "1px", "0b1e", "1.5p-"] {
+            let mut reader = Reader::from_str(text);
+            let mut context = ParserContext::default();
+            let error =
+                Number::parse(&mut reader, &mut context).expect_err("The parser must not succeed");
+
+            assert_error(&context, &error, ParserError::NumberWithUnknownSuffix);
+        }
+    }
+
+    #[test]
+    fn test_parse_err_integer_suffix_and_decimals() {
+        for text in &["1.5u8", "1e3i32", "0x1.8p3u64"] {
+            let mut reader = Reader::from_str(text);
+            let mut context = ParserContext::default();
+            let error =
+                Number::parse(&mut reader, &mut context).expect_err("The parser must not succeed");
+
+            assert_error(
+                &context,
+                &error,
+                ParserError::NumberWithIntegerSuffixAndDecimals,
+            );
+        }
+    }
+
+    #[test]
+    fn test_value() {
+        for (text, numerator, denominator) in &[
+            ("25", 25i64, 1i64),
+            ("0xFF", 255, 1),
+            ("1_000.5", 2001, 2),
+            ("0b0.1", 1, 2),
+            ("0o0.4", 1, 2),
+            ("0x0.8", 1, 2),
+            ("1.5e-10", 3, 20_000_000_000),
+            ("1_000E3", 1_000_000, 1),
+            ("0x1.8p3", 12, 1),
+            ("0x1p-2", 1, 4),
+            ("42u8", 42, 1),
+        ] {
+            let mut reader = Reader::from_str(text);
+            let mut context = ParserContext::default();
+            let number = Number::parse(&mut reader, &mut context).expect("The parser must succeed");
+
+            assert_eq!(
+                number.value(),
+                BigRational::new(BigInt::from(*numerator), BigInt::from(*denominator)),
+                "The value of {} is incorrect",
+                text
+            );
+        }
+    }
+
+    #[test]
+    fn test_to_i64() {
+        for (text, value) in &[
+            ("25", Ok(25)),
+            ("0x7FFF_FFFF_FFFF_FFFF", Ok(i64::MAX)),
+            (
+                "0x8000_0000_0000_0000",
+                Err(NumberConversionError::Overflow),
+            ),
+            ("1.5", Err(NumberConversionError::PrecisionLoss)),
+            ("1.5e1", Ok(15)),
+        ] {
+            let mut reader = Reader::from_str(text);
+            let mut context = ParserContext::default();
+            let number = Number::parse(&mut reader, &mut context).expect("The parser must succeed");
+
+            assert_eq!(
+                number.to_i64(),
+                *value,
+                "The value of {} is incorrect",
+                text
+            );
+        }
+    }
+
+    #[test]
+    fn test_to_u64() {
+        for (text, value) in &[
+            ("0xFFFF_FFFF_FFFF_FFFF", Ok(u64::MAX)),
+            (
+                "0x1_0000_0000_0000_0000",
+                Err(NumberConversionError::Overflow),
+            ),
+            ("0b0.1", Err(NumberConversionError::PrecisionLoss)),
+        ] {
+            let mut reader = Reader::from_str(text);
+            let mut context = ParserContext::default();
+            let number = Number::parse(&mut reader, &mut context).expect("The parser must succeed");
+
+            assert_eq!(
+                number.to_u64(),
+                *value,
+                "The value of {} is incorrect",
+                text
+            );
+        }
+    }
+
+    #[test]
+    fn test_to_f64() {
+        for (text, value) in &[
+            ("1.5", Ok(1.5)),
+            ("0x1.8p3", Ok(12.0)),
+            ("0.1", Ok(0.1)),
+            ("0.3f64", Ok(0.3)),
+            ("1e308", Ok(1e308)),
+            ("1e400", Err(NumberConversionError::Overflow)),
+            ("0x1p-1074", Ok(f64::from_bits(1))),
+            ("0x1p-1075", Ok(0.0)),
+        ] {
+            let mut reader = Reader::from_str(text);
+            let mut context = ParserContext::default();
+            let number = Number::parse(&mut reader, &mut context).expect("The parser must succeed");
+
+            assert_eq!(
+                number.to_f64(),
+                *value,
+                "The value of {} is incorrect",
+                text
+            );
+        }
+    }
+
+    #[test]
+    fn test_to_f64_exact() {
+        for (text, value) in &[
+            ("1.5", Ok(1.5)),
+            ("0x1.8p3", Ok(12.0)),
+            ("0.1", Err(NumberConversionError::PrecisionLoss)),
+            ("0.3f64", Err(NumberConversionError::PrecisionLoss)),
+            ("1e308", Err(NumberConversionError::PrecisionLoss)),
+            ("1e400", Err(NumberConversionError::Overflow)),
+            ("0x1p-1074", Ok(f64::from_bits(1))),
+            ("0x1p-1075", Err(NumberConversionError::PrecisionLoss)),
+        ] {
+            let mut reader = Reader::from_str(text);
+            let mut context = ParserContext::default();
+            let number = Number::parse(&mut reader, &mut context).expect("The parser must succeed");
+
+            assert_eq!(
+                number.to_f64_exact(),
+                *value,
+                "The value of {} is incorrect",
+                text
+            );
+        }
+    }
+
+    #[test]
+    fn test_parse_err_too_large_exponent() {
+        let mut reader = Reader::from_str("1e65536");
+        let mut context = ParserContext::default();
+        let error =
+            Number::parse(&mut reader, &mut context).expect_err("The parser must not succeed");
+
+        assert_error(&context, &error, ParserError::NumberWithTooLargeExponent);
+    }
+
+    #[test]
+    fn test_parse_err_without_digits_in_exponent() {
+        for text in &["1e", "1.5E+", "2e-a", "0x1p", "1e_1"] {
+            let mut reader = Reader::from_str(text);
+            let mut context = ParserContext::default();
+            let error =
+                Number::parse(&mut reader, &mut context).expect_err("The parser must not succeed");
+
+            assert_error(&context, &error, ParserError::NumberWithoutDigitsInExponent);
+        }
+    }
+
+    #[test]
+    fn test_parse_err_exponent_in_wrong_radix() {
+        for text in &["0b1e3", "0o7.1E3", "0b1p3", "0o7P3", "1p3", "1.5p3"] {
+            let mut reader = Reader::from_str(text);
+            let mut context = ParserContext::default();
+            let error =
+                Number::parse(&mut reader, &mut context).expect_err("The parser must not succeed");
+
+            assert_error(
+                &context,
+                &error,
+                ParserError::NumberWithExponentInWrongRadix,
+            );
+        }
+    }
 
     #[test]
     fn test_warning_trailing_zeroes() {
@@ -0,0 +1,730 @@
+use std::ops::RangeInclusive;
+use std::sync::Arc;
+
+use doclog::Color;
+
+use crate::context::ParserContext;
+use crate::io::{Cursor, Reader, Span};
+use crate::parsers::commons::whitespaces::Whitespace;
+use crate::parsers::expressions::literals::integer::HEXADECIMAL_DIGIT_CHARS;
+use crate::parsers::expressions::Expression;
+use crate::parsers::utils::{cursor_manager, generate_error_log, generate_source_code};
+use crate::parsers::{ParserResult, ParserResultError};
+use crate::{ParserError, ParserNode};
+
+pub static STRING_DELIMITER: &str = "\"";
+pub static RAW_STRING_PREFIX: &str = "r";
+pub static RAW_STRING_REPEAT_TOKEN: &str = "#";
+pub static ESCAPE_TOKEN: &str = "\\";
+pub static UNICODE_ESCAPE_OPEN_TOKEN: &str = "u{";
+pub static UNICODE_ESCAPE_CLOSE_TOKEN: &str = "}";
+pub static INTERPOLATION_OPEN_TOKEN: &str = "${";
+pub static INTERPOLATION_CLOSE_TOKEN: &str = "}";
+static SPECIAL_CHARS: &[RangeInclusive<char>] = &['"'..='"', '$'..='$', '\\'..='\\'];
+static UNICODE_ESCAPE_MAX_DIGITS: usize = 6;
+
+/// A string literal in the Mosfet language.
+/// Can be written as a normal string (`"..."`) that accepts escape sequences and
+/// interpolations (`${expr}`), or as a raw string (`r"..."`, `r#"..."#`, ...) that
+/// keeps its content as it is.
+#[derive(Debug)]
+pub struct StringLiteral {
+    span: Arc<Span>,
+    is_raw: bool,
+    repeated_tokens: usize,
+    segments: Vec<StringSegment>,
+}
+
+/// A segment of a `StringLiteral`.
+#[derive(Debug)]
+pub enum StringSegment {
+    Text(Arc<Span>),
+    Escape(Arc<StringEscape>),
+    Interpolation(Arc<StringInterpolation>),
+}
+
+/// An escape sequence inside a `StringLiteral`, e.g. `\n` or `\u{1F600}`.
+#[derive(Debug)]
+pub struct StringEscape {
+    span: Arc<Span>,
+    value: char,
+}
+
+/// An interpolated expression inside a `StringLiteral`, e.g. `${name}`.
+#[derive(Debug)]
+pub struct StringInterpolation {
+    span: Arc<Span>,
+    expression: Arc<Expression>,
+    pre_expression_whitespace: Arc<Whitespace>,
+    post_expression_whitespace: Arc<Whitespace>,
+}
+
+impl StringLiteral {
+    // GETTERS ----------------------------------------------------------------
+
+    /// Whether the string is raw, i.e. `r"..."`, or not.
+    pub fn is_raw(&self) -> bool {
+        self.is_raw
+    }
+
+    /// The number of `#` tokens that delimit a raw string.
+    pub fn repeated_tokens(&self) -> &usize {
+        &self.repeated_tokens
+    }
+
+    pub fn segments(&self) -> &Vec<StringSegment> {
+        &self.segments
+    }
+
+    /// Whether the string contains any interpolation or not.
+    pub fn has_interpolations(&self) -> bool {
+        self.segments
+            .iter()
+            .any(|segment| matches!(segment, StringSegment::Interpolation(_)))
+    }
+
+    /// The value of the string with all its escape sequences resolved.
+    /// Returns `None` if the string contains interpolations.
+    pub fn value(&self) -> Option<String> {
+        let mut result = String::new();
+
+        for segment in &self.segments {
+            match segment {
+                StringSegment::Text(span) => result.push_str(span.content()),
+                StringSegment::Escape(escape) => result.push(escape.value),
+                StringSegment::Interpolation(_) => return None,
+            }
+        }
+
+        Some(result)
+    }
+
+    // STATIC METHODS ---------------------------------------------------------
+
+    /// Parses a `StringLiteral`, either raw or not.
+    pub fn parse(reader: &mut Reader, context: &mut ParserContext) -> ParserResult<StringLiteral> {
+        match Self::parse_raw(reader, context) {
+            Ok(v) => return Ok(v),
+            Err(ParserResultError::NotFound) => { /* Ignore because not found */ }
+            Err(ParserResultError::Error) => return Err(ParserResultError::Error),
+        }
+
+        cursor_manager(reader, |reader, init_cursor| {
+            if !reader.read(STRING_DELIMITER) {
+                return Err(ParserResultError::NotFound);
+            }
+
+            let mut segments = Vec::new();
+            let mut text_cursor = reader.save_cursor();
+
+            loop {
+                let _ = reader.read_until_one_of(SPECIAL_CHARS, true);
+
+                if reader.continues_with(STRING_DELIMITER) {
+                    Self::push_text(reader, &text_cursor, &mut segments);
+                    reader.read(STRING_DELIMITER);
+                    break;
+                }
+
+                if reader.continues_with(ESCAPE_TOKEN) {
+                    Self::push_text(reader, &text_cursor, &mut segments);
+                    let escape = StringEscape::parse(reader, context)?;
+                    segments.push(StringSegment::Escape(Arc::new(escape)));
+                    text_cursor = reader.save_cursor();
+                    continue;
+                }
+
+                if reader.continues_with(INTERPOLATION_OPEN_TOKEN) {
+                    Self::push_text(reader, &text_cursor, &mut segments);
+                    let interpolation = StringInterpolation::parse(reader, context)?;
+                    segments.push(StringSegment::Interpolation(Arc::new(interpolation)));
+                    text_cursor = reader.save_cursor();
+                    continue;
+                }
+
+                // A single '$' that does not start an interpolation.
+                if reader.read_one().is_some() {
+                    continue;
+                }
+
+                Self::add_without_end_token_error(
+                    reader,
+                    context,
+                    init_cursor.byte_offset(),
+                    STRING_DELIMITER,
+                );
+
+                return Err(ParserResultError::Error);
+            }
+
+            Ok(StringLiteral {
+                span: Arc::new(reader.substring_to_current(init_cursor)),
+                is_raw: false,
+                repeated_tokens: 0,
+                segments,
+            })
+        })
+    }
+
+    /// Parses a raw `StringLiteral`.
+    pub fn parse_raw(
+        reader: &mut Reader,
+        context: &mut ParserContext,
+    ) -> ParserResult<StringLiteral> {
+        cursor_manager(reader, |reader, init_cursor| {
+            if !reader.read(RAW_STRING_PREFIX) {
+                return Err(ParserResultError::NotFound);
+            }
+
+            // Read opening tokens to build close ones.
+            let mut close_token = STRING_DELIMITER.to_string();
+            let mut repeated_tokens = 0;
+            while reader.read(RAW_STRING_REPEAT_TOKEN) {
+                close_token.push_str(RAW_STRING_REPEAT_TOKEN);
+                repeated_tokens += 1;
+            }
+
+            if !reader.read(STRING_DELIMITER) {
+                return Err(ParserResultError::NotFound);
+            }
+
+            let text_cursor = reader.save_cursor();
+            if reader.read_until(close_token.as_str(), false).is_none() {
+                Self::add_without_end_token_error(
+                    reader,
+                    context,
+                    init_cursor.byte_offset(),
+                    close_token.as_str(),
+                );
+
+                return Err(ParserResultError::Error);
+            }
+
+            let mut segments = Vec::new();
+            Self::push_text(reader, &text_cursor, &mut segments);
+
+            // The close token is always there because `read_until` found it.
+            let has_close_token = reader.read(close_token.as_str());
+            debug_assert!(has_close_token, "The close token must follow the text");
+
+            Ok(StringLiteral {
+                span: Arc::new(reader.substring_to_current(init_cursor)),
+                is_raw: true,
+                repeated_tokens,
+                segments,
+            })
+        })
+    }
+
+    /// Adds a text segment from `text_cursor` to the current position if it is not empty.
+    fn push_text(reader: &Reader, text_cursor: &Cursor, segments: &mut Vec<StringSegment>) {
+        if reader.byte_offset() != text_cursor.byte_offset() {
+            let span = Arc::new(reader.substring_to_current(text_cursor));
+            segments.push(StringSegment::Text(span));
+        }
+    }
+
+    fn add_without_end_token_error(
+        reader: &Reader,
+        context: &mut ParserContext,
+        init_offset: usize,
+        close_token: &str,
+    ) {
+        context.add_message(generate_error_log(
+            ParserError::StringWithoutEndToken,
+            format!(
+                "The end token '{}' was expected here to close the string",
+                close_token
+            ),
+            |log| {
+                generate_source_code(log, reader, |doc| {
+                    doc.highlight_section(
+                        init_offset..reader.byte_offset(),
+                        None,
+                        Some(Color::Magenta),
+                    )
+                    .highlight_cursor(
+                        reader.byte_offset(),
+                        Some(Arc::new(format!(
+                            "Insert here the close token '{}'",
+                            close_token
+                        ))),
+                        None,
+                    )
+                })
+            },
+        ));
+    }
+}
+
+impl ParserNode for StringLiteral {
+    fn span(&self) -> &Arc<Span> {
+        &self.span
+    }
+}
+
+impl StringEscape {
+    // GETTERS ----------------------------------------------------------------
+
+    /// The character the escape sequence represents.
+    pub fn value(&self) -> char {
+        self.value
+    }
+
+    // STATIC METHODS ---------------------------------------------------------
+
+    /// Parses a `StringEscape`.
+    pub fn parse(reader: &mut Reader, context: &mut ParserContext) -> ParserResult<StringEscape> {
+        cursor_manager(reader, |reader, init_cursor| {
+            if !reader.read(ESCAPE_TOKEN) {
+                return Err(ParserResultError::NotFound);
+            }
+
+            if reader.read(UNICODE_ESCAPE_OPEN_TOKEN) {
+                return Self::parse_unicode(reader, context, init_cursor);
+            }
+
+            let value = match reader.read_one() {
+                Some('n') => '\n',
+                Some('t') => '\t',
+                Some('r') => '\r',
+                Some('0') => '\0',
+                Some('\\') => '\\',
+                Some('"') => '"',
+                Some('$') => '$',
+                _ => {
+                    context.add_message(generate_error_log(
+                        ParserError::StringWithInvalidEscape,
+                        "Unknown escape sequence".to_string(),
+                        |log| {
+                            generate_source_code(log, reader, |doc| {
+                                doc.highlight_section_str(
+                                    init_cursor.byte_offset()..reader.byte_offset(),
+                                    Some("Valid escapes are: \\n, \\t, \\r, \\0, \\\\, \\\", \\$ and \\u{...}"),
+                                    None,
+                                )
+                            })
+                        },
+                    ));
+
+                    return Err(ParserResultError::Error);
+                }
+            };
+
+            Ok(StringEscape {
+                span: Arc::new(reader.substring_to_current(init_cursor)),
+                value,
+            })
+        })
+    }
+
+    fn parse_unicode(
+        reader: &mut Reader,
+        context: &mut ParserContext,
+        init_cursor: &Cursor,
+    ) -> ParserResult<StringEscape> {
+        let digits_cursor = reader.save_cursor();
+        let digits = reader
+            .read_many_of(HEXADECIMAL_DIGIT_CHARS)
+            .unwrap_or("")
+            .to_string();
+        let digits_end_cursor = reader.save_cursor();
+
+        let value = if digits.is_empty() || digits.len() > UNICODE_ESCAPE_MAX_DIGITS {
+            None
+        } else {
+            u32::from_str_radix(digits.as_str(), 16)
+                .ok()
+                .and_then(std::char::from_u32)
+        };
+
+        if !reader.read(UNICODE_ESCAPE_CLOSE_TOKEN) || value.is_none() {
+            context.add_message(generate_error_log(
+                ParserError::StringWithInvalidUnicodeEscape,
+                format!(
+                    "A unicode escape must contain between 1 and {} hexadecimal digits representing a valid unicode character",
+                    UNICODE_ESCAPE_MAX_DIGITS
+                ),
+                |log| {
+                    generate_source_code(log, reader, |doc| {
+                        doc.highlight_section(
+                            init_cursor.byte_offset()..digits_cursor.byte_offset(),
+                            None,
+                            Some(Color::Magenta),
+                        )
+                        .highlight_section_str(
+                            digits_cursor.byte_offset()..digits_end_cursor.byte_offset(),
+                            Some("Invalid unicode character"),
+                            None,
+                        )
+                    })
+                },
+            ));
+
+            return Err(ParserResultError::Error);
+        }
+
+        Ok(StringEscape {
+            span: Arc::new(reader.substring_to_current(init_cursor)),
+            value: value.unwrap(),
+        })
+    }
+}
+
+impl ParserNode for StringEscape {
+    fn span(&self) -> &Arc<Span> {
+        &self.span
+    }
+}
+
+impl StringInterpolation {
+    // GETTERS ----------------------------------------------------------------
+
+    pub fn expression(&self) -> &Arc<Expression> {
+        &self.expression
+    }
+
+    pub fn pre_expression_whitespace(&self) -> &Arc<Whitespace> {
+        &self.pre_expression_whitespace
+    }
+
+    pub fn post_expression_whitespace(&self) -> &Arc<Whitespace> {
+        &self.post_expression_whitespace
+    }
+
+    // STATIC METHODS ---------------------------------------------------------
+
+    /// Parses a `StringInterpolation`.
+    pub fn parse(
+        reader: &mut Reader,
+        context: &mut ParserContext,
+    ) -> ParserResult<StringInterpolation> {
+        cursor_manager(reader, |reader, init_cursor| {
+            if !reader.read(INTERPOLATION_OPEN_TOKEN) {
+                return Err(ParserResultError::NotFound);
+            }
+
+            let pre_expression_whitespace = Whitespace::parse_multiline_or_default(reader, context);
+
+            let expression = match Expression::parse(reader, context) {
+                Ok(v) => v,
+                Err(ParserResultError::NotFound) => {
+                    context.add_message(generate_error_log(
+                        ParserError::StringInterpolationWithoutExpression,
+                        "An expression was expected inside the interpolation".to_string(),
+                        |log| {
+                            generate_source_code(log, reader, |doc| {
+                                doc.highlight_section(
+                                    init_cursor.byte_offset()
+                                        ..pre_expression_whitespace
+                                            .span()
+                                            .start_cursor()
+                                            .byte_offset(),
+                                    None,
+                                    Some(Color::Magenta),
+                                )
+                                .highlight_cursor_str(
+                                    pre_expression_whitespace
+                                        .span()
+                                        .start_cursor()
+                                        .byte_offset(),
+                                    Some("Insert an expression here"),
+                                    None,
+                                )
+                            })
+                        },
+                    ));
+
+                    return Err(ParserResultError::Error);
+                }
+                Err(ParserResultError::Error) => return Err(ParserResultError::Error),
+            };
+
+            let post_expression_whitespace =
+                Whitespace::parse_multiline_or_default(reader, context);
+
+            if !reader.read(INTERPOLATION_CLOSE_TOKEN) {
+                context.add_message(generate_error_log(
+                    ParserError::StringInterpolationWithoutEndToken,
+                    format!(
+                        "The end token '{}' was expected here to close the interpolation",
+                        INTERPOLATION_CLOSE_TOKEN
+                    ),
+                    |log| {
+                        generate_source_code(log, reader, |doc| {
+                            doc.highlight_section(
+                                init_cursor.byte_offset()..reader.byte_offset(),
+                                None,
+                                Some(Color::Magenta),
+                            )
+                            .highlight_cursor(
+                                reader.byte_offset(),
+                                Some(Arc::new(format!(
+                                    "Insert here the close token '{}'",
+                                    INTERPOLATION_CLOSE_TOKEN
+                                ))),
+                                None,
+                            )
+                        })
+                    },
+                ));
+
+                return Err(ParserResultError::Error);
+            }
+
+            Ok(StringInterpolation {
+                span: Arc::new(reader.substring_to_current(init_cursor)),
+                expression: Arc::new(expression),
+                pre_expression_whitespace: Arc::new(pre_expression_whitespace),
+                post_expression_whitespace: Arc::new(post_expression_whitespace),
+            })
+        })
+    }
+}
+
+impl ParserNode for StringInterpolation {
+    fn span(&self) -> &Arc<Span> {
+        &self.span
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use crate::test::{assert_error, assert_not_found};
+
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let mut reader = Reader::from_str("\"This is a string\"/rest");
+        let mut context = ParserContext::default();
+        let literal =
+            StringLiteral::parse(&mut reader, &mut context).expect("The parser must succeed");
+
+        assert_eq!(
+            literal.content(),
+            "\"This is a string\"",
+            "The content is incorrect"
+        );
+        assert!(!literal.is_raw, "The is_raw is incorrect");
+        assert_eq!(literal.segments.len(), 1, "The segments.len is incorrect");
+        assert_eq!(
+            literal.value(),
+            Some("This is a string".to_string()),
+            "The value is incorrect"
+        );
+    }
+
+    #[test]
+    fn test_parse_empty() {
+        let mut reader = Reader::from_str("\"\"");
+        let mut context = ParserContext::default();
+        let literal =
+            StringLiteral::parse(&mut reader, &mut context).expect("The parser must succeed");
+
+        assert_eq!(literal.content(), "\"\"", "The content is incorrect");
+        assert_eq!(literal.segments.len(), 0, "The segments.len is incorrect");
+        assert_eq!(
+            literal.value(),
+            Some("".to_string()),
+            "The value is incorrect"
+        );
+    }
+
+    #[test]
+    fn test_parse_escapes() {
+        let mut reader = Reader::from_str("\"a\\n\\t\\r\\0\\\\\\\"\\$b\\u{41}\\u{1F600}\"");
+        let mut context = ParserContext::default();
+        let literal =
+            StringLiteral::parse(&mut reader, &mut context).expect("The parser must succeed");
+
+        assert_eq!(literal.segments.len(), 11, "The segments.len is incorrect");
+        assert_eq!(
+            literal.value(),
+            Some("a\n\t\r\0\\\"$bA\u{1F600}".to_string()),
+            "The value is incorrect"
+        );
+
+        match &literal.segments[1] {
+            StringSegment::Escape(v) => {
+                assert_eq!(v.content(), "\\n", "The segment[1] is incorrect");
+            }
+            _ => panic!("Incorrect segment type for 1"),
+        }
+    }
+
+    #[test]
+    fn test_parse_dollar_without_interpolation() {
+        let mut reader = Reader::from_str("\"$a$\"");
+        let mut context = ParserContext::default();
+        let literal =
+            StringLiteral::parse(&mut reader, &mut context).expect("The parser must succeed");
+
+        assert_eq!(literal.segments.len(), 1, "The segments.len is incorrect");
+        assert_eq!(
+            literal.value(),
+            Some("$a$".to_string()),
+            "The value is incorrect"
+        );
+    }
+
+    #[test]
+    fn test_parse_interpolation() {
+        let mut reader = Reader::from_str("\"a ${ b } c${25}\"");
+        let mut context = ParserContext::default();
+        let literal =
+            StringLiteral::parse(&mut reader, &mut context).expect("The parser must succeed");
+
+        assert_eq!(literal.segments.len(), 4, "The segments.len is incorrect");
+        assert_eq!(literal.value(), None, "The value is incorrect");
+        assert!(
+            literal.has_interpolations(),
+            "The string must be interpolated"
+        );
+
+        match &literal.segments[1] {
+            StringSegment::Interpolation(v) => {
+                assert_eq!(v.content(), "${ b }", "The segment[1] is incorrect");
+
+                if let Expression::VariableAccess(identifier) = v.expression.as_ref() {
+                    assert_eq!(identifier.content(), "b", "The expression is incorrect");
+                } else {
+                    panic!("The expression is incorrect");
+                }
+            }
+            _ => panic!("Incorrect segment type for 1"),
+        }
+
+        match &literal.segments[2] {
+            StringSegment::Text(v) => {
+                assert_eq!(v.content(), " c", "The segment[2] is incorrect");
+            }
+            _ => panic!("Incorrect segment type for 2"),
+        }
+    }
+
+    #[test]
+    fn test_parse_raw() {
+        let mut reader = Reader::from_str("r\"a\\n${b}\"");
+        let mut context = ParserContext::default();
+        let literal =
+            StringLiteral::parse(&mut reader, &mut context).expect("The parser must succeed");
+
+        assert!(literal.is_raw, "The is_raw is incorrect");
+        assert_eq!(
+            literal.repeated_tokens, 0,
+            "The repeated_tokens is incorrect"
+        );
+        assert_eq!(
+            literal.value(),
+            Some("a\\n${b}".to_string()),
+            "The value is incorrect"
+        );
+
+        let mut reader = Reader::from_str("r##\"a\"#b\"##");
+        let mut context = ParserContext::default();
+        let literal =
+            StringLiteral::parse(&mut reader, &mut context).expect("The parser must succeed");
+
+        assert!(literal.is_raw, "The is_raw is incorrect");
+        assert_eq!(
+            literal.repeated_tokens, 2,
+            "The repeated_tokens is incorrect"
+        );
+        assert_eq!(
+            literal.value(),
+            Some("a\"#b".to_string()),
+            "The value is incorrect"
+        );
+    }
+
+    #[test]
+    fn test_parse_err_not_found() {
+        for content in &["", "-", "r", "r#", "raw"] {
+            let mut reader = Reader::from_str(content);
+            let mut context = ParserContext::default();
+            let error = StringLiteral::parse(&mut reader, &mut context)
+                .expect_err("The parser must not succeed");
+
+            assert_not_found(&context, &error, 0);
+        }
+    }
+
+    #[test]
+    fn test_parse_err_without_end_token() {
+        for content in &["\"test", "\"test\\\"", "r\"test", "r#\"test\""] {
+            let mut reader = Reader::from_str(content);
+            let mut context = ParserContext::default();
+            let error = StringLiteral::parse(&mut reader, &mut context)
+                .expect_err("The parser must not succeed");
+
+            assert_error(&context, &error, ParserError::StringWithoutEndToken);
+        }
+    }
+
+    #[test]
+    fn test_parse_err_invalid_escape() {
+        for content in &["\"\\a\"", "\"\\"] {
+            let mut reader = Reader::from_str(content);
+            let mut context = ParserContext::default();
+            let error = StringLiteral::parse(&mut reader, &mut context)
+                .expect_err("The parser must not succeed");
+
+            assert_error(&context, &error, ParserError::StringWithInvalidEscape);
+        }
+    }
+
+    #[test]
+    fn test_parse_err_invalid_unicode_escape() {
+        for content in &[
+            "\"\\u{}\"",
+            "\"\\u{1234567}\"",
+            "\"\\u{D800}\"",
+            "\"\\u{41\"",
+            "\"\\u{x}\"",
+        ] {
+            let mut reader = Reader::from_str(content);
+            let mut context = ParserContext::default();
+            let error = StringLiteral::parse(&mut reader, &mut context)
+                .expect_err("The parser must not succeed");
+
+            assert_error(
+                &context,
+                &error,
+                ParserError::StringWithInvalidUnicodeEscape,
+            );
+        }
+    }
+
+    #[test]
+    fn test_parse_err_interpolation_without_expression() {
+        let mut reader = Reader::from_str("\"${ }\"");
+        let mut context = ParserContext::default();
+        let error = StringLiteral::parse(&mut reader, &mut context)
+            .expect_err("The parser must not succeed");
+
+        assert_error(
+            &context,
+            &error,
+            ParserError::StringInterpolationWithoutExpression,
+        );
+    }
+
+    #[test]
+    fn test_parse_err_interpolation_without_end_token() {
+        let mut reader = Reader::from_str("\"${a\"");
+        let mut context = ParserContext::default();
+        let error = StringLiteral::parse(&mut reader, &mut context)
+            .expect_err("The parser must not succeed");
+
+        assert_error(
+            &context,
+            &error,
+            ParserError::StringInterpolationWithoutEndToken,
+        );
+    }
+}
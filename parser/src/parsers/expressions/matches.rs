@@ -0,0 +1,578 @@
+use std::sync::Arc;
+
+use doclog::Color;
+
+use crate::context::{Binding, Mutability, ParserContext};
+use crate::io::{Cursor, Reader, Span};
+use crate::parsers::commons::identifier::Identifier;
+use crate::parsers::commons::sequences::{Sequence, SequenceDefinition, SequenceElement};
+use crate::parsers::commons::whitespaces::Whitespace;
+use crate::parsers::expressions::conditionals::IF_KEYWORD;
+use crate::parsers::expressions::lambdas::ARROW_TOKEN;
+use crate::parsers::expressions::Expression;
+use crate::parsers::patterns::Pattern;
+use crate::parsers::utils::{cursor_manager, generate_error_log, generate_source_code};
+use crate::parsers::{ParserResult, ParserResultError};
+use crate::{ParserError, ParserNode};
+
+pub static MATCH_KEYWORD: &str = "match";
+pub static MATCH_ARMS_DEFINITION: SequenceDefinition = SequenceDefinition {
+    open_token: "{",
+    close_token: "}",
+    name: "match",
+    missing_separator_error: ParserError::MissingCommaInMatch,
+    without_end_token_error: ParserError::MatchWithoutEndToken,
+};
+
+/// An expression that compares a value against a list of patterns and evaluates
+/// the arm of the first one that matches, e.g. `match a { 0 -> "zero", _ -> "other" }`.
+#[derive(Debug)]
+pub struct MatchExpression {
+    span: Arc<Span>,
+    subject: Arc<Expression>,
+    arms: Sequence<MatchArm>,
+    pre_subject_whitespace: Arc<Whitespace>,
+    pre_arms_whitespace: Arc<Whitespace>,
+}
+
+impl MatchExpression {
+    // GETTERS ----------------------------------------------------------------
+
+    pub fn subject(&self) -> &Arc<Expression> {
+        &self.subject
+    }
+
+    /// The arms of the match with their surrounding whitespaces.
+    pub fn arms(&self) -> &Vec<SequenceElement<MatchArm>> {
+        self.arms.elements()
+    }
+
+    pub fn arm_sequence(&self) -> &Sequence<MatchArm> {
+        &self.arms
+    }
+
+    pub fn pre_subject_whitespace(&self) -> &Arc<Whitespace> {
+        &self.pre_subject_whitespace
+    }
+
+    pub fn pre_arms_whitespace(&self) -> &Arc<Whitespace> {
+        &self.pre_arms_whitespace
+    }
+
+    // STATIC METHODS ---------------------------------------------------------
+
+    /// Parses a `MatchExpression`.
+    pub fn parse(
+        reader: &mut Reader,
+        context: &mut ParserContext,
+    ) -> ParserResult<MatchExpression> {
+        cursor_manager(reader, |reader, init_cursor| {
+            if !Identifier::parse_keyword(reader, context, MATCH_KEYWORD) {
+                return Err(ParserResultError::NotFound);
+            }
+
+            let pre_subject_whitespace = Whitespace::parse_multiline_or_default(reader, context);
+
+            let subject = match Expression::parse_before_block(reader, context) {
+                Ok(v) => v,
+                Err(ParserResultError::NotFound) => {
+                    context.add_message(generate_error_log(
+                        ParserError::MissingSubjectInMatchExpression,
+                        "An expression is expected after the 'match' keyword".to_string(),
+                        |log| {
+                            generate_source_code(log, reader, |doc| {
+                                doc.highlight_section(
+                                    init_cursor.byte_offset()
+                                        ..pre_subject_whitespace
+                                            .span()
+                                            .start_cursor()
+                                            .byte_offset(),
+                                    None,
+                                    Some(Color::Magenta),
+                                )
+                                .highlight_cursor_str(
+                                    pre_subject_whitespace.span().start_cursor().byte_offset(),
+                                    Some("Insert an expression here"),
+                                    None,
+                                )
+                            })
+                        },
+                    ));
+
+                    return Err(ParserResultError::Error);
+                }
+                Err(ParserResultError::Error) => return Err(ParserResultError::Error),
+            };
+
+            let pre_arms_whitespace = Whitespace::parse_multiline_or_default(reader, context);
+
+            let arms =
+                match Sequence::parse(reader, context, &MATCH_ARMS_DEFINITION, MatchArm::parse) {
+                    Ok(v) => v,
+                    Err(ParserResultError::NotFound) => {
+                        context.add_message(generate_error_log(
+                            ParserError::MissingArmsInMatchExpression,
+                            format!(
+                                "The arms of the match are expected here between '{}' and '{}'",
+                                MATCH_ARMS_DEFINITION.open_token, MATCH_ARMS_DEFINITION.close_token
+                            ),
+                            |log| {
+                                generate_source_code(log, reader, |doc| {
+                                    doc.highlight_section(
+                                        init_cursor.byte_offset()..reader.byte_offset(),
+                                        None,
+                                        Some(Color::Magenta),
+                                    )
+                                    .highlight_cursor_str(
+                                        reader.byte_offset(),
+                                        Some("Insert the arms here"),
+                                        None,
+                                    )
+                                })
+                            },
+                        ));
+
+                        return Err(ParserResultError::Error);
+                    }
+                    Err(ParserResultError::Error) => return Err(ParserResultError::Error),
+                };
+
+            Ok(MatchExpression {
+                span: Arc::new(reader.substring_to_current(init_cursor)),
+                subject: Arc::new(subject),
+                arms,
+                pre_subject_whitespace: Arc::new(pre_subject_whitespace),
+                pre_arms_whitespace: Arc::new(pre_arms_whitespace),
+            })
+        })
+    }
+}
+
+impl ParserNode for MatchExpression {
+    fn span(&self) -> &Arc<Span> {
+        &self.span
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+/// An arm of a `MatchExpression`, e.g. `(a, _) if a > 0 -> a`.
+#[derive(Debug)]
+pub struct MatchArm {
+    span: Arc<Span>,
+    pattern: Arc<Pattern>,
+    guard: Option<Arc<MatchGuard>>,
+    expression: Arc<Expression>,
+    pre_guard_whitespace: Option<Arc<Whitespace>>,
+    pre_arrow_whitespace: Arc<Whitespace>,
+    pre_expression_whitespace: Arc<Whitespace>,
+}
+
+impl MatchArm {
+    // GETTERS ----------------------------------------------------------------
+
+    pub fn pattern(&self) -> &Arc<Pattern> {
+        &self.pattern
+    }
+
+    pub fn guard(&self) -> &Option<Arc<MatchGuard>> {
+        &self.guard
+    }
+
+    pub fn expression(&self) -> &Arc<Expression> {
+        &self.expression
+    }
+
+    pub fn pre_guard_whitespace(&self) -> &Option<Arc<Whitespace>> {
+        &self.pre_guard_whitespace
+    }
+
+    pub fn pre_arrow_whitespace(&self) -> &Arc<Whitespace> {
+        &self.pre_arrow_whitespace
+    }
+
+    pub fn pre_expression_whitespace(&self) -> &Arc<Whitespace> {
+        &self.pre_expression_whitespace
+    }
+
+    // STATIC METHODS ---------------------------------------------------------
+
+    /// Parses a `MatchArm`.
+    pub fn parse(reader: &mut Reader, context: &mut ParserContext) -> ParserResult<MatchArm> {
+        cursor_manager(reader, |reader, init_cursor| {
+            let pattern = Pattern::parse(reader, context)?;
+
+            // The bindings of the pattern are only visible inside the arm.
+            context.push_scope();
+
+            for name in pattern.bindings() {
+                context.declare(
+                    name.content().to_string(),
+                    Binding::new(name.span().clone(), Mutability::Immutable),
+                );
+            }
+
+            let result = Self::parse_after_pattern(reader, context, init_cursor, pattern);
+            context.pop_scope();
+            result
+        })
+    }
+
+    /// Parses the rest of the arm after its pattern.
+    fn parse_after_pattern(
+        reader: &mut Reader,
+        context: &mut ParserContext,
+        init_cursor: &Cursor,
+        pattern: Pattern,
+    ) -> ParserResult<MatchArm> {
+        // Guard.
+        let pre_guard_cursor = reader.save_cursor();
+        let pre_guard_whitespace = Whitespace::parse_multiline_or_default(reader, context);
+        let (guard, pre_guard_whitespace) = match MatchGuard::parse(reader, context) {
+            Ok(v) => (Some(Arc::new(v)), Some(Arc::new(pre_guard_whitespace))),
+            Err(ParserResultError::NotFound) => {
+                reader.restore(pre_guard_cursor);
+                (None, None)
+            }
+            Err(ParserResultError::Error) => return Err(ParserResultError::Error),
+        };
+
+        let pre_arrow_whitespace = Whitespace::parse_multiline_or_default(reader, context);
+
+        if !reader.read(ARROW_TOKEN) {
+            context.add_message(generate_error_log(
+                ParserError::MissingArrowInMatchArm,
+                format!(
+                    "The arrow '{}' is expected after the pattern of the match arm",
+                    ARROW_TOKEN
+                ),
+                |log| {
+                    generate_source_code(log, reader, |doc| {
+                        doc.highlight_section(
+                            init_cursor.byte_offset()
+                                ..pre_arrow_whitespace.span().start_cursor().byte_offset(),
+                            None,
+                            Some(Color::Magenta),
+                        )
+                        .highlight_cursor(
+                            pre_arrow_whitespace.span().start_cursor().byte_offset(),
+                            Some(Arc::new(format!("Insert the arrow '{}' here", ARROW_TOKEN))),
+                            None,
+                        )
+                    })
+                },
+            ));
+
+            return Err(ParserResultError::Error);
+        }
+
+        let pre_expression_whitespace = Whitespace::parse_multiline_or_default(reader, context);
+
+        let expression = match Expression::parse(reader, context) {
+            Ok(v) => v,
+            Err(ParserResultError::NotFound) => {
+                context.add_message(generate_error_log(
+                    ParserError::MissingExpressionInMatchArm,
+                    format!(
+                        "An expression is expected after the arrow '{}' of the match arm",
+                        ARROW_TOKEN
+                    ),
+                    |log| {
+                        generate_source_code(log, reader, |doc| {
+                            doc.highlight_section(
+                                init_cursor.byte_offset()..reader.byte_offset(),
+                                None,
+                                Some(Color::Magenta),
+                            )
+                            .highlight_cursor_str(
+                                reader.byte_offset(),
+                                Some("Insert an expression here"),
+                                None,
+                            )
+                        })
+                    },
+                ));
+
+                return Err(ParserResultError::Error);
+            }
+            Err(ParserResultError::Error) => return Err(ParserResultError::Error),
+        };
+
+        Ok(MatchArm {
+            span: Arc::new(reader.substring_to_current(init_cursor)),
+            pattern: Arc::new(pattern),
+            guard,
+            expression: Arc::new(expression),
+            pre_guard_whitespace,
+            pre_arrow_whitespace: Arc::new(pre_arrow_whitespace),
+            pre_expression_whitespace: Arc::new(pre_expression_whitespace),
+        })
+    }
+}
+
+impl ParserNode for MatchArm {
+    fn span(&self) -> &Arc<Span> {
+        &self.span
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+/// An extra condition of a `MatchArm` that must be true for the arm to match,
+/// e.g. `if a > 0`.
+#[derive(Debug)]
+pub struct MatchGuard {
+    span: Arc<Span>,
+    condition: Arc<Expression>,
+    pre_condition_whitespace: Arc<Whitespace>,
+}
+
+impl MatchGuard {
+    // GETTERS ----------------------------------------------------------------
+
+    pub fn condition(&self) -> &Arc<Expression> {
+        &self.condition
+    }
+
+    pub fn pre_condition_whitespace(&self) -> &Arc<Whitespace> {
+        &self.pre_condition_whitespace
+    }
+
+    // STATIC METHODS ---------------------------------------------------------
+
+    /// Parses a `MatchGuard`.
+    pub fn parse(reader: &mut Reader, context: &mut ParserContext) -> ParserResult<MatchGuard> {
+        cursor_manager(reader, |reader, init_cursor| {
+            if !Identifier::parse_keyword(reader, context, IF_KEYWORD) {
+                return Err(ParserResultError::NotFound);
+            }
+
+            let pre_condition_whitespace = Whitespace::parse_multiline_or_default(reader, context);
+
+            let condition = match Expression::parse(reader, context) {
+                Ok(v) => v,
+                Err(ParserResultError::NotFound) => {
+                    context.add_message(generate_error_log(
+                        ParserError::MissingConditionInMatchGuard,
+                        "A condition is expected after the 'if' keyword of the guard".to_string(),
+                        |log| {
+                            generate_source_code(log, reader, |doc| {
+                                doc.highlight_section(
+                                    init_cursor.byte_offset()
+                                        ..pre_condition_whitespace
+                                            .span()
+                                            .start_cursor()
+                                            .byte_offset(),
+                                    None,
+                                    Some(Color::Magenta),
+                                )
+                                .highlight_cursor_str(
+                                    pre_condition_whitespace.span().start_cursor().byte_offset(),
+                                    Some("Insert an expression here"),
+                                    None,
+                                )
+                            })
+                        },
+                    ));
+
+                    return Err(ParserResultError::Error);
+                }
+                Err(ParserResultError::Error) => return Err(ParserResultError::Error),
+            };
+
+            Ok(MatchGuard {
+                span: Arc::new(reader.substring_to_current(init_cursor)),
+                condition: Arc::new(condition),
+                pre_condition_whitespace: Arc::new(pre_condition_whitespace),
+            })
+        })
+    }
+}
+
+impl ParserNode for MatchGuard {
+    fn span(&self) -> &Arc<Span> {
+        &self.span
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use crate::test::{assert_error, assert_not_found};
+
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let mut reader =
+            Reader::from_str("match (a, b) {\n  (0, _) -> \"zero\",\n  (x, 1..=9) if x > 0 -> x,\n  _ -> nil,\n}/rest");
+        let mut context = ParserContext::default();
+        let expression =
+            MatchExpression::parse(&mut reader, &mut context).expect("The parser must succeed");
+
+        assert_eq!(
+            expression.content(),
+            "match (a, b) {\n  (0, _) -> \"zero\",\n  (x, 1..=9) if x > 0 -> x,\n  _ -> nil,\n}",
+            "The content is incorrect"
+        );
+        assert_eq!(
+            expression.subject.content(),
+            "(a, b)",
+            "The subject is incorrect"
+        );
+        assert_eq!(expression.arms().len(), 3, "The arms length is incorrect");
+
+        let arm = expression.arms()[1].node();
+        assert_eq!(
+            arm.pattern.content(),
+            "(x, 1..=9)",
+            "The pattern is incorrect"
+        );
+        assert_eq!(
+            arm.guard.as_ref().map(|v| v.condition.content()),
+            Some("x > 0"),
+            "The guard is incorrect"
+        );
+        assert_eq!(arm.expression.content(), "x", "The expression is incorrect");
+
+        let arm = expression.arms()[2].node();
+        assert!(arm.guard.is_none(), "The guard must be empty");
+    }
+
+    #[test]
+    fn test_parse_open_range_subject() {
+        let mut reader = Reader::from_str("match 0.. {\n  _ -> nil,\n}");
+        let mut context = ParserContext::default();
+        let expression =
+            MatchExpression::parse(&mut reader, &mut context).expect("The parser must succeed");
+
+        assert_eq!(
+            expression.subject.content(),
+            "0..",
+            "The subject is incorrect"
+        );
+        assert_eq!(expression.arms().len(), 1, "The arms length is incorrect");
+    }
+
+    #[test]
+    fn test_parse_empty() {
+        let mut reader = Reader::from_str("match a {}");
+        let mut context = ParserContext::default();
+        let expression =
+            MatchExpression::parse(&mut reader, &mut context).expect("The parser must succeed");
+
+        assert!(expression.arms().is_empty(), "The arms must be empty");
+    }
+
+    #[test]
+    fn test_parse_as_expression() {
+        let mut reader = Reader::from_str("match a { 0b1 -> 1, _ -> 2 } + 1");
+        let mut context = ParserContext::default();
+        let expression =
+            Expression::parse(&mut reader, &mut context).expect("The parser must succeed");
+
+        assert!(
+            matches!(expression, Expression::Binary(_)),
+            "The expression type is incorrect"
+        );
+    }
+
+    #[test]
+    fn test_parse_err_not_found() {
+        let mut reader = Reader::from_str("matches { }");
+        let mut context = ParserContext::default();
+        let error = MatchExpression::parse(&mut reader, &mut context)
+            .expect_err("The parser must not succeed");
+
+        assert_not_found(&context, &error, 0);
+    }
+
+    #[test]
+    fn test_parse_err_missing_subject() {
+        for text in &["match { _ -> 1 }", "match"] {
+            let mut reader = Reader::from_str(text);
+            let mut context = ParserContext::default();
+            let error = MatchExpression::parse(&mut reader, &mut context)
+                .expect_err("The parser must not succeed");
+
+            assert_error(
+                &context,
+                &error,
+                ParserError::MissingSubjectInMatchExpression,
+            );
+        }
+    }
+
+    #[test]
+    fn test_parse_err_missing_arms() {
+        for text in &["match a", "match a -> 1"] {
+            let mut reader = Reader::from_str(text);
+            let mut context = ParserContext::default();
+            let error = MatchExpression::parse(&mut reader, &mut context)
+                .expect_err("The parser must not succeed");
+
+            assert_error(&context, &error, ParserError::MissingArmsInMatchExpression);
+        }
+    }
+
+    #[test]
+    fn test_parse_err_without_end_token() {
+        for text in &["match a { _ -> 1", "match a { _ -> 1, + }"] {
+            let mut reader = Reader::from_str(text);
+            let mut context = ParserContext::default();
+            let error = MatchExpression::parse(&mut reader, &mut context)
+                .expect_err("The parser must not succeed");
+
+            assert_error(&context, &error, ParserError::MatchWithoutEndToken);
+        }
+    }
+
+    #[test]
+    fn test_parse_err_missing_comma() {
+        let mut reader = Reader::from_str("match a {\n  0 -> 1\n  _ -> 2\n}");
+        let mut context = ParserContext::default();
+        let error = MatchExpression::parse(&mut reader, &mut context)
+            .expect_err("The parser must not succeed");
+
+        assert_error(&context, &error, ParserError::MissingCommaInMatch);
+    }
+
+    #[test]
+    fn test_parse_err_missing_arrow() {
+        for text in &["match a { _ 1 }", "match a { x if x > 0 }"] {
+            let mut reader = Reader::from_str(text);
+            let mut context = ParserContext::default();
+            let error = MatchExpression::parse(&mut reader, &mut context)
+                .expect_err("The parser must not succeed");
+
+            assert_error(&context, &error, ParserError::MissingArrowInMatchArm);
+        }
+    }
+
+    #[test]
+    fn test_parse_err_missing_expression() {
+        let mut reader = Reader::from_str("match a { _ -> }");
+        let mut context = ParserContext::default();
+        let error = MatchExpression::parse(&mut reader, &mut context)
+            .expect_err("The parser must not succeed");
+
+        assert_error(&context, &error, ParserError::MissingExpressionInMatchArm);
+    }
+
+    #[test]
+    fn test_parse_err_missing_guard_condition() {
+        let mut reader = Reader::from_str("match a { _ if -> 1 }");
+        let mut context = ParserContext::default();
+        let error = MatchExpression::parse(&mut reader, &mut context)
+            .expect_err("The parser must not succeed");
+
+        assert_error(&context, &error, ParserError::MissingConditionInMatchGuard);
+    }
+}
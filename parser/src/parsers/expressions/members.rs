@@ -0,0 +1,292 @@
+use std::sync::Arc;
+
+use doclog::Color;
+
+use crate::context::ParserContext;
+use crate::io::{Reader, Span};
+use crate::parsers::commons::identifier::Identifier;
+use crate::parsers::commons::sequences::{Sequence, SequenceElement};
+use crate::parsers::commons::whitespaces::Whitespace;
+use crate::parsers::expressions::calls::{Argument, CallExpression, ARGUMENTS_DEFINITION};
+use crate::parsers::expressions::Expression;
+use crate::parsers::utils::{generate_error_log, generate_source_code};
+use crate::parsers::{ParserResult, ParserResultError};
+use crate::{ParserError, ParserNode};
+
+pub static MEMBER_ACCESS_TOKEN: &str = ".";
+
+/// An access to a member of an expression, e.g. `a.b`.
+///
+/// The dot can be placed at the beginning of a new line to split long chains.
+#[derive(Debug)]
+pub struct MemberAccess {
+    span: Arc<Span>,
+    object: Arc<Expression>,
+    member: Arc<Identifier>,
+    pre_access_whitespace: Arc<Whitespace>,
+}
+
+impl MemberAccess {
+    // GETTERS ----------------------------------------------------------------
+
+    pub fn object(&self) -> &Arc<Expression> {
+        &self.object
+    }
+
+    pub fn member(&self) -> &Arc<Identifier> {
+        &self.member
+    }
+
+    pub fn pre_access_whitespace(&self) -> &Arc<Whitespace> {
+        &self.pre_access_whitespace
+    }
+
+    // STATIC METHODS ---------------------------------------------------------
+
+    /// Parses an access to a member of `object`, or a call to one of its methods,
+    /// that must be placed just after the object. `pre_access_whitespace` is the whitespace
+    /// already read between the object and the dot.
+    pub fn parse(
+        reader: &mut Reader,
+        context: &mut ParserContext,
+        object: Expression,
+        pre_access_whitespace: Whitespace,
+    ) -> ParserResult<Expression> {
+        let dot_cursor = reader.save_cursor();
+        if !reader.read(MEMBER_ACCESS_TOKEN) {
+            return Err(ParserResultError::NotFound);
+        }
+
+        let member = match Identifier::parse(reader, context) {
+            Ok(v) => v,
+            Err(_) => {
+                context.add_message(generate_error_log(
+                    ParserError::MissingMemberNameAfterDot,
+                    "A member name is expected after the dot".to_string(),
+                    |log| {
+                        generate_source_code(log, reader, |doc| {
+                            doc.highlight_section(
+                                dot_cursor.byte_offset()..reader.byte_offset(),
+                                None,
+                                Some(Color::Magenta),
+                            )
+                            .highlight_cursor_str(
+                                reader.byte_offset(),
+                                Some("Insert an identifier here"),
+                                None,
+                            )
+                        })
+                    },
+                ));
+
+                return Err(ParserResultError::Error);
+            }
+        };
+
+        if reader.continues_with(ARGUMENTS_DEFINITION.open_token) {
+            let arguments = CallExpression::parse_arguments(reader, context)?;
+
+            return Ok(Expression::MethodCall(Arc::new(MethodCall {
+                span: Arc::new(reader.substring_to_current(object.span().start_cursor())),
+                object: Arc::new(object),
+                method: Arc::new(member),
+                arguments,
+                pre_access_whitespace: Arc::new(pre_access_whitespace),
+            })));
+        }
+
+        Ok(Expression::MemberAccess(Arc::new(MemberAccess {
+            span: Arc::new(reader.substring_to_current(object.span().start_cursor())),
+            object: Arc::new(object),
+            member: Arc::new(member),
+            pre_access_whitespace: Arc::new(pre_access_whitespace),
+        })))
+    }
+}
+
+impl ParserNode for MemberAccess {
+    fn span(&self) -> &Arc<Span> {
+        &self.span
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+/// A call to a method of an expression, e.g. `a.b(c)`.
+#[derive(Debug)]
+pub struct MethodCall {
+    span: Arc<Span>,
+    object: Arc<Expression>,
+    method: Arc<Identifier>,
+    arguments: Sequence<Argument>,
+    pre_access_whitespace: Arc<Whitespace>,
+}
+
+impl MethodCall {
+    // GETTERS ----------------------------------------------------------------
+
+    pub fn object(&self) -> &Arc<Expression> {
+        &self.object
+    }
+
+    pub fn method(&self) -> &Arc<Identifier> {
+        &self.method
+    }
+
+    /// The arguments of the call with their surrounding whitespaces.
+    pub fn arguments(&self) -> &Vec<SequenceElement<Argument>> {
+        self.arguments.elements()
+    }
+
+    pub fn argument_sequence(&self) -> &Sequence<Argument> {
+        &self.arguments
+    }
+
+    pub fn pre_access_whitespace(&self) -> &Arc<Whitespace> {
+        &self.pre_access_whitespace
+    }
+}
+
+impl ParserNode for MethodCall {
+    fn span(&self) -> &Arc<Span> {
+        &self.span
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use crate::test::{assert_error, expression_to_string};
+
+    use super::*;
+
+    #[test]
+    fn test_parse_member_access() {
+        let mut reader = Reader::from_str("a.b/rest");
+        let mut context = ParserContext::default();
+        let expression =
+            Expression::parse_operand(&mut reader, &mut context).expect("The parser must succeed");
+
+        if let Expression::MemberAccess(access) = expression {
+            assert_eq!(access.content(), "a.b", "The content is incorrect");
+            assert_eq!(access.object.content(), "a", "The object is incorrect");
+            assert_eq!(access.member.content(), "b", "The member is incorrect");
+        } else {
+            panic!("The expression type is incorrect");
+        }
+    }
+
+    #[test]
+    fn test_parse_method_call() {
+        let mut reader = Reader::from_str("a.b(1, c: 2)/rest");
+        let mut context = ParserContext::default();
+        let expression =
+            Expression::parse_operand(&mut reader, &mut context).expect("The parser must succeed");
+
+        if let Expression::MethodCall(call) = expression {
+            assert_eq!(call.content(), "a.b(1, c: 2)", "The content is incorrect");
+            assert_eq!(call.object.content(), "a", "The object is incorrect");
+            assert_eq!(call.method.content(), "b", "The method is incorrect");
+            assert_eq!(
+                call.arguments().len(),
+                2,
+                "The arguments length is incorrect"
+            );
+        } else {
+            panic!("The expression type is incorrect");
+        }
+    }
+
+    #[test]
+    fn test_parse_chain() {
+        for (text, result) in &[
+            ("a.b.c", "((a.b).c)"),
+            ("obj.method(x).other", "((obj.method(x)).other)"),
+            ("list[i].x", "((list[i]).x)"),
+            ("a.b[0](c)", "(((a.b)[0])(c))"),
+            ("-a.b", "(-(a.b))"),
+            ("a.b + c.d", "((a.b) + (c.d))"),
+        ] {
+            let mut reader = Reader::from_str(text);
+            let mut context = ParserContext::default();
+            let expression =
+                Expression::parse(&mut reader, &mut context).expect("The parser must succeed");
+
+            assert_eq!(
+                expression_to_string(&expression),
+                *result,
+                "The expression is incorrect"
+            );
+        }
+    }
+
+    #[test]
+    fn test_parse_multiline_chain() {
+        let mut reader = Reader::from_str("a\n  .b()\n  # comment\n  .c\nd");
+        let mut context = ParserContext::default();
+        let expression =
+            Expression::parse(&mut reader, &mut context).expect("The parser must succeed");
+
+        assert_eq!(
+            expression.content(),
+            "a\n  .b()\n  # comment\n  .c",
+            "The content is incorrect"
+        );
+
+        if let Expression::MemberAccess(access) = expression {
+            assert_eq!(
+                access.pre_access_whitespace.content(),
+                "\n  # comment\n  ",
+                "The pre_access_whitespace is incorrect"
+            );
+        } else {
+            panic!("The expression type is incorrect");
+        }
+    }
+
+    #[test]
+    fn test_parse_number_member() {
+        let mut reader = Reader::from_str("1.5.a");
+        let mut context = ParserContext::default();
+        let expression =
+            Expression::parse(&mut reader, &mut context).expect("The parser must succeed");
+
+        if let Expression::MemberAccess(access) = expression {
+            assert_eq!(access.object.content(), "1.5", "The object is incorrect");
+        } else {
+            panic!("The expression type is incorrect");
+        }
+    }
+
+    #[test]
+    fn test_parse_hexadecimal_number_method_call() {
+        let mut reader = Reader::from_str("0xFF.abs()");
+        let mut context = ParserContext::default();
+        let expression =
+            Expression::parse(&mut reader, &mut context).expect("The parser must succeed");
+
+        if let Expression::MethodCall(call) = expression {
+            assert_eq!(call.object.content(), "0xFF", "The object is incorrect");
+            assert_eq!(call.method.content(), "abs", "The method is incorrect");
+        } else {
+            panic!("The expression type is incorrect");
+        }
+    }
+
+    #[test]
+    fn test_parse_err_missing_member() {
+        for text in &["a.", "a. b", "a.(b)"] {
+            let mut reader = Reader::from_str(text);
+            let mut context = ParserContext::default();
+            let error = Expression::parse(&mut reader, &mut context)
+                .expect_err("The parser must not succeed");
+
+            assert_error(&context, &error, ParserError::MissingMemberNameAfterDot);
+        }
+    }
+}
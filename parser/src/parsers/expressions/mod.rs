@@ -3,17 +3,53 @@ use std::sync::Arc;
 use crate::context::ParserContext;
 use crate::io::{Reader, Span};
 use crate::parsers::commons::identifier::Identifier;
+use crate::parsers::commons::whitespaces::Whitespace;
+use crate::parsers::expressions::binary::BinaryExpression;
+use crate::parsers::expressions::blocks::{Block, BLOCK_OPEN_TOKEN};
+use crate::parsers::expressions::calls::{CallExpression, ARGUMENTS_DEFINITION};
+use crate::parsers::expressions::conditionals::IfExpression;
+use crate::parsers::expressions::indexes::{IndexExpression, INDEX_OPEN_TOKEN};
+use crate::parsers::expressions::lambdas::LambdaExpression;
 use crate::parsers::expressions::literals::Literal;
+use crate::parsers::expressions::matches::MatchExpression;
+use crate::parsers::expressions::members::{MemberAccess, MethodCall, MEMBER_ACCESS_TOKEN};
+use crate::parsers::expressions::ranges::{RangeExpression, RANGE_TOKEN};
+use crate::parsers::expressions::tuples::{ParenthesizedExpression, TupleExpression};
+use crate::parsers::expressions::unary::UnaryExpression;
 use crate::parsers::{ParserResult, ParserResultError};
 use crate::ParserNode;
 
+pub mod binary;
+pub mod blocks;
+pub mod calls;
+pub mod conditionals;
+pub mod indexes;
+pub mod lambdas;
 pub mod literals;
+pub mod matches;
+pub mod members;
+pub mod ranges;
+pub mod tuples;
+pub mod unary;
 
 /// A expression in the Mosfet language, like a value or variable access.
 #[derive(Debug)]
 pub enum Expression {
     Literal(Arc<Literal>),
     VariableAccess(Arc<Identifier>),
+    Parenthesized(Arc<ParenthesizedExpression>),
+    Tuple(Arc<TupleExpression>),
+    Block(Arc<Block>),
+    Lambda(Arc<LambdaExpression>),
+    If(Arc<IfExpression>),
+    Match(Arc<MatchExpression>),
+    Call(Arc<CallExpression>),
+    MemberAccess(Arc<MemberAccess>),
+    MethodCall(Arc<MethodCall>),
+    Index(Arc<IndexExpression>),
+    Unary(Arc<UnaryExpression>),
+    Binary(Arc<BinaryExpression>),
+    Range(Arc<RangeExpression>),
 }
 
 impl Expression {
@@ -21,20 +57,131 @@ impl Expression {
 
     /// Parses an expression.
     pub fn parse(reader: &mut Reader, context: &mut ParserContext) -> ParserResult<Expression> {
+        RangeExpression::parse_or_binary(reader, context)
+    }
+
+    /// Parses an expression followed by a block, like the condition of an `if`.
+    /// It cannot start with a brace nor take the block as the end of a range,
+    /// to not mistake the block for a map or part of the expression.
+    pub fn parse_before_block(
+        reader: &mut Reader,
+        context: &mut ParserContext,
+    ) -> ParserResult<Expression> {
+        if reader.continues_with(BLOCK_OPEN_TOKEN) {
+            return Err(ParserResultError::NotFound);
+        }
+
+        RangeExpression::parse_or_binary_before_block(reader, context)
+    }
+
+    /// Parses an expression that can be used as an operand of a binary operation.
+    pub fn parse_operand(
+        reader: &mut Reader,
+        context: &mut ParserContext,
+    ) -> ParserResult<Expression> {
+        Self::parse_operand_with_precedence(reader, context, 0)
+    }
+
+    /// Parses an operand like `parse_operand` but, if it is a unary expression, its
+    /// own operand can only contain binary operators with, at least, `min_precedence`.
+    pub fn parse_operand_with_precedence(
+        reader: &mut Reader,
+        context: &mut ParserContext,
+        min_precedence: u8,
+    ) -> ParserResult<Expression> {
+        match UnaryExpression::parse_with_precedence(reader, context, min_precedence) {
+            Ok(node) => return Ok(Expression::Unary(Arc::new(node))),
+            Err(ParserResultError::NotFound) => { /* Ignore because not found */ }
+            Err(ParserResultError::Error) => return Err(ParserResultError::Error),
+        }
+
+        let expression = Self::parse_primary(reader, context)?;
+        Self::parse_postfix(reader, context, expression)
+    }
+
+    /// Parses an expression that is not made of operators, like a literal or a variable access.
+    fn parse_primary(reader: &mut Reader, context: &mut ParserContext) -> ParserResult<Expression> {
         match Literal::parse(reader, context) {
             Ok(node) => return Ok(Expression::Literal(Arc::new(node))),
             Err(ParserResultError::NotFound) => { /* Ignore because not found */ }
             Err(ParserResultError::Error) => return Err(ParserResultError::Error),
         }
 
+        // After literals to parse maps before blocks.
+        match Block::parse(reader, context) {
+            Ok(node) => return Ok(Expression::Block(Arc::new(node))),
+            Err(ParserResultError::NotFound) => { /* Ignore because not found */ }
+            Err(ParserResultError::Error) => return Err(ParserResultError::Error),
+        }
+
+        match IfExpression::parse(reader, context) {
+            Ok(node) => return Ok(Expression::If(Arc::new(node))),
+            Err(ParserResultError::NotFound) => { /* Ignore because not found */ }
+            Err(ParserResultError::Error) => return Err(ParserResultError::Error),
+        }
+
+        match MatchExpression::parse(reader, context) {
+            Ok(node) => return Ok(Expression::Match(Arc::new(node))),
+            Err(ParserResultError::NotFound) => { /* Ignore because not found */ }
+            Err(ParserResultError::Error) => return Err(ParserResultError::Error),
+        }
+
         match Identifier::parse(reader, context) {
             Ok(node) => return Ok(Expression::VariableAccess(Arc::new(node))),
             Err(ParserResultError::NotFound) => { /* Ignore because not found */ }
             Err(ParserResultError::Error) => return Err(ParserResultError::Error),
         }
 
+        match LambdaExpression::parse(reader, context) {
+            Ok(node) => return Ok(Expression::Lambda(Arc::new(node))),
+            Err(ParserResultError::NotFound) => { /* Ignore because not found */ }
+            Err(ParserResultError::Error) => return Err(ParserResultError::Error),
+        }
+
+        match TupleExpression::parse_or_parenthesized(reader, context) {
+            Ok(node) => return Ok(node),
+            Err(ParserResultError::NotFound) => { /* Ignore because not found */ }
+            Err(ParserResultError::Error) => return Err(ParserResultError::Error),
+        }
+
         Err(ParserResultError::NotFound)
     }
+
+    /// Parses the chain of postfix operations applied to `expression`, i.e. calls, member
+    /// accesses and indexes, building a left-nested tree.
+    fn parse_postfix(
+        reader: &mut Reader,
+        context: &mut ParserContext,
+        mut expression: Expression,
+    ) -> ParserResult<Expression> {
+        loop {
+            if reader.continues_with(ARGUMENTS_DEFINITION.open_token) {
+                let node = CallExpression::parse(reader, context, expression)?;
+                expression = Expression::Call(Arc::new(node));
+                continue;
+            }
+
+            if reader.continues_with(INDEX_OPEN_TOKEN) {
+                let node = IndexExpression::parse(reader, context, expression)?;
+                expression = Expression::Index(Arc::new(node));
+                continue;
+            }
+
+            // Member accesses can continue in the next lines.
+            let pre_access_cursor = reader.save_cursor();
+            let pre_access_whitespace = Whitespace::parse_multiline_or_default(reader, context);
+
+            // Do not mistake a range operator for a member access.
+            if reader.continues_with(MEMBER_ACCESS_TOKEN) && !reader.continues_with(RANGE_TOKEN) {
+                expression =
+                    MemberAccess::parse(reader, context, expression, pre_access_whitespace)?;
+                continue;
+            }
+
+            reader.restore(pre_access_cursor);
+            return Ok(expression);
+        }
+    }
 }
 
 impl ParserNode for Expression {
@@ -42,6 +189,19 @@ impl ParserNode for Expression {
         match self {
             Expression::Literal(n) => n.span(),
             Expression::VariableAccess(n) => n.span(),
+            Expression::Parenthesized(n) => n.span(),
+            Expression::Tuple(n) => n.span(),
+            Expression::Block(n) => n.span(),
+            Expression::Lambda(n) => n.span(),
+            Expression::If(n) => n.span(),
+            Expression::Match(n) => n.span(),
+            Expression::Call(n) => n.span(),
+            Expression::MemberAccess(n) => n.span(),
+            Expression::MethodCall(n) => n.span(),
+            Expression::Index(n) => n.span(),
+            Expression::Unary(n) => n.span(),
+            Expression::Binary(n) => n.span(),
+            Expression::Range(n) => n.span(),
         }
     }
 }
@@ -59,7 +219,7 @@ mod tests {
 
     #[test]
     fn test_parse_literal() {
-        let mut reader = Reader::from_str("25/rest");
+        let mut reader = Reader::from_str("25 rest");
         let mut context = ParserContext::default();
         let expression =
             Expression::parse(&mut reader, &mut context).expect("The parser must succeed");
@@ -67,11 +227,9 @@ mod tests {
         if let Expression::Literal(literal) = expression {
             if let Literal::Number(number) = literal.as_ref() {
                 assert_eq!(number.span().content(), "25", "The span is incorrect");
+            } else {
+                panic!("The literal type is incorrect");
             }
-        // FIXME(juliotpaez): uncomment when there are more literals.
-        // else {
-        //     panic!("The literal type is incorrect");
-        // }
         } else {
             panic!("The expression type is incorrect");
         }
@@ -79,7 +237,7 @@ mod tests {
 
     #[test]
     fn test_parse_variable_access() {
-        let mut reader = Reader::from_str("name/rest");
+        let mut reader = Reader::from_str("name rest");
         let mut context = ParserContext::default();
         let expression =
             Expression::parse(&mut reader, &mut context).expect("The parser must succeed");
@@ -91,9 +249,53 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_parse_keyword_literals() {
+        for content in &["true", "false", "nil"] {
+            let mut reader = Reader::from_str(content);
+            let mut context = ParserContext::default();
+            let expression =
+                Expression::parse(&mut reader, &mut context).expect("The parser must succeed");
+
+            if let Expression::Literal(literal) = expression {
+                assert_eq!(literal.content(), *content, "The literal is incorrect");
+            } else {
+                panic!("The expression type is incorrect");
+            }
+        }
+    }
+
+    #[test]
+    fn test_parse_unary() {
+        let mut reader = Reader::from_str("-25 rest");
+        let mut context = ParserContext::default();
+        let expression =
+            Expression::parse(&mut reader, &mut context).expect("The parser must succeed");
+
+        if let Expression::Unary(unary) = expression {
+            assert_eq!(unary.content(), "-25", "The content is incorrect");
+        } else {
+            panic!("The expression type is incorrect");
+        }
+    }
+
+    #[test]
+    fn test_parse_binary() {
+        let mut reader = Reader::from_str("a + 2 * b rest");
+        let mut context = ParserContext::default();
+        let expression =
+            Expression::parse(&mut reader, &mut context).expect("The parser must succeed");
+
+        if let Expression::Binary(binary) = expression {
+            assert_eq!(binary.content(), "a + 2 * b", "The content is incorrect");
+        } else {
+            panic!("The expression type is incorrect");
+        }
+    }
+
     #[test]
     fn test_parse_err_not_found() {
-        let mut reader = Reader::from_str("-");
+        let mut reader = Reader::from_str("+");
         let mut context = ParserContext::default();
         let error =
             Expression::parse(&mut reader, &mut context).expect_err("The parser must not succeed");
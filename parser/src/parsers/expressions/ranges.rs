@@ -0,0 +1,337 @@
+use std::sync::Arc;
+
+use doclog::Color;
+
+use crate::context::ParserContext;
+use crate::io::{Reader, Span};
+use crate::parsers::commons::whitespaces::Whitespace;
+use crate::parsers::expressions::binary::BinaryExpression;
+use crate::parsers::expressions::blocks::BLOCK_OPEN_TOKEN;
+use crate::parsers::expressions::Expression;
+use crate::parsers::utils::{cursor_manager, generate_error_log, generate_source_code};
+use crate::parsers::{ParserResult, ParserResultError};
+use crate::{ParserError, ParserNode};
+
+pub static RANGE_TOKEN: &str = "..";
+pub static INCLUSIVE_RANGE_TOKEN: &str = "..=";
+
+/// A range of values between two expressions, e.g. `a..b` that excludes the end or
+/// `a..=b` that includes it.
+///
+/// Both bounds can be omitted, e.g. `a..`, `..b` or `..`, except the end of inclusive
+/// ranges. Ranges have lower precedence than any binary operation, so `a + 1..b` is
+/// `(a + 1)..b`.
+#[derive(Debug)]
+pub struct RangeExpression {
+    span: Arc<Span>,
+    start: Option<Arc<Expression>>,
+    end: Option<Arc<Expression>>,
+    is_inclusive: bool,
+    pre_operator_whitespace: Option<Arc<Whitespace>>,
+    pre_end_whitespace: Option<Arc<Whitespace>>,
+}
+
+impl RangeExpression {
+    // GETTERS ----------------------------------------------------------------
+
+    pub fn start(&self) -> &Option<Arc<Expression>> {
+        &self.start
+    }
+
+    pub fn end(&self) -> &Option<Arc<Expression>> {
+        &self.end
+    }
+
+    /// Whether the end is included in the range or not.
+    pub fn is_inclusive(&self) -> bool {
+        self.is_inclusive
+    }
+
+    /// The range operator as str.
+    pub fn operator_str(&self) -> &'static str {
+        if self.is_inclusive {
+            INCLUSIVE_RANGE_TOKEN
+        } else {
+            RANGE_TOKEN
+        }
+    }
+
+    pub fn pre_operator_whitespace(&self) -> &Option<Arc<Whitespace>> {
+        &self.pre_operator_whitespace
+    }
+
+    pub fn pre_end_whitespace(&self) -> &Option<Arc<Whitespace>> {
+        &self.pre_end_whitespace
+    }
+
+    // STATIC METHODS ---------------------------------------------------------
+
+    /// Parses a range or, if there is no range operator, the binary expression
+    /// that would be its start.
+    pub fn parse_or_binary(
+        reader: &mut Reader,
+        context: &mut ParserContext,
+    ) -> ParserResult<Expression> {
+        Self::parse_or_binary_with_config(reader, context, true)
+    }
+
+    /// Parses a range like `parse_or_binary` but without taking a block as its end,
+    /// so that it can be followed by a block, e.g. `for i in 0.. { }`.
+    pub fn parse_or_binary_before_block(
+        reader: &mut Reader,
+        context: &mut ParserContext,
+    ) -> ParserResult<Expression> {
+        Self::parse_or_binary_with_config(reader, context, false)
+    }
+
+    fn parse_or_binary_with_config(
+        reader: &mut Reader,
+        context: &mut ParserContext,
+        allow_block_end: bool,
+    ) -> ParserResult<Expression> {
+        cursor_manager(reader, |reader, init_cursor| {
+            let start = match BinaryExpression::parse_with_precedence(reader, context, 0) {
+                Ok(v) => Some(v),
+                Err(ParserResultError::NotFound) => None,
+                Err(ParserResultError::Error) => return Err(ParserResultError::Error),
+            };
+
+            let pre_operator_cursor = reader.save_cursor();
+            let pre_operator_whitespace = start
+                .as_ref()
+                .map(|_| Whitespace::parse_inline_or_default(reader, context));
+
+            let operator_cursor = reader.save_cursor();
+            let is_inclusive = if reader.read(INCLUSIVE_RANGE_TOKEN) {
+                true
+            } else if reader.read(RANGE_TOKEN) {
+                false
+            } else {
+                reader.restore(pre_operator_cursor);
+                return start.ok_or(ParserResultError::NotFound);
+            };
+
+            // The end is optional so it must be in the same line to not take
+            // the next statement.
+            let pre_end_cursor = reader.save_cursor();
+            let pre_end_whitespace = Whitespace::parse_inline_or_default(reader, context);
+
+            let end = if !allow_block_end && reader.continues_with(BLOCK_OPEN_TOKEN) {
+                Err(ParserResultError::NotFound)
+            } else {
+                BinaryExpression::parse_with_precedence(reader, context, 0)
+            };
+
+            let end = match end {
+                Ok(v) => Some(v),
+                Err(ParserResultError::NotFound) => {
+                    reader.restore(pre_end_cursor);
+                    None
+                }
+                Err(ParserResultError::Error) => return Err(ParserResultError::Error),
+            };
+
+            if is_inclusive && end.is_none() {
+                context.add_message(generate_error_log(
+                    ParserError::MissingEndInInclusiveRange,
+                    format!(
+                        "An expression is expected after the operator '{}' of the inclusive range",
+                        INCLUSIVE_RANGE_TOKEN
+                    ),
+                    |log| {
+                        generate_source_code(log, reader, |doc| {
+                            doc.highlight_section(
+                                operator_cursor.byte_offset()..reader.byte_offset(),
+                                None,
+                                Some(Color::Magenta),
+                            )
+                            .highlight_cursor_str(
+                                reader.byte_offset(),
+                                Some("Insert an expression here"),
+                                None,
+                            )
+                        })
+                    },
+                ));
+
+                return Err(ParserResultError::Error);
+            }
+
+            let pre_end_whitespace = end.as_ref().map(|_| Arc::new(pre_end_whitespace));
+
+            Ok(Expression::Range(Arc::new(RangeExpression {
+                span: Arc::new(reader.substring_to_current(init_cursor)),
+                start: start.map(Arc::new),
+                end: end.map(Arc::new),
+                is_inclusive,
+                pre_operator_whitespace: pre_operator_whitespace.map(Arc::new),
+                pre_end_whitespace,
+            })))
+        })
+    }
+}
+
+impl ParserNode for RangeExpression {
+    fn span(&self) -> &Arc<Span> {
+        &self.span
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use crate::parsers::expressions::literals::Literal;
+    use crate::parsers::file::MosfetFile;
+    use crate::test::{assert_error, assert_not_found, expression_to_string};
+
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        for (text, result) in &[
+            ("a..b", "(a..b)"),
+            ("a..=b", "(a..=b)"),
+            ("a ..b", "(a..b)"),
+            ("a..", "(a..)"),
+            ("..b", "(..b)"),
+            ("..=b", "(..=b)"),
+            ("..", "(..)"),
+            ("a + 1..b * 2", "((a + 1)..(b * 2))"),
+            ("-a..-b", "((-a)..(-b))"),
+            ("a.b..c.d", "((a.b)..(c.d))"),
+            ("(1..)", "(1..)"),
+        ] {
+            let mut reader = Reader::from_str(text);
+            let mut context = ParserContext::default();
+            let expression =
+                Expression::parse(&mut reader, &mut context).expect("The parser must succeed");
+
+            assert_eq!(expression.content(), *text, "The content is incorrect");
+            assert_eq!(
+                expression_to_string(&expression),
+                *result,
+                "The expression is incorrect"
+            );
+        }
+    }
+
+    #[test]
+    fn test_parse_before_block() {
+        for (text, content, rest) in &[
+            ("0.. { a }", "0..", " { a }"),
+            ("0..{ a }", "0..", "{ a }"),
+            ("0..b { a }", "0..b", " { a }"),
+        ] {
+            let mut reader = Reader::from_str(text);
+            let mut context = ParserContext::default();
+            let expression =
+                RangeExpression::parse_or_binary_before_block(&mut reader, &mut context)
+                    .expect("The parser must succeed");
+
+            assert_eq!(expression.content(), *content, "The content is incorrect");
+            assert_eq!(
+                reader.remaining_content(),
+                *rest,
+                "The remaining content is incorrect"
+            );
+        }
+    }
+
+    #[test]
+    fn test_parse_numbers() {
+        // The range operator has priority over the decimal separator.
+        let mut reader = Reader::from_str("1..5");
+        let mut context = ParserContext::default();
+        let expression =
+            Expression::parse(&mut reader, &mut context).expect("The parser must succeed");
+
+        if let Expression::Range(range) = &expression {
+            assert_eq!(
+                range.start.as_ref().map(|v| v.content()),
+                Some("1"),
+                "The start is incorrect"
+            );
+            assert_eq!(
+                range.end.as_ref().map(|v| v.content()),
+                Some("5"),
+                "The end is incorrect"
+            );
+        } else {
+            panic!("The expression type is incorrect");
+        }
+
+        for (text, result) in &[("1.5..2.5", "(1.5..2.5)"), ("1..=5.0", "(1..=5.0)")] {
+            let mut reader = Reader::from_str(text);
+            let mut context = ParserContext::default();
+            let expression =
+                Expression::parse(&mut reader, &mut context).expect("The parser must succeed");
+
+            assert_eq!(
+                expression_to_string(&expression),
+                *result,
+                "The expression is incorrect"
+            );
+        }
+
+        // A decimal number is never a range.
+        let mut reader = Reader::from_str("1.5");
+        let mut context = ParserContext::default();
+        let expression =
+            Expression::parse(&mut reader, &mut context).expect("The parser must succeed");
+
+        assert!(
+            matches!(&expression, Expression::Literal(v) if matches!(v.as_ref(), Literal::Number(_))),
+            "The expression type is incorrect"
+        );
+        assert_eq!(expression.content(), "1.5", "The content is incorrect");
+    }
+
+    #[test]
+    fn test_parse_number_with_spaced_dot() {
+        // `1.` is not a number, so the dot is an access to a member that is missing.
+        let mut reader = Reader::from_str("1. .5");
+        let mut context = ParserContext::default();
+        let error =
+            Expression::parse(&mut reader, &mut context).expect_err("The parser must not succeed");
+
+        assert_error(&context, &error, ParserError::MissingMemberNameAfterDot);
+    }
+
+    #[test]
+    fn test_parse_open_end_in_statement() {
+        let mut reader = Reader::from_str("let a = 1..\nlet b = ..2");
+        let mut context = ParserContext::default();
+        let file = MosfetFile::parse(&mut reader, &mut context).expect("The parser must succeed");
+
+        assert_eq!(
+            file.statements().len(),
+            2,
+            "The statements length is incorrect"
+        );
+    }
+
+    #[test]
+    fn test_parse_err_not_found() {
+        let mut reader = Reader::from_str(". a");
+        let mut context = ParserContext::default();
+        let error =
+            Expression::parse(&mut reader, &mut context).expect_err("The parser must not succeed");
+
+        assert_not_found(&context, &error, 0);
+    }
+
+    #[test]
+    fn test_parse_err_missing_inclusive_end() {
+        for text in &["a..=", "..=", "a..=\nb"] {
+            let mut reader = Reader::from_str(text);
+            let mut context = ParserContext::default();
+            let error = Expression::parse(&mut reader, &mut context)
+                .expect_err("The parser must not succeed");
+
+            assert_error(&context, &error, ParserError::MissingEndInInclusiveRange);
+        }
+    }
+}
@@ -0,0 +1,223 @@
+use std::sync::Arc;
+
+use crate::context::ParserContext;
+use crate::io::{Reader, Span};
+use crate::parsers::commons::sequences::{Sequence, SequenceDefinition};
+use crate::parsers::commons::whitespaces::Whitespace;
+use crate::parsers::expressions::Expression;
+use crate::parsers::ParserResult;
+use crate::{ParserError, ParserNode};
+
+pub static TUPLE_DEFINITION: SequenceDefinition = SequenceDefinition {
+    open_token: "(",
+    close_token: ")",
+    name: "tuple",
+    missing_separator_error: ParserError::MissingCommaInTuple,
+    without_end_token_error: ParserError::ParenthesisWithoutEndToken,
+};
+
+/// An expression enclosed between parentheses to group it, e.g. `(a + b)`.
+#[derive(Debug)]
+pub struct ParenthesizedExpression {
+    span: Arc<Span>,
+    expression: Arc<Expression>,
+    pre_expression_whitespace: Arc<Whitespace>,
+    post_expression_whitespace: Arc<Whitespace>,
+}
+
+impl ParenthesizedExpression {
+    // GETTERS ----------------------------------------------------------------
+
+    pub fn expression(&self) -> &Arc<Expression> {
+        &self.expression
+    }
+
+    pub fn pre_expression_whitespace(&self) -> &Arc<Whitespace> {
+        &self.pre_expression_whitespace
+    }
+
+    pub fn post_expression_whitespace(&self) -> &Arc<Whitespace> {
+        &self.post_expression_whitespace
+    }
+}
+
+impl ParserNode for ParenthesizedExpression {
+    fn span(&self) -> &Arc<Span> {
+        &self.span
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+/// A tuple of expressions, e.g. `(a, b, c)`.
+///
+/// A tuple with only one element requires a trailing comma, e.g. `(a,)`, to distinguish
+/// it from a `ParenthesizedExpression`. `()` is the empty tuple.
+#[derive(Debug)]
+pub struct TupleExpression {
+    sequence: Sequence<Expression>,
+}
+
+impl TupleExpression {
+    // GETTERS ----------------------------------------------------------------
+
+    pub fn sequence(&self) -> &Sequence<Expression> {
+        &self.sequence
+    }
+
+    // STATIC METHODS ---------------------------------------------------------
+
+    /// Parses a tuple or a parenthesized expression, depending on the number of elements
+    /// and the trailing comma.
+    pub fn parse_or_parenthesized(
+        reader: &mut Reader,
+        context: &mut ParserContext,
+    ) -> ParserResult<Expression> {
+        let sequence = Sequence::parse(reader, context, &TUPLE_DEFINITION, Expression::parse)?;
+
+        if sequence.elements().len() == 1 && !sequence.has_trailing_separator() {
+            let element = &sequence.elements()[0];
+
+            return Ok(Expression::Parenthesized(Arc::new(
+                ParenthesizedExpression {
+                    span: sequence.span().clone(),
+                    expression: element.node().clone(),
+                    pre_expression_whitespace: element.pre_node_whitespace().clone(),
+                    post_expression_whitespace: element.post_node_whitespace().clone(),
+                },
+            )));
+        }
+
+        Ok(Expression::Tuple(Arc::new(TupleExpression { sequence })))
+    }
+}
+
+impl ParserNode for TupleExpression {
+    fn span(&self) -> &Arc<Span> {
+        self.sequence.span()
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use crate::test::{assert_error, assert_not_found, expression_to_string};
+
+    use super::*;
+
+    #[test]
+    fn test_parse_parenthesized() {
+        let mut reader = Reader::from_str("(\n  a + b # comment\n)");
+        let mut context = ParserContext::default();
+        let expression = TupleExpression::parse_or_parenthesized(&mut reader, &mut context)
+            .expect("The parser must succeed");
+
+        if let Expression::Parenthesized(parenthesized) = expression {
+            assert_eq!(
+                parenthesized.content(),
+                "(\n  a + b # comment\n)",
+                "The content is incorrect"
+            );
+            assert_eq!(
+                parenthesized.expression.content(),
+                "a + b",
+                "The expression is incorrect"
+            );
+            assert_eq!(
+                parenthesized.pre_expression_whitespace.content(),
+                "\n  ",
+                "The pre_expression_whitespace is incorrect"
+            );
+            assert_eq!(
+                parenthesized.post_expression_whitespace.content(),
+                " # comment\n",
+                "The post_expression_whitespace is incorrect"
+            );
+        } else {
+            panic!("The expression type is incorrect");
+        }
+    }
+
+    #[test]
+    fn test_parse_tuple() {
+        for (text, length) in &[
+            ("()", 0),
+            ("(a,)", 1),
+            ("(a, b)", 2),
+            ("(\n a,\n b,\n c,\n)", 3),
+        ] {
+            let mut reader = Reader::from_str(text);
+            let mut context = ParserContext::default();
+            let expression = TupleExpression::parse_or_parenthesized(&mut reader, &mut context)
+                .expect("The parser must succeed");
+
+            if let Expression::Tuple(tuple) = expression {
+                assert_eq!(tuple.content(), *text, "The content is incorrect");
+                assert_eq!(
+                    tuple.sequence.elements().len(),
+                    *length,
+                    "The elements length is incorrect"
+                );
+            } else {
+                panic!("The expression type is incorrect");
+            }
+        }
+    }
+
+    #[test]
+    fn test_parse_grouping() {
+        for (text, result) in &[
+            ("(1 + 2) * 3", "((1 + 2) * 3)"),
+            ("2 ** (3 - 1)", "(2 ** (3 - 1))"),
+            ("-(a)", "(-a)"),
+        ] {
+            let mut reader = Reader::from_str(text);
+            let mut context = ParserContext::default();
+            let expression =
+                Expression::parse(&mut reader, &mut context).expect("The parser must succeed");
+
+            assert_eq!(
+                expression_to_string(&expression),
+                *result,
+                "The expression is incorrect"
+            );
+        }
+    }
+
+    #[test]
+    fn test_parse_err_not_found() {
+        let mut reader = Reader::from_str("a");
+        let mut context = ParserContext::default();
+        let error = TupleExpression::parse_or_parenthesized(&mut reader, &mut context)
+            .expect_err("The parser must not succeed");
+
+        assert_not_found(&context, &error, 0);
+    }
+
+    #[test]
+    fn test_parse_err_missing_comma() {
+        let mut reader = Reader::from_str("(a b)");
+        let mut context = ParserContext::default();
+        let error = TupleExpression::parse_or_parenthesized(&mut reader, &mut context)
+            .expect_err("The parser must not succeed");
+
+        assert_error(&context, &error, ParserError::MissingCommaInTuple);
+    }
+
+    #[test]
+    fn test_parse_err_without_end_token() {
+        for text in &["(", "(a", "(a,\n b", "(a ]"] {
+            let mut reader = Reader::from_str(text);
+            let mut context = ParserContext::default();
+            let error = TupleExpression::parse_or_parenthesized(&mut reader, &mut context)
+                .expect_err("The parser must not succeed");
+
+            assert_error(&context, &error, ParserError::ParenthesisWithoutEndToken);
+        }
+    }
+}
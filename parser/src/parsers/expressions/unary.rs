@@ -0,0 +1,302 @@
+use std::sync::Arc;
+
+use doclog::Color;
+
+use crate::context::ParserContext;
+use crate::io::{Reader, Span};
+use crate::parsers::commons::identifier::Identifier;
+use crate::parsers::commons::whitespaces::Whitespace;
+use crate::parsers::expressions::binary::{BinaryExpression, BinaryOperator};
+use crate::parsers::expressions::Expression;
+use crate::parsers::utils::{cursor_manager, generate_error_log, generate_source_code};
+use crate::parsers::{ParserResult, ParserResultError};
+use crate::{ParserError, ParserNode};
+
+pub static NEGATION_TOKEN: &str = "-";
+static BIT_NOT_TOKEN: &str = "~";
+static NOT_KEYWORD: &str = "not";
+static ARROW_TOKEN: &str = "->";
+
+/// The operators that can be used in a `UnaryExpression`.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum UnaryOperator {
+    Negation,
+    Not,
+    BitNot,
+}
+
+impl UnaryOperator {
+    // GETTERS ----------------------------------------------------------------
+
+    /// The operator as str.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            UnaryOperator::Negation => NEGATION_TOKEN,
+            UnaryOperator::Not => NOT_KEYWORD,
+            UnaryOperator::BitNot => BIT_NOT_TOKEN,
+        }
+    }
+
+    /// The minimum precedence of the binary operators that can be part of the operand,
+    /// i.e. `not` applies to whole comparisons while `-` and `~` only to powers.
+    pub fn operand_precedence(&self) -> u8 {
+        match self {
+            UnaryOperator::Not => BinaryOperator::Equal.precedence(),
+            UnaryOperator::Negation | UnaryOperator::BitNot => BinaryOperator::Power.precedence(),
+        }
+    }
+
+    // STATIC METHODS ---------------------------------------------------------
+
+    /// Parses a `UnaryOperator`.
+    pub fn parse(reader: &mut Reader, context: &mut ParserContext) -> ParserResult<UnaryOperator> {
+        if reader.continues_with(ARROW_TOKEN) {
+            return Err(ParserResultError::NotFound);
+        }
+
+        if reader.read(NEGATION_TOKEN) {
+            return Ok(UnaryOperator::Negation);
+        }
+
+        if reader.read(BIT_NOT_TOKEN) {
+            return Ok(UnaryOperator::BitNot);
+        }
+
+        if Identifier::parse_keyword(reader, context, NOT_KEYWORD) {
+            return Ok(UnaryOperator::Not);
+        }
+
+        Err(ParserResultError::NotFound)
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+/// A prefix operation over an expression, e.g. `-a` or `not a`.
+///
+/// `-` and `~` bind tighter than any binary operator except `**`, so `-a * b` is
+/// `(-a) * b` but `-2 ** 2` is `-(2 ** 2)`. `not` binds looser than comparisons, so
+/// `not a == b` is `not (a == b)` and `not a and b` is `(not a) and b`. The operand
+/// never takes operators looser than the one before the unary expression, e.g.
+/// `2 * not a == b` is `(2 * (not a)) == b`.
+///
+/// Number literals never include a sign, thus `-5` is always a negation of the literal `5`.
+#[derive(Debug)]
+pub struct UnaryExpression {
+    span: Arc<Span>,
+    operator: UnaryOperator,
+    expression: Arc<Expression>,
+    pre_expression_whitespace: Arc<Whitespace>,
+}
+
+impl UnaryExpression {
+    // GETTERS ----------------------------------------------------------------
+
+    pub fn operator(&self) -> &UnaryOperator {
+        &self.operator
+    }
+
+    pub fn expression(&self) -> &Arc<Expression> {
+        &self.expression
+    }
+
+    pub fn pre_expression_whitespace(&self) -> &Arc<Whitespace> {
+        &self.pre_expression_whitespace
+    }
+
+    // STATIC METHODS ---------------------------------------------------------
+
+    /// Parses a unary expression.
+    pub fn parse(
+        reader: &mut Reader,
+        context: &mut ParserContext,
+    ) -> ParserResult<UnaryExpression> {
+        Self::parse_with_precedence(reader, context, 0)
+    }
+
+    /// Parses a unary expression whose operand can only contain binary operators with,
+    /// at least, `min_precedence`, e.g. as the right operand of `*` the operand of `not`
+    /// does not take comparisons.
+    pub fn parse_with_precedence(
+        reader: &mut Reader,
+        context: &mut ParserContext,
+        min_precedence: u8,
+    ) -> ParserResult<UnaryExpression> {
+        cursor_manager(reader, |reader, init_cursor| {
+            let operator = UnaryOperator::parse(reader, context)?;
+
+            let pre_expression_whitespace = Whitespace::parse_inline_or_default(reader, context);
+
+            let expression = match BinaryExpression::parse_with_precedence(
+                reader,
+                context,
+                operator.operand_precedence().max(min_precedence),
+            ) {
+                Ok(v) => v,
+                Err(ParserResultError::NotFound) => {
+                    context.add_message(generate_error_log(
+                        ParserError::MissingExpressionInUnaryExpression,
+                        format!(
+                            "An expression was expected after the operator '{}'",
+                            operator.as_str()
+                        ),
+                        |log| {
+                            generate_source_code(log, reader, |doc| {
+                                doc.highlight_section(
+                                    init_cursor.byte_offset()
+                                        ..pre_expression_whitespace
+                                            .span()
+                                            .start_cursor()
+                                            .byte_offset(),
+                                    None,
+                                    Some(Color::Magenta),
+                                )
+                                .highlight_cursor_str(
+                                    reader.byte_offset(),
+                                    Some("Insert an expression here"),
+                                    None,
+                                )
+                            })
+                        },
+                    ));
+
+                    return Err(ParserResultError::Error);
+                }
+                Err(ParserResultError::Error) => return Err(ParserResultError::Error),
+            };
+
+            let span = Arc::new(reader.substring_to_current(init_cursor));
+            Ok(UnaryExpression {
+                span,
+                operator,
+                expression: Arc::new(expression),
+                pre_expression_whitespace: Arc::new(pre_expression_whitespace),
+            })
+        })
+    }
+}
+
+impl ParserNode for UnaryExpression {
+    fn span(&self) -> &Arc<Span> {
+        &self.span
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use crate::parsers::expressions::literals::Literal;
+    use crate::test::{assert_error, assert_not_found, expression_to_string};
+
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        for (text, operator, expression_text) in &[
+            ("-a", UnaryOperator::Negation, "a"),
+            ("~  a", UnaryOperator::BitNot, "a"),
+            ("not a", UnaryOperator::Not, "a"),
+            ("--a", UnaryOperator::Negation, "-a"),
+        ] {
+            let mut reader = Reader::from_str(text);
+            let mut context = ParserContext::default();
+            let unary =
+                UnaryExpression::parse(&mut reader, &mut context).expect("The parser must succeed");
+
+            assert_eq!(unary.content(), *text, "The content is incorrect");
+            assert_eq!(unary.operator, *operator, "The operator is incorrect");
+            assert_eq!(
+                unary.expression.content(),
+                *expression_text,
+                "The expression is incorrect"
+            );
+        }
+    }
+
+    #[test]
+    fn test_parse_negative_number() {
+        let mut reader = Reader::from_str("-5");
+        let mut context = ParserContext::default();
+        let unary =
+            UnaryExpression::parse(&mut reader, &mut context).expect("The parser must succeed");
+
+        assert_eq!(
+            unary.operator,
+            UnaryOperator::Negation,
+            "The operator is incorrect"
+        );
+
+        if let Expression::Literal(literal) = unary.expression.as_ref() {
+            if let Literal::Number(number) = literal.as_ref() {
+                assert_eq!(number.content(), "5", "The number is incorrect");
+            } else {
+                panic!("The literal type is incorrect");
+            }
+        } else {
+            panic!("The expression type is incorrect");
+        }
+    }
+
+    #[test]
+    fn test_parse_precedence() {
+        for (text, result) in &[
+            ("-2 ** 2", "(-(2 ** 2))"),
+            ("2 ** -2", "(2 ** (-2))"),
+            ("-a * b", "((-a) * b)"),
+            ("not a == b", "(not (a == b))"),
+            ("not a < b + c", "(not (a < (b + c)))"),
+            ("not a and b", "((not a) and b)"),
+            ("not a == b or c", "((not (a == b)) or c)"),
+            ("2 * not a == b", "((2 * (not a)) == b)"),
+            ("a == not b == c", "((a == (not b)) == c)"),
+            ("a and not b == c", "(a and (not (b == c)))"),
+            ("a - -b", "(a - (-b))"),
+            ("a--b", "(a - (-b))"),
+            ("~a & -b", "((~a) & (-b))"),
+        ] {
+            let mut reader = Reader::from_str(text);
+            let mut context = ParserContext::default();
+            let expression =
+                Expression::parse(&mut reader, &mut context).expect("The parser must succeed");
+
+            assert_eq!(
+                expression_to_string(&expression),
+                *result,
+                "The expression is incorrect"
+            );
+        }
+    }
+
+    #[test]
+    fn test_parse_err_not_found() {
+        for text in &["->", "nota", "+a"] {
+            let mut reader = Reader::from_str(text);
+            let mut context = ParserContext::default();
+            let error = UnaryExpression::parse(&mut reader, &mut context)
+                .expect_err("The parser must not succeed");
+
+            assert_not_found(&context, &error, 0);
+        }
+    }
+
+    #[test]
+    fn test_parse_err_missing_expression() {
+        for text in &["-", "not", "~ \n a"] {
+            let mut reader = Reader::from_str(text);
+            let mut context = ParserContext::default();
+            let error = UnaryExpression::parse(&mut reader, &mut context)
+                .expect_err("The parser must not succeed");
+
+            assert_error(
+                &context,
+                &error,
+                ParserError::MissingExpressionInUnaryExpression,
+            );
+        }
+    }
+}
@@ -1,10 +1,12 @@
+use std::sync::Arc;
+
 use doclog::Color;
 
 use crate::context::ParserContext;
 use crate::io::{Reader, Span};
 use crate::parsers::commons::whitespaces::Whitespace;
 use crate::parsers::result::ParserResult;
-use crate::parsers::statements::Statement;
+use crate::parsers::statements::{ExpressionStatement, ImportStatement, Statement};
 use crate::parsers::utils::{cursor_manager, generate_error_log, generate_source_code};
 use crate::parsers::ParserResultError;
 use crate::ParserError;
@@ -29,109 +31,76 @@ impl MosfetFile {
         &self.statements
     }
 
+    /// The import statements of the file.
+    pub fn imports(&self) -> Vec<&Arc<ImportStatement>> {
+        self.statements
+            .iter()
+            .filter_map(|v| match v {
+                Statement::ImportStatement(v) => Some(v),
+                _ => None,
+            })
+            .collect()
+    }
+
     // STATIC METHODS ---------------------------------------------------------
 
     /// Parses a Mosfet file.
     pub fn parse(reader: &mut Reader, context: &mut ParserContext) -> ParserResult<MosfetFile> {
         cursor_manager(reader, |reader, init_cursor| {
-            let mut statements = Vec::new();
-
-            // First statement.
-            let _ = Whitespace::parse_multiline(reader, context);
-
-            match Statement::parse(reader, context) {
-                Ok(statement) => statements.push(statement),
-                Err(_) => {
-                    // Check end.
-                    let span = reader.substring_to_current(&init_cursor);
-                    return if reader.remaining_length() == 0 {
-                        Ok(MosfetFile { span, statements })
-                    } else {
-                        context.add_message(generate_error_log(
-                            ParserError::NotAMosfetFile,
-                            "The file is not recognized as valid Mosfet file".to_string(),
-                            |log| log,
-                        ));
-
-                        Err(ParserResultError::Error)
-                    };
-                }
-            }
+            let (mut statements, expression) = Statement::parse_list(reader, context)?;
 
-            // Next statements.
-            loop {
-                let whitespace = Whitespace::parse_multiline(reader, context);
-
-                match Statement::parse(reader, context) {
-                    Ok(statement) => {
-                        // Check whitespace is multiline to prevent two statements in the same line.
-                        if !whitespace
-                            .as_ref()
-                            .map(|ws| ws.is_multiline())
-                            .unwrap_or(false)
-                        {
-                            context.add_message(generate_error_log(
-                                ParserError::TwoStatementsInSameLineInFile,
-                                "Two statements in the same line are forbidden".to_string(),
-                                |log| {
-                                    generate_source_code(log, &reader, |doc| {
-                                        doc.highlight_cursor_str(
-                                            statements
-                                                .last()
-                                                .unwrap()
-                                                .span()
-                                                .end_cursor()
-                                                .byte_offset(),
-                                            Some("Insert a new line (\\n) here"),
-                                            None,
-                                        )
-                                    })
-                                },
-                            ));
-
-                            return Err(ParserResultError::Error);
-                        }
+            // The last expression of a file is not a value but a statement.
+            if let Some(expression) = expression {
+                statements.push(Statement::ExpressionStatement(Arc::new(
+                    ExpressionStatement::new(reader, context, expression),
+                )));
 
-                        statements.push(statement);
-                    }
-                    Err(ParserResultError::NotFound) => break,
-                    Err(ParserResultError::Error) => return Err(ParserResultError::Error),
-                }
+                let _ = Whitespace::parse_multiline_or_default(reader, context);
             }
 
             // Check end.
-            let span = reader.substring_to_current(&init_cursor);
+            let span = reader.substring_to_current(init_cursor);
             if reader.remaining_length() == 0 {
-                Ok(MosfetFile { span, statements })
-            } else {
+                return Ok(MosfetFile { span, statements });
+            }
+
+            if statements.is_empty() {
                 context.add_message(generate_error_log(
-                    ParserError::ExpectedEOFInFile,
-                    "The End Of File (EOF) was expected here".to_string(),
-                    |log| {
-                        let last_statement = statements.last().unwrap();
-                        generate_source_code(log, &reader, |doc| {
-                            let doc = doc.highlight_cursor_str(
-                                last_statement.span().end_cursor().byte_offset(),
-                                Some("The file must end here"),
-                                None,
-                            );
-
-                            if reader.content().len() - reader.byte_offset() != 0 {
-                                doc.highlight_section_str(
-                                    last_statement.span().end_cursor().byte_offset()
-                                        ..reader.content().len(),
-                                    Some("Remove this code"),
-                                    Some(Color::Magenta),
-                                )
-                            } else {
-                                doc
-                            }
-                        })
-                    },
+                    ParserError::NotAMosfetFile,
+                    "The file is not recognized as valid Mosfet file".to_string(),
+                    |log| log,
                 ));
 
-                Err(ParserResultError::Error)
+                return Err(ParserResultError::Error);
             }
+
+            context.add_message(generate_error_log(
+                ParserError::ExpectedEOFInFile,
+                "The End Of File (EOF) was expected here".to_string(),
+                |log| {
+                    let last_statement = statements.last().unwrap();
+                    generate_source_code(log, reader, |doc| {
+                        let doc = doc.highlight_cursor_str(
+                            last_statement.span().end_cursor().byte_offset(),
+                            Some("The file must end here"),
+                            None,
+                        );
+
+                        if reader.content().len() - reader.byte_offset() != 0 {
+                            doc.highlight_section_str(
+                                last_statement.span().end_cursor().byte_offset()
+                                    ..reader.content().len(),
+                                Some("Remove this code"),
+                                Some(Color::Magenta),
+                            )
+                        } else {
+                            doc
+                        }
+                    })
+                },
+            ));
+
+            Err(ParserResultError::Error)
         })
     }
 }
@@ -203,9 +172,54 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_parse_statements_with_multiline_operations() {
+        let mut reader = Reader::from_str("let x = 3 +\n  4\nlet y = x\n- 1");
+        let mut context = ParserContext::default();
+        let mosfet_file =
+            MosfetFile::parse(&mut reader, &mut context).expect("The parser must succeed");
+
+        // The operator cannot start a new line, so it starts a new statement.
+        assert_eq!(
+            mosfet_file.statements.len(),
+            3,
+            "The statement length is incorrect"
+        );
+        assert_eq!(
+            mosfet_file.statements[1].span().content(),
+            "let y = x",
+            "The second statement is incorrect"
+        );
+
+        let mut reader = Reader::from_str("let x = 3 +\n  4\nlet y = x -\n 1");
+        let mut context = ParserContext::default();
+        let mosfet_file =
+            MosfetFile::parse(&mut reader, &mut context).expect("The parser must succeed");
+
+        assert_eq!(
+            mosfet_file.statements.len(),
+            2,
+            "The statement length is incorrect"
+        );
+    }
+
+    #[test]
+    fn test_parse_statements_with_multiline_member_chains() {
+        let mut reader = Reader::from_str("let x = a\n  .b()\n  .c\nlet y = x");
+        let mut context = ParserContext::default();
+        let mosfet_file =
+            MosfetFile::parse(&mut reader, &mut context).expect("The parser must succeed");
+
+        assert_eq!(
+            mosfet_file.statements.len(),
+            2,
+            "The statement length is incorrect"
+        );
+    }
+
     #[test]
     fn test_parse_err_eof_before_first_statement() {
-        let mut reader = Reader::from_str(" \n t");
+        let mut reader = Reader::from_str(" \n )");
         let mut context = ParserContext::default();
         let error =
             MosfetFile::parse(&mut reader, &mut context).expect_err("The parser must not succeed");
@@ -215,7 +229,7 @@ mod tests {
 
     #[test]
     fn test_parse_err_eof_after_first_statement() {
-        let mut reader = Reader::from_str("let x = 3 t");
+        let mut reader = Reader::from_str("let x = 3 )");
         let mut context = ParserContext::default();
         let error =
             MosfetFile::parse(&mut reader, &mut context).expect_err("The parser must not succeed");
@@ -225,11 +239,13 @@ mod tests {
 
     #[test]
     fn test_parse_err_two_statements_same_line() {
-        let mut reader = Reader::from_str("let x = 3 let y = 4");
-        let mut context = ParserContext::default();
-        let error =
-            MosfetFile::parse(&mut reader, &mut context).expect_err("The parser must not succeed");
-
-        assert_error(&context, &error, ParserError::TwoStatementsInSameLineInFile);
+        for text in &["let x = 3 let y = 4", "let x = 3 + a let y = 4"] {
+            let mut reader = Reader::from_str(text);
+            let mut context = ParserContext::default();
+            let error = MosfetFile::parse(&mut reader, &mut context)
+                .expect_err("The parser must not succeed");
+
+            assert_error(&context, &error, ParserError::TwoStatementsInSameLineInFile);
+        }
     }
 }
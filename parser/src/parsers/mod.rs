@@ -4,6 +4,8 @@ pub use result::*;
 pub mod commons;
 pub mod expressions;
 pub mod file;
+pub mod patterns;
 mod result;
 pub mod statements;
-mod utils;
+pub mod types;
+pub(crate) mod utils;
@@ -0,0 +1,133 @@
+use std::sync::Arc;
+
+use crate::context::ParserContext;
+use crate::io::{Reader, Span};
+use crate::parsers::commons::sequences::{Sequence, SequenceElement};
+use crate::parsers::expressions::literals::LIST_DEFINITION;
+use crate::parsers::patterns::Pattern;
+use crate::parsers::{ParserResult, ParserResultError};
+use crate::ParserNode;
+
+/// A pattern that matches the elements of a list, e.g. `[a, b]` or `[head, ...rest]`.
+///
+/// Only the last element can be a `RestPattern`.
+#[derive(Debug)]
+pub struct ListPattern {
+    sequence: Sequence<Pattern>,
+}
+
+impl ListPattern {
+    // GETTERS ----------------------------------------------------------------
+
+    /// The elements of the pattern with their surrounding whitespaces.
+    pub fn elements(&self) -> &Vec<SequenceElement<Pattern>> {
+        self.sequence.elements()
+    }
+
+    pub fn sequence(&self) -> &Sequence<Pattern> {
+        &self.sequence
+    }
+
+    // STATIC METHODS ---------------------------------------------------------
+
+    /// Parses a `ListPattern`.
+    pub fn parse(reader: &mut Reader, context: &mut ParserContext) -> ParserResult<ListPattern> {
+        let sequence = Sequence::parse(
+            reader,
+            context,
+            &LIST_DEFINITION,
+            Pattern::parse_list_element,
+        )?;
+
+        let elements = sequence.elements();
+        for element in elements.iter().take(elements.len().saturating_sub(1)) {
+            if let Pattern::Rest(rest) = element.node().as_ref() {
+                Pattern::report_misplaced_rest(reader, context, rest);
+                return Err(ParserResultError::Error);
+            }
+        }
+
+        Ok(ListPattern { sequence })
+    }
+}
+
+impl ParserNode for ListPattern {
+    fn span(&self) -> &Arc<Span> {
+        self.sequence.span()
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use crate::test::{assert_error, assert_not_found};
+    use crate::ParserError;
+
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        for (text, length, has_rest) in &[
+            ("[]", 0, false),
+            ("[a]", 1, false),
+            ("[head, ...rest]", 2, true),
+            ("[\n  (a, b),\n  _,\n  ...,\n]", 3, true),
+        ] {
+            let mut reader = Reader::from_str(text);
+            let mut context = ParserContext::default();
+            let pattern =
+                ListPattern::parse(&mut reader, &mut context).expect("The parser must succeed");
+
+            assert_eq!(pattern.content(), *text, "The content is incorrect");
+            assert_eq!(
+                pattern.elements().len(),
+                *length,
+                "The elements length is incorrect"
+            );
+            assert_eq!(
+                pattern
+                    .elements()
+                    .last()
+                    .map(|v| matches!(v.node().as_ref(), Pattern::Rest(_)))
+                    .unwrap_or(false),
+                *has_rest,
+                "The rest element is incorrect"
+            );
+        }
+    }
+
+    #[test]
+    fn test_parse_err_not_found() {
+        let mut reader = Reader::from_str("(a, b)");
+        let mut context = ParserContext::default();
+        let error =
+            ListPattern::parse(&mut reader, &mut context).expect_err("The parser must not succeed");
+
+        assert_not_found(&context, &error, 0);
+    }
+
+    #[test]
+    fn test_parse_err_misplaced_rest() {
+        for text in &["[...rest, a]", "[a, ..., b]", "[...a, ...b]", "[[...a, b]]"] {
+            let mut reader = Reader::from_str(text);
+            let mut context = ParserContext::default();
+            let error = ListPattern::parse(&mut reader, &mut context)
+                .expect_err("The parser must not succeed");
+
+            assert_error(&context, &error, ParserError::MisplacedRestPattern);
+        }
+    }
+
+    #[test]
+    fn test_parse_err_without_end_token() {
+        let mut reader = Reader::from_str("[a, b");
+        let mut context = ParserContext::default();
+        let error =
+            ListPattern::parse(&mut reader, &mut context).expect_err("The parser must not succeed");
+
+        assert_error(&context, &error, ParserError::ListWithoutEndToken);
+    }
+}
@@ -0,0 +1,164 @@
+use std::sync::Arc;
+
+use crate::context::ParserContext;
+use crate::io::{Reader, Span};
+use crate::parsers::expressions::literals::{
+    BooleanLiteral, Literal, NilLiteral, Number, StringLiteral,
+};
+use crate::parsers::expressions::unary::NEGATION_TOKEN;
+use crate::parsers::utils::cursor_manager;
+use crate::parsers::{ParserResult, ParserResultError};
+use crate::ParserNode;
+
+/// A pattern that matches a constant value, e.g. `0xFF`, `-1` or `"text"`.
+///
+/// Only numbers, strings, booleans and nil are allowed. Numbers can be preceded by
+/// a minus sign without whitespaces to match negative values.
+#[derive(Debug)]
+pub struct LiteralPattern {
+    span: Arc<Span>,
+    literal: Arc<Literal>,
+    is_negative: bool,
+}
+
+impl LiteralPattern {
+    // GETTERS ----------------------------------------------------------------
+
+    pub fn literal(&self) -> &Arc<Literal> {
+        &self.literal
+    }
+
+    /// Whether the number is preceded by a minus sign or not.
+    pub fn is_negative(&self) -> bool {
+        self.is_negative
+    }
+
+    // STATIC METHODS ---------------------------------------------------------
+
+    /// Parses a `LiteralPattern`.
+    pub fn parse(reader: &mut Reader, context: &mut ParserContext) -> ParserResult<LiteralPattern> {
+        cursor_manager(reader, |reader, init_cursor| {
+            if reader.read(NEGATION_TOKEN) {
+                let number = Number::parse(reader, context)?;
+
+                return Ok(LiteralPattern {
+                    span: Arc::new(reader.substring_to_current(init_cursor)),
+                    literal: Arc::new(Literal::Number(Arc::new(number))),
+                    is_negative: true,
+                });
+            }
+
+            let literal = Self::parse_literal(reader, context)?;
+
+            Ok(LiteralPattern {
+                span: Arc::new(reader.substring_to_current(init_cursor)),
+                literal: Arc::new(literal),
+                is_negative: false,
+            })
+        })
+    }
+
+    /// Parses any of the literals allowed in patterns.
+    fn parse_literal(reader: &mut Reader, context: &mut ParserContext) -> ParserResult<Literal> {
+        match Number::parse(reader, context) {
+            Ok(node) => return Ok(Literal::Number(Arc::new(node))),
+            Err(ParserResultError::NotFound) => { /* Ignore because not found */ }
+            Err(ParserResultError::Error) => return Err(ParserResultError::Error),
+        }
+
+        match StringLiteral::parse(reader, context) {
+            Ok(node) => return Ok(Literal::String(Arc::new(node))),
+            Err(ParserResultError::NotFound) => { /* Ignore because not found */ }
+            Err(ParserResultError::Error) => return Err(ParserResultError::Error),
+        }
+
+        match BooleanLiteral::parse(reader, context) {
+            Ok(node) => return Ok(Literal::Boolean(Arc::new(node))),
+            Err(ParserResultError::NotFound) => { /* Ignore because not found */ }
+            Err(ParserResultError::Error) => return Err(ParserResultError::Error),
+        }
+
+        match NilLiteral::parse(reader, context) {
+            Ok(node) => return Ok(Literal::Nil(Arc::new(node))),
+            Err(ParserResultError::NotFound) => { /* Ignore because not found */ }
+            Err(ParserResultError::Error) => return Err(ParserResultError::Error),
+        }
+
+        Err(ParserResultError::NotFound)
+    }
+}
+
+impl ParserNode for LiteralPattern {
+    fn span(&self) -> &Arc<Span> {
+        &self.span
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use crate::parsers::expressions::literals::integer::Radix;
+    use crate::test::assert_not_found;
+
+    use super::*;
+
+    #[test]
+    fn test_parse_numbers() {
+        for (text, radix, is_negative) in &[
+            ("0b1010", Radix::Binary, false),
+            ("0o17", Radix::Octal, false),
+            ("0d99", Radix::Decimal, false),
+            ("99", Radix::Decimal, false),
+            ("0xFF", Radix::Hexadecimal, false),
+            ("-0b1", Radix::Binary, true),
+            ("-0o7", Radix::Octal, true),
+            ("-1.5e3", Radix::Decimal, true),
+            ("-0x1.8p3", Radix::Hexadecimal, true),
+        ] {
+            let mut reader = Reader::from_str(text);
+            let mut context = ParserContext::default();
+            let pattern =
+                LiteralPattern::parse(&mut reader, &mut context).expect("The parser must succeed");
+
+            assert_eq!(pattern.content(), *text, "The content is incorrect");
+            assert_eq!(
+                pattern.is_negative, *is_negative,
+                "The is_negative is incorrect"
+            );
+
+            if let Literal::Number(number) = pattern.literal.as_ref() {
+                assert_eq!(number.radix(), radix, "The radix is incorrect");
+            } else {
+                panic!("The literal type is incorrect");
+            }
+        }
+    }
+
+    #[test]
+    fn test_parse_others() {
+        for text in &["\"text\"", "r#\"raw\"#", "true", "false", "nil"] {
+            let mut reader = Reader::from_str(text);
+            let mut context = ParserContext::default();
+            let pattern =
+                LiteralPattern::parse(&mut reader, &mut context).expect("The parser must succeed");
+
+            assert_eq!(pattern.content(), *text, "The content is incorrect");
+            assert!(!pattern.is_negative, "The is_negative is incorrect");
+        }
+    }
+
+    #[test]
+    fn test_parse_err_not_found() {
+        for text in &["a", "[1]", "{}", "- 1", "-a", "-\"a\""] {
+            let mut reader = Reader::from_str(text);
+            let mut context = ParserContext::default();
+            let error = LiteralPattern::parse(&mut reader, &mut context)
+                .expect_err("The parser must not succeed");
+
+            assert_not_found(&context, &error, 0);
+        }
+    }
+}
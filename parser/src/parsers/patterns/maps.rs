@@ -0,0 +1,250 @@
+use std::sync::Arc;
+
+use doclog::Color;
+
+use crate::context::ParserContext;
+use crate::io::{Reader, Span};
+use crate::parsers::commons::identifier::Identifier;
+use crate::parsers::commons::sequences::{Sequence, SequenceElement};
+use crate::parsers::commons::whitespaces::Whitespace;
+use crate::parsers::expressions::literals::{KEY_VALUE_SEPARATOR, MAP_DEFINITION};
+use crate::parsers::patterns::Pattern;
+use crate::parsers::utils::{cursor_manager, generate_error_log, generate_source_code};
+use crate::parsers::{ParserResult, ParserResultError};
+use crate::{ParserError, ParserNode};
+
+/// A pattern that matches the entries of a map by their keys, e.g. `{ x, y: (a, b) }`.
+///
+/// An entry without pattern binds the value to a variable named like its key.
+#[derive(Debug)]
+pub struct MapPattern {
+    sequence: Sequence<MapPatternEntry>,
+}
+
+impl MapPattern {
+    // GETTERS ----------------------------------------------------------------
+
+    /// The entries of the pattern with their surrounding whitespaces.
+    pub fn entries(&self) -> &Vec<SequenceElement<MapPatternEntry>> {
+        self.sequence.elements()
+    }
+
+    pub fn sequence(&self) -> &Sequence<MapPatternEntry> {
+        &self.sequence
+    }
+
+    // STATIC METHODS ---------------------------------------------------------
+
+    /// Parses a `MapPattern`.
+    pub fn parse(reader: &mut Reader, context: &mut ParserContext) -> ParserResult<MapPattern> {
+        let sequence = Sequence::parse(reader, context, &MAP_DEFINITION, MapPatternEntry::parse)?;
+
+        Ok(MapPattern { sequence })
+    }
+}
+
+impl ParserNode for MapPattern {
+    fn span(&self) -> &Arc<Span> {
+        self.sequence.span()
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+/// An entry of a `MapPattern`, e.g. `x` or `x: (a, b)`.
+#[derive(Debug)]
+pub struct MapPatternEntry {
+    span: Arc<Span>,
+    key: Arc<Identifier>,
+    pattern: Option<Arc<Pattern>>,
+    pre_separator_whitespace: Option<Arc<Whitespace>>,
+    pre_pattern_whitespace: Option<Arc<Whitespace>>,
+}
+
+impl MapPatternEntry {
+    // GETTERS ----------------------------------------------------------------
+
+    pub fn key(&self) -> &Arc<Identifier> {
+        &self.key
+    }
+
+    pub fn pattern(&self) -> &Option<Arc<Pattern>> {
+        &self.pattern
+    }
+
+    pub fn pre_separator_whitespace(&self) -> &Option<Arc<Whitespace>> {
+        &self.pre_separator_whitespace
+    }
+
+    pub fn pre_pattern_whitespace(&self) -> &Option<Arc<Whitespace>> {
+        &self.pre_pattern_whitespace
+    }
+
+    // STATIC METHODS ---------------------------------------------------------
+
+    /// Parses a `MapPatternEntry`.
+    pub fn parse(
+        reader: &mut Reader,
+        context: &mut ParserContext,
+    ) -> ParserResult<MapPatternEntry> {
+        cursor_manager(reader, |reader, init_cursor| {
+            let key = Arc::new(Identifier::parse(reader, context)?);
+
+            let pre_separator_cursor = reader.save_cursor();
+            let pre_separator_whitespace = Whitespace::parse_multiline_or_default(reader, context);
+
+            if !reader.read(KEY_VALUE_SEPARATOR) {
+                reader.restore(pre_separator_cursor);
+
+                return Ok(MapPatternEntry {
+                    span: key.span().clone(),
+                    key,
+                    pattern: None,
+                    pre_separator_whitespace: None,
+                    pre_pattern_whitespace: None,
+                });
+            }
+
+            let pre_pattern_whitespace = Whitespace::parse_multiline_or_default(reader, context);
+
+            let pattern = match Pattern::parse_nested(reader, context) {
+                Ok(v) => v,
+                Err(ParserResultError::NotFound) => {
+                    context.add_message(generate_error_log(
+                        ParserError::MissingPatternInMapPatternEntry,
+                        "A pattern is expected after the colon of the map entry".to_string(),
+                        |log| {
+                            generate_source_code(log, reader, |doc| {
+                                doc.highlight_section(
+                                    init_cursor.byte_offset()
+                                        ..pre_pattern_whitespace
+                                            .span()
+                                            .start_cursor()
+                                            .byte_offset(),
+                                    None,
+                                    Some(Color::Magenta),
+                                )
+                                .highlight_cursor_str(
+                                    pre_pattern_whitespace.span().start_cursor().byte_offset(),
+                                    Some("Insert a pattern here"),
+                                    None,
+                                )
+                            })
+                        },
+                    ));
+
+                    return Err(ParserResultError::Error);
+                }
+                Err(ParserResultError::Error) => return Err(ParserResultError::Error),
+            };
+
+            let span = Arc::new(reader.substring_to_current(init_cursor));
+            Ok(MapPatternEntry {
+                span,
+                key,
+                pattern: Some(Arc::new(pattern)),
+                pre_separator_whitespace: Some(Arc::new(pre_separator_whitespace)),
+                pre_pattern_whitespace: Some(Arc::new(pre_pattern_whitespace)),
+            })
+        })
+    }
+}
+
+impl ParserNode for MapPatternEntry {
+    fn span(&self) -> &Arc<Span> {
+        &self.span
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use crate::test::{assert_error, assert_not_found};
+
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let mut reader = Reader::from_str("{ x, y :\n  (a, b), }");
+        let mut context = ParserContext::default();
+        let pattern =
+            MapPattern::parse(&mut reader, &mut context).expect("The parser must succeed");
+
+        assert_eq!(
+            pattern.content(),
+            "{ x, y :\n  (a, b), }",
+            "The content is incorrect"
+        );
+
+        let entries = pattern.entries();
+        assert_eq!(entries.len(), 2, "The entries length is incorrect");
+
+        let entry = entries[0].node();
+        assert_eq!(entry.content(), "x", "The first entry is incorrect");
+        assert!(entry.pattern.is_none(), "The first pattern must be empty");
+
+        let entry = entries[1].node();
+        assert_eq!(
+            entry.content(),
+            "y :\n  (a, b)",
+            "The second entry is incorrect"
+        );
+        assert_eq!(entry.key.content(), "y", "The second key is incorrect");
+        assert_eq!(
+            entry.pattern.as_ref().map(|v| v.content()),
+            Some("(a, b)"),
+            "The second pattern is incorrect"
+        );
+        assert_eq!(
+            entry.pre_separator_whitespace.as_ref().map(|v| v.content()),
+            Some(" "),
+            "The pre_separator_whitespace is incorrect"
+        );
+        assert_eq!(
+            entry.pre_pattern_whitespace.as_ref().map(|v| v.content()),
+            Some("\n  "),
+            "The pre_pattern_whitespace is incorrect"
+        );
+    }
+
+    #[test]
+    fn test_parse_err_not_found() {
+        let mut reader = Reader::from_str("[a]");
+        let mut context = ParserContext::default();
+        let error =
+            MapPattern::parse(&mut reader, &mut context).expect_err("The parser must not succeed");
+
+        assert_not_found(&context, &error, 0);
+    }
+
+    #[test]
+    fn test_parse_err_missing_pattern() {
+        for text in &["{ x: }", "{ x: +1 }"] {
+            let mut reader = Reader::from_str(text);
+            let mut context = ParserContext::default();
+            let error = MapPattern::parse(&mut reader, &mut context)
+                .expect_err("The parser must not succeed");
+
+            assert_error(
+                &context,
+                &error,
+                ParserError::MissingPatternInMapPatternEntry,
+            );
+        }
+    }
+
+    #[test]
+    fn test_parse_err_missing_comma() {
+        let mut reader = Reader::from_str("{ x y }");
+        let mut context = ParserContext::default();
+        let error =
+            MapPattern::parse(&mut reader, &mut context).expect_err("The parser must not succeed");
+
+        assert_error(&context, &error, ParserError::MissingCommaInMap);
+    }
+}
@@ -0,0 +1,345 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use doclog::Color;
+
+pub use lists::*;
+pub use literals::*;
+pub use maps::*;
+pub use ranges::*;
+pub use rests::*;
+pub use tuples::*;
+pub use wildcards::*;
+
+use crate::context::ParserContext;
+use crate::io::{Reader, Span};
+use crate::parsers::commons::identifier::Identifier;
+use crate::parsers::utils::{generate_error_log, generate_source_code};
+use crate::parsers::{ParserResult, ParserResultError};
+use crate::{ParserError, ParserNode};
+
+mod lists;
+mod literals;
+mod maps;
+mod ranges;
+mod rests;
+mod tuples;
+mod wildcards;
+
+/// A pattern in the Mosfet language that a value can be matched against, e.g. `(a, _)`.
+#[derive(Debug)]
+pub enum Pattern {
+    Literal(Arc<LiteralPattern>),
+    Range(Arc<RangePattern>),
+    Wildcard(Arc<WildcardPattern>),
+    Binding(Arc<Identifier>),
+    Parenthesized(Arc<ParenthesizedPattern>),
+    Tuple(Arc<TuplePattern>),
+    List(Arc<ListPattern>),
+    Map(Arc<MapPattern>),
+    Rest(Arc<RestPattern>),
+}
+
+impl Pattern {
+    // GETTERS ----------------------------------------------------------------
+
+    /// The names bound by the pattern in order of appearance.
+    pub fn bindings(&self) -> Vec<&Arc<Identifier>> {
+        let mut bindings = Vec::new();
+        self.collect_bindings(&mut bindings);
+        bindings
+    }
+
+    fn collect_bindings<'a>(&'a self, bindings: &mut Vec<&'a Arc<Identifier>>) {
+        match self {
+            Pattern::Binding(v) => bindings.push(v),
+            Pattern::Parenthesized(v) => v.pattern().collect_bindings(bindings),
+            Pattern::Tuple(v) => {
+                for element in v.sequence().elements() {
+                    element.node().collect_bindings(bindings);
+                }
+            }
+            Pattern::List(v) => {
+                for element in v.elements() {
+                    element.node().collect_bindings(bindings);
+                }
+            }
+            Pattern::Map(v) => {
+                for entry in v.entries() {
+                    match entry.node().pattern() {
+                        Some(pattern) => pattern.collect_bindings(bindings),
+                        None => bindings.push(entry.node().key()),
+                    }
+                }
+            }
+            Pattern::Rest(v) => {
+                if let Some(name) = v.name() {
+                    bindings.push(name);
+                }
+            }
+            Pattern::Literal(_) | Pattern::Range(_) | Pattern::Wildcard(_) => {}
+        }
+    }
+
+    // STATIC METHODS ---------------------------------------------------------
+
+    /// Parses a pattern checking that it does not bind the same name twice.
+    pub fn parse(reader: &mut Reader, context: &mut ParserContext) -> ParserResult<Pattern> {
+        let pattern = Self::parse_nested(reader, context)?;
+        pattern.check_duplicated_bindings(reader, context)?;
+
+        Ok(pattern)
+    }
+
+    /// Parses a pattern that can be nested inside another one, i.e. without checking
+    /// its bindings.
+    fn parse_nested(reader: &mut Reader, context: &mut ParserContext) -> ParserResult<Pattern> {
+        match RestPattern::parse(reader, context) {
+            Ok(node) => {
+                Self::report_misplaced_rest(reader, context, &node);
+                return Err(ParserResultError::Error);
+            }
+            Err(ParserResultError::NotFound) => { /* Ignore because not found */ }
+            Err(ParserResultError::Error) => return Err(ParserResultError::Error),
+        }
+
+        match RangePattern::parse_or_literal(reader, context) {
+            Ok(node) => return Ok(node),
+            Err(ParserResultError::NotFound) => { /* Ignore because not found */ }
+            Err(ParserResultError::Error) => return Err(ParserResultError::Error),
+        }
+
+        match WildcardPattern::parse(reader, context) {
+            Ok(node) => return Ok(Pattern::Wildcard(Arc::new(node))),
+            Err(ParserResultError::NotFound) => { /* Ignore because not found */ }
+            Err(ParserResultError::Error) => return Err(ParserResultError::Error),
+        }
+
+        match Identifier::parse(reader, context) {
+            Ok(node) => return Ok(Pattern::Binding(Arc::new(node))),
+            Err(ParserResultError::NotFound) => { /* Ignore because not found */ }
+            Err(ParserResultError::Error) => return Err(ParserResultError::Error),
+        }
+
+        match TuplePattern::parse_or_parenthesized(reader, context) {
+            Ok(node) => return Ok(node),
+            Err(ParserResultError::NotFound) => { /* Ignore because not found */ }
+            Err(ParserResultError::Error) => return Err(ParserResultError::Error),
+        }
+
+        match ListPattern::parse(reader, context) {
+            Ok(node) => return Ok(Pattern::List(Arc::new(node))),
+            Err(ParserResultError::NotFound) => { /* Ignore because not found */ }
+            Err(ParserResultError::Error) => return Err(ParserResultError::Error),
+        }
+
+        match MapPattern::parse(reader, context) {
+            Ok(node) => return Ok(Pattern::Map(Arc::new(node))),
+            Err(ParserResultError::NotFound) => { /* Ignore because not found */ }
+            Err(ParserResultError::Error) => return Err(ParserResultError::Error),
+        }
+
+        Err(ParserResultError::NotFound)
+    }
+
+    /// Parses an element of a `ListPattern`, which can also be a `RestPattern`.
+    fn parse_list_element(
+        reader: &mut Reader,
+        context: &mut ParserContext,
+    ) -> ParserResult<Pattern> {
+        match RestPattern::parse(reader, context) {
+            Ok(node) => return Ok(Pattern::Rest(Arc::new(node))),
+            Err(ParserResultError::NotFound) => { /* Ignore because not found */ }
+            Err(ParserResultError::Error) => return Err(ParserResultError::Error),
+        }
+
+        Self::parse_nested(reader, context)
+    }
+
+    fn check_duplicated_bindings(
+        &self,
+        reader: &Reader,
+        context: &mut ParserContext,
+    ) -> ParserResult<()> {
+        let mut names: HashMap<&str, &Arc<Identifier>> = HashMap::new();
+
+        for binding in self.bindings() {
+            let first_binding = match names.get(binding.content()) {
+                Some(v) => *v,
+                None => {
+                    names.insert(binding.content(), binding);
+                    continue;
+                }
+            };
+
+            context.add_message(generate_error_log(
+                ParserError::DuplicatedBindingInPattern,
+                format!(
+                    "The name '{}' is bound more than once in the same pattern",
+                    binding.content()
+                ),
+                |log| {
+                    generate_source_code(log, reader, |doc| {
+                        doc.highlight_section_str(
+                            first_binding.span().start_cursor().byte_offset()
+                                ..first_binding.span().end_cursor().byte_offset(),
+                            Some("First bound here"),
+                            None,
+                        )
+                        .highlight_section_str(
+                            binding.span().start_cursor().byte_offset()
+                                ..binding.span().end_cursor().byte_offset(),
+                            Some("Rename or remove this binding"),
+                            Some(Color::Magenta),
+                        )
+                    })
+                },
+            ));
+
+            return Err(ParserResultError::Error);
+        }
+
+        Ok(())
+    }
+
+    fn report_misplaced_rest(reader: &Reader, context: &mut ParserContext, rest: &RestPattern) {
+        context.add_message(generate_error_log(
+            ParserError::MisplacedRestPattern,
+            format!(
+                "The rest pattern '{}' is only allowed as the last element of a list pattern",
+                REST_TOKEN
+            ),
+            |log| {
+                generate_source_code(log, reader, |doc| {
+                    doc.highlight_section_str(
+                        rest.span().start_cursor().byte_offset()
+                            ..rest.span().end_cursor().byte_offset(),
+                        Some("Move or remove this pattern"),
+                        Some(Color::Magenta),
+                    )
+                })
+            },
+        ));
+    }
+}
+
+impl ParserNode for Pattern {
+    fn span(&self) -> &Arc<Span> {
+        match self {
+            Pattern::Literal(n) => n.span(),
+            Pattern::Range(n) => n.span(),
+            Pattern::Wildcard(n) => n.span(),
+            Pattern::Binding(n) => n.span(),
+            Pattern::Parenthesized(n) => n.span(),
+            Pattern::Tuple(n) => n.span(),
+            Pattern::List(n) => n.span(),
+            Pattern::Map(n) => n.span(),
+            Pattern::Rest(n) => n.span(),
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use crate::test::{assert_error, assert_not_found};
+
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        for text in &["1", "\"a\"", "true", "nil", "-1"] {
+            let mut reader = Reader::from_str(text);
+            let mut context = ParserContext::default();
+            let pattern =
+                Pattern::parse(&mut reader, &mut context).expect("The parser must succeed");
+
+            assert!(
+                matches!(pattern, Pattern::Literal(_)),
+                "The pattern type is incorrect for: {}",
+                text
+            );
+        }
+
+        for (text, is_binding) in &[("_", false), ("_a", true), ("value", true)] {
+            let mut reader = Reader::from_str(text);
+            let mut context = ParserContext::default();
+            let pattern =
+                Pattern::parse(&mut reader, &mut context).expect("The parser must succeed");
+
+            assert_eq!(pattern.content(), *text, "The content is incorrect");
+            assert_eq!(
+                matches!(pattern, Pattern::Binding(_)),
+                *is_binding,
+                "The pattern type is incorrect for: {}",
+                text
+            );
+        }
+
+        let mut reader = Reader::from_str("1..5");
+        let mut context = ParserContext::default();
+        let pattern = Pattern::parse(&mut reader, &mut context).expect("The parser must succeed");
+
+        assert!(
+            matches!(pattern, Pattern::Range(_)),
+            "The pattern type is incorrect"
+        );
+    }
+
+    #[test]
+    fn test_bindings() {
+        for (text, expected) in &[
+            ("(a, _, (b, 1..2), (c))", vec!["a", "b", "c"]),
+            ("[a, [b, _], ...c]", vec!["a", "b", "c"]),
+            ("[a, ...]", vec!["a"]),
+            ("{ a, b: (c, d), e: _ }", vec!["a", "c", "d"]),
+        ] {
+            let mut reader = Reader::from_str(text);
+            let mut context = ParserContext::default();
+            let pattern =
+                Pattern::parse(&mut reader, &mut context).expect("The parser must succeed");
+            let bindings: Vec<_> = pattern.bindings().iter().map(|v| v.content()).collect();
+
+            assert_eq!(bindings, *expected, "The bindings are incorrect");
+        }
+    }
+
+    #[test]
+    fn test_parse_err_duplicated_binding() {
+        for text in &["(a, a)", "[a, (b, a)]", "[a, ...a]", "{ a, b: a }"] {
+            let mut reader = Reader::from_str(text);
+            let mut context = ParserContext::default();
+            let error =
+                Pattern::parse(&mut reader, &mut context).expect_err("The parser must not succeed");
+
+            assert_error(&context, &error, ParserError::DuplicatedBindingInPattern);
+        }
+    }
+
+    #[test]
+    fn test_parse_err_misplaced_rest() {
+        for text in &["...a", "(a, ...b)", "{ a: ...b }", "[(...a)]"] {
+            let mut reader = Reader::from_str(text);
+            let mut context = ParserContext::default();
+            let error =
+                Pattern::parse(&mut reader, &mut context).expect_err("The parser must not succeed");
+
+            assert_error(&context, &error, ParserError::MisplacedRestPattern);
+        }
+    }
+
+    #[test]
+    fn test_parse_err_not_found() {
+        for text in &["+", "..", "if"] {
+            let mut reader = Reader::from_str(text);
+            let mut context = ParserContext::default();
+            let error =
+                Pattern::parse(&mut reader, &mut context).expect_err("The parser must not succeed");
+
+            assert_not_found(&context, &error, 0);
+        }
+    }
+}
@@ -0,0 +1,188 @@
+use std::sync::Arc;
+
+use doclog::Color;
+
+use crate::context::ParserContext;
+use crate::io::{Reader, Span};
+use crate::parsers::commons::whitespaces::Whitespace;
+use crate::parsers::expressions::ranges::{INCLUSIVE_RANGE_TOKEN, RANGE_TOKEN};
+use crate::parsers::patterns::{LiteralPattern, Pattern};
+use crate::parsers::utils::{cursor_manager, generate_error_log, generate_source_code};
+use crate::parsers::{ParserResult, ParserResultError};
+use crate::{ParserError, ParserNode};
+
+/// A pattern that matches any value between two literals, e.g. `1..5` that excludes
+/// the end or `"a"..="z"` that includes it.
+#[derive(Debug)]
+pub struct RangePattern {
+    span: Arc<Span>,
+    start: Arc<LiteralPattern>,
+    end: Arc<LiteralPattern>,
+    is_inclusive: bool,
+    pre_operator_whitespace: Arc<Whitespace>,
+    pre_end_whitespace: Arc<Whitespace>,
+}
+
+impl RangePattern {
+    // GETTERS ----------------------------------------------------------------
+
+    pub fn start(&self) -> &Arc<LiteralPattern> {
+        &self.start
+    }
+
+    pub fn end(&self) -> &Arc<LiteralPattern> {
+        &self.end
+    }
+
+    /// Whether the end is included in the range or not.
+    pub fn is_inclusive(&self) -> bool {
+        self.is_inclusive
+    }
+
+    pub fn pre_operator_whitespace(&self) -> &Arc<Whitespace> {
+        &self.pre_operator_whitespace
+    }
+
+    pub fn pre_end_whitespace(&self) -> &Arc<Whitespace> {
+        &self.pre_end_whitespace
+    }
+
+    // STATIC METHODS ---------------------------------------------------------
+
+    /// Parses a range or a literal pattern, depending on whether the literal is followed
+    /// by a range operator or not.
+    pub fn parse_or_literal(
+        reader: &mut Reader,
+        context: &mut ParserContext,
+    ) -> ParserResult<Pattern> {
+        cursor_manager(reader, |reader, init_cursor| {
+            let start = LiteralPattern::parse(reader, context)?;
+
+            let pre_operator_cursor = reader.save_cursor();
+            let pre_operator_whitespace = Whitespace::parse_inline_or_default(reader, context);
+
+            let operator_cursor = reader.save_cursor();
+            let is_inclusive = if reader.read(INCLUSIVE_RANGE_TOKEN) {
+                true
+            } else if reader.read(RANGE_TOKEN) {
+                false
+            } else {
+                reader.restore(pre_operator_cursor);
+                return Ok(Pattern::Literal(Arc::new(start)));
+            };
+
+            let pre_end_whitespace = Whitespace::parse_inline_or_default(reader, context);
+
+            let end = match LiteralPattern::parse(reader, context) {
+                Ok(v) => v,
+                Err(ParserResultError::NotFound) => {
+                    context.add_message(generate_error_log(
+                        ParserError::MissingEndInRangePattern,
+                        "A literal is expected as the end of the range pattern".to_string(),
+                        |log| {
+                            generate_source_code(log, reader, |doc| {
+                                doc.highlight_section(
+                                    operator_cursor.byte_offset()..reader.byte_offset(),
+                                    None,
+                                    Some(Color::Magenta),
+                                )
+                                .highlight_cursor_str(
+                                    reader.byte_offset(),
+                                    Some("Insert a literal here"),
+                                    None,
+                                )
+                            })
+                        },
+                    ));
+
+                    return Err(ParserResultError::Error);
+                }
+                Err(ParserResultError::Error) => return Err(ParserResultError::Error),
+            };
+
+            Ok(Pattern::Range(Arc::new(RangePattern {
+                span: Arc::new(reader.substring_to_current(init_cursor)),
+                start: Arc::new(start),
+                end: Arc::new(end),
+                is_inclusive,
+                pre_operator_whitespace: Arc::new(pre_operator_whitespace),
+                pre_end_whitespace: Arc::new(pre_end_whitespace),
+            })))
+        })
+    }
+}
+
+impl ParserNode for RangePattern {
+    fn span(&self) -> &Arc<Span> {
+        &self.span
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use crate::test::assert_error;
+
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        for (text, start, end, is_inclusive) in &[
+            ("1..5", "1", "5", false),
+            ("-10 ..= -1", "-10", "-1", true),
+            ("0x00..=0xFF", "0x00", "0xFF", true),
+            ("1.5..2.5", "1.5", "2.5", false),
+            ("\"a\"..\"z\"", "\"a\"", "\"z\"", false),
+        ] {
+            let mut reader = Reader::from_str(text);
+            let mut context = ParserContext::default();
+            let pattern = RangePattern::parse_or_literal(&mut reader, &mut context)
+                .expect("The parser must succeed");
+
+            if let Pattern::Range(range) = pattern {
+                assert_eq!(range.content(), *text, "The content is incorrect");
+                assert_eq!(range.start.content(), *start, "The start is incorrect");
+                assert_eq!(range.end.content(), *end, "The end is incorrect");
+                assert_eq!(
+                    range.is_inclusive, *is_inclusive,
+                    "The is_inclusive is incorrect"
+                );
+            } else {
+                panic!("The pattern type is incorrect");
+            }
+        }
+    }
+
+    #[test]
+    fn test_parse_literal() {
+        let mut reader = Reader::from_str("1 -> a");
+        let mut context = ParserContext::default();
+        let pattern = RangePattern::parse_or_literal(&mut reader, &mut context)
+            .expect("The parser must succeed");
+
+        assert!(
+            matches!(pattern, Pattern::Literal(_)),
+            "The pattern type is incorrect"
+        );
+        assert_eq!(
+            reader.remaining_content(),
+            " -> a",
+            "The remaining content is incorrect"
+        );
+    }
+
+    #[test]
+    fn test_parse_err_missing_end() {
+        for text in &["1..", "1..=a", "1.. (2)"] {
+            let mut reader = Reader::from_str(text);
+            let mut context = ParserContext::default();
+            let error = RangePattern::parse_or_literal(&mut reader, &mut context)
+                .expect_err("The parser must not succeed");
+
+            assert_error(&context, &error, ParserError::MissingEndInRangePattern);
+        }
+    }
+}
@@ -0,0 +1,99 @@
+use std::sync::Arc;
+
+use crate::context::ParserContext;
+use crate::io::{Reader, Span};
+use crate::parsers::commons::identifier::Identifier;
+use crate::parsers::utils::cursor_manager;
+use crate::parsers::{ParserResult, ParserResultError};
+use crate::ParserNode;
+
+pub static REST_TOKEN: &str = "...";
+
+/// A pattern that matches the remaining elements of a list, optionally binding them,
+/// e.g. `...` or `...rest`.
+///
+/// It is only allowed as the last element of a `ListPattern` and the name must be placed
+/// just after the token, without whitespaces.
+#[derive(Debug)]
+pub struct RestPattern {
+    span: Arc<Span>,
+    name: Option<Arc<Identifier>>,
+}
+
+impl RestPattern {
+    // GETTERS ----------------------------------------------------------------
+
+    pub fn name(&self) -> &Option<Arc<Identifier>> {
+        &self.name
+    }
+
+    // STATIC METHODS ---------------------------------------------------------
+
+    /// Parses a `RestPattern`.
+    pub fn parse(reader: &mut Reader, context: &mut ParserContext) -> ParserResult<RestPattern> {
+        cursor_manager(reader, |reader, init_cursor| {
+            if !reader.read(REST_TOKEN) {
+                return Err(ParserResultError::NotFound);
+            }
+
+            let name = match Identifier::parse(reader, context) {
+                Ok(v) => Some(Arc::new(v)),
+                Err(ParserResultError::NotFound) => None,
+                Err(ParserResultError::Error) => return Err(ParserResultError::Error),
+            };
+
+            let span = Arc::new(reader.substring_to_current(init_cursor));
+            Ok(RestPattern { span, name })
+        })
+    }
+}
+
+impl ParserNode for RestPattern {
+    fn span(&self) -> &Arc<Span> {
+        &self.span
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use crate::test::assert_not_found;
+
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        for (text, content, name) in &[
+            ("...rest]", "...rest", Some("rest")),
+            ("...]", "...", None),
+            ("... rest", "...", None),
+        ] {
+            let mut reader = Reader::from_str(text);
+            let mut context = ParserContext::default();
+            let pattern =
+                RestPattern::parse(&mut reader, &mut context).expect("The parser must succeed");
+
+            assert_eq!(pattern.content(), *content, "The content is incorrect");
+            assert_eq!(
+                pattern.name.as_ref().map(|v| v.content()),
+                *name,
+                "The name is incorrect"
+            );
+        }
+    }
+
+    #[test]
+    fn test_parse_err_not_found() {
+        for text in &["..", "a", "_"] {
+            let mut reader = Reader::from_str(text);
+            let mut context = ParserContext::default();
+            let error = RestPattern::parse(&mut reader, &mut context)
+                .expect_err("The parser must not succeed");
+
+            assert_not_found(&context, &error, 0);
+        }
+    }
+}
@@ -0,0 +1,171 @@
+use std::sync::Arc;
+
+use crate::context::ParserContext;
+use crate::io::{Reader, Span};
+use crate::parsers::commons::sequences::Sequence;
+use crate::parsers::commons::whitespaces::Whitespace;
+use crate::parsers::expressions::tuples::TUPLE_DEFINITION;
+use crate::parsers::patterns::Pattern;
+use crate::parsers::ParserResult;
+use crate::ParserNode;
+
+/// A pattern enclosed between parentheses to group it, e.g. `(a)`.
+#[derive(Debug)]
+pub struct ParenthesizedPattern {
+    span: Arc<Span>,
+    pattern: Arc<Pattern>,
+    pre_pattern_whitespace: Arc<Whitespace>,
+    post_pattern_whitespace: Arc<Whitespace>,
+}
+
+impl ParenthesizedPattern {
+    // GETTERS ----------------------------------------------------------------
+
+    pub fn pattern(&self) -> &Arc<Pattern> {
+        &self.pattern
+    }
+
+    pub fn pre_pattern_whitespace(&self) -> &Arc<Whitespace> {
+        &self.pre_pattern_whitespace
+    }
+
+    pub fn post_pattern_whitespace(&self) -> &Arc<Whitespace> {
+        &self.post_pattern_whitespace
+    }
+}
+
+impl ParserNode for ParenthesizedPattern {
+    fn span(&self) -> &Arc<Span> {
+        &self.span
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+/// A pattern that matches the elements of a tuple, e.g. `(a, _, 1)`.
+///
+/// Follows the same rules as `TupleExpression`, so a tuple with only one element
+/// requires a trailing comma, e.g. `(a,)`.
+#[derive(Debug)]
+pub struct TuplePattern {
+    sequence: Sequence<Pattern>,
+}
+
+impl TuplePattern {
+    // GETTERS ----------------------------------------------------------------
+
+    pub fn sequence(&self) -> &Sequence<Pattern> {
+        &self.sequence
+    }
+
+    // STATIC METHODS ---------------------------------------------------------
+
+    /// Parses a tuple or a parenthesized pattern, depending on the number of elements
+    /// and the trailing comma.
+    pub fn parse_or_parenthesized(
+        reader: &mut Reader,
+        context: &mut ParserContext,
+    ) -> ParserResult<Pattern> {
+        let sequence = Sequence::parse(reader, context, &TUPLE_DEFINITION, Pattern::parse_nested)?;
+
+        if sequence.elements().len() == 1 && !sequence.has_trailing_separator() {
+            let element = &sequence.elements()[0];
+
+            return Ok(Pattern::Parenthesized(Arc::new(ParenthesizedPattern {
+                span: sequence.span().clone(),
+                pattern: element.node().clone(),
+                pre_pattern_whitespace: element.pre_node_whitespace().clone(),
+                post_pattern_whitespace: element.post_node_whitespace().clone(),
+            })));
+        }
+
+        Ok(Pattern::Tuple(Arc::new(TuplePattern { sequence })))
+    }
+}
+
+impl ParserNode for TuplePattern {
+    fn span(&self) -> &Arc<Span> {
+        self.sequence.span()
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use crate::test::assert_error;
+    use crate::ParserError;
+
+    use super::*;
+
+    #[test]
+    fn test_parse_parenthesized() {
+        let mut reader = Reader::from_str("( a )");
+        let mut context = ParserContext::default();
+        let pattern = TuplePattern::parse_or_parenthesized(&mut reader, &mut context)
+            .expect("The parser must succeed");
+
+        if let Pattern::Parenthesized(parenthesized) = pattern {
+            assert_eq!(parenthesized.content(), "( a )", "The content is incorrect");
+            assert_eq!(
+                parenthesized.pattern.content(),
+                "a",
+                "The pattern is incorrect"
+            );
+        } else {
+            panic!("The pattern type is incorrect");
+        }
+    }
+
+    #[test]
+    fn test_parse_tuple() {
+        for (text, length) in &[
+            ("()", 0),
+            ("(a,)", 1),
+            ("(_, 1..5)", 2),
+            ("(\n  (a, b),\n  -1,\n  \"c\",\n)", 3),
+        ] {
+            let mut reader = Reader::from_str(text);
+            let mut context = ParserContext::default();
+            let pattern = TuplePattern::parse_or_parenthesized(&mut reader, &mut context)
+                .expect("The parser must succeed");
+
+            if let Pattern::Tuple(tuple) = pattern {
+                assert_eq!(tuple.content(), *text, "The content is incorrect");
+                assert_eq!(
+                    tuple.sequence.elements().len(),
+                    *length,
+                    "The elements length is incorrect"
+                );
+            } else {
+                panic!("The pattern type is incorrect");
+            }
+        }
+    }
+
+    #[test]
+    fn test_parse_err_without_end_token() {
+        for text in &["(a", "(a, +b)"] {
+            let mut reader = Reader::from_str(text);
+            let mut context = ParserContext::default();
+            let error = TuplePattern::parse_or_parenthesized(&mut reader, &mut context)
+                .expect_err("The parser must not succeed");
+
+            assert_error(&context, &error, ParserError::ParenthesisWithoutEndToken);
+        }
+    }
+
+    #[test]
+    fn test_parse_err_missing_comma() {
+        let mut reader = Reader::from_str("(a b)");
+        let mut context = ParserContext::default();
+        let error = TuplePattern::parse_or_parenthesized(&mut reader, &mut context)
+            .expect_err("The parser must not succeed");
+
+        assert_error(&context, &error, ParserError::MissingCommaInTuple);
+    }
+}
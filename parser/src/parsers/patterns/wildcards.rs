@@ -0,0 +1,75 @@
+use std::sync::Arc;
+
+use crate::context::ParserContext;
+use crate::io::{Reader, Span};
+use crate::parsers::commons::identifier::Identifier;
+use crate::parsers::utils::cursor_manager;
+use crate::parsers::{ParserResult, ParserResultError};
+use crate::ParserNode;
+
+pub static WILDCARD_TOKEN: &str = "_";
+
+/// A pattern that matches any value without binding it, i.e. `_`.
+#[derive(Debug)]
+pub struct WildcardPattern {
+    span: Arc<Span>,
+}
+
+impl WildcardPattern {
+    // STATIC METHODS ---------------------------------------------------------
+
+    /// Parses a `WildcardPattern`.
+    pub fn parse(
+        reader: &mut Reader,
+        context: &mut ParserContext,
+    ) -> ParserResult<WildcardPattern> {
+        cursor_manager(reader, |reader, init_cursor| {
+            if !Identifier::parse_keyword(reader, context, WILDCARD_TOKEN) {
+                return Err(ParserResultError::NotFound);
+            }
+
+            Ok(WildcardPattern {
+                span: Arc::new(reader.substring_to_current(init_cursor)),
+            })
+        })
+    }
+}
+
+impl ParserNode for WildcardPattern {
+    fn span(&self) -> &Arc<Span> {
+        &self.span
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use crate::test::assert_not_found;
+
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let mut reader = Reader::from_str("_ -> a");
+        let mut context = ParserContext::default();
+        let pattern =
+            WildcardPattern::parse(&mut reader, &mut context).expect("The parser must succeed");
+
+        assert_eq!(pattern.content(), "_", "The content is incorrect");
+    }
+
+    #[test]
+    fn test_parse_err_not_found() {
+        for text in &["_a", "a_", "a"] {
+            let mut reader = Reader::from_str(text);
+            let mut context = ParserContext::default();
+            let error = WildcardPattern::parse(&mut reader, &mut context)
+                .expect_err("The parser must not succeed");
+
+            assert_not_found(&context, &error, 0);
+        }
+    }
+}
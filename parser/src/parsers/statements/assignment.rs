@@ -0,0 +1,453 @@
+use std::sync::Arc;
+
+use doclog::Color;
+
+use crate::context::{Mutability, ParserContext};
+use crate::io::{Reader, Span};
+use crate::parsers::commons::whitespaces::Whitespace;
+use crate::parsers::expressions::binary::BinaryOperator;
+use crate::parsers::expressions::Expression;
+use crate::parsers::result::ParserResult;
+use crate::parsers::utils::{cursor_manager, generate_error_log, generate_source_code};
+use crate::parsers::ParserResultError;
+use crate::{ParserError, ParserNode};
+
+static ASSIGN_TOKEN: &str = "=";
+
+/// The operators sorted so that longer tokens are read first.
+static OPERATORS: &[(&str, AssignmentOperator)] = &[
+    ("**=", AssignmentOperator::Power),
+    ("*=", AssignmentOperator::Multiply),
+    ("/=", AssignmentOperator::Divide),
+    ("%=", AssignmentOperator::Remainder),
+    ("+=", AssignmentOperator::Add),
+    ("-=", AssignmentOperator::Subtract),
+    ("<<=", AssignmentOperator::ShiftLeft),
+    (">>=", AssignmentOperator::ShiftRight),
+    ("&=", AssignmentOperator::BitAnd),
+    ("|=", AssignmentOperator::BitOr),
+    ("^=", AssignmentOperator::BitXor),
+];
+
+/// The operators that can be used in an `Assignment`.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum AssignmentOperator {
+    Assign,
+    Add,
+    Subtract,
+    Multiply,
+    Divide,
+    Remainder,
+    Power,
+    BitAnd,
+    BitOr,
+    BitXor,
+    ShiftLeft,
+    ShiftRight,
+}
+
+impl AssignmentOperator {
+    // GETTERS ----------------------------------------------------------------
+
+    /// The operator as str.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AssignmentOperator::Assign => "=",
+            AssignmentOperator::Add => "+=",
+            AssignmentOperator::Subtract => "-=",
+            AssignmentOperator::Multiply => "*=",
+            AssignmentOperator::Divide => "/=",
+            AssignmentOperator::Remainder => "%=",
+            AssignmentOperator::Power => "**=",
+            AssignmentOperator::BitAnd => "&=",
+            AssignmentOperator::BitOr => "|=",
+            AssignmentOperator::BitXor => "^=",
+            AssignmentOperator::ShiftLeft => "<<=",
+            AssignmentOperator::ShiftRight => ">>=",
+        }
+    }
+
+    /// The binary operation that a compound assignment applies between the target
+    /// and the expression, e.g. `Add` for `+=`.
+    pub fn binary_operator(&self) -> Option<BinaryOperator> {
+        match self {
+            AssignmentOperator::Assign => None,
+            AssignmentOperator::Add => Some(BinaryOperator::Add),
+            AssignmentOperator::Subtract => Some(BinaryOperator::Subtract),
+            AssignmentOperator::Multiply => Some(BinaryOperator::Multiply),
+            AssignmentOperator::Divide => Some(BinaryOperator::Divide),
+            AssignmentOperator::Remainder => Some(BinaryOperator::Remainder),
+            AssignmentOperator::Power => Some(BinaryOperator::Power),
+            AssignmentOperator::BitAnd => Some(BinaryOperator::BitAnd),
+            AssignmentOperator::BitOr => Some(BinaryOperator::BitOr),
+            AssignmentOperator::BitXor => Some(BinaryOperator::BitXor),
+            AssignmentOperator::ShiftLeft => Some(BinaryOperator::ShiftLeft),
+            AssignmentOperator::ShiftRight => Some(BinaryOperator::ShiftRight),
+        }
+    }
+
+    // STATIC METHODS ---------------------------------------------------------
+
+    /// Parses an `AssignmentOperator`.
+    pub fn parse(reader: &mut Reader) -> ParserResult<AssignmentOperator> {
+        for (token, operator) in OPERATORS {
+            if reader.read(token) {
+                return Ok(*operator);
+            }
+        }
+
+        // Prevent reading the equal operator '=='.
+        if !reader.continues_with("==") && reader.read(ASSIGN_TOKEN) {
+            return Ok(AssignmentOperator::Assign);
+        }
+
+        Err(ParserResultError::NotFound)
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+/// An assignment of a new value to a variable, a member or an index, e.g. `a = 1`,
+/// `a.b += 1` or `a[0] *= 2`.
+#[derive(Debug)]
+pub struct Assignment {
+    span: Arc<Span>,
+    target: Arc<Expression>,
+    operator: AssignmentOperator,
+    expression: Arc<Expression>,
+    pre_operator_whitespace: Arc<Whitespace>,
+    pre_expression_whitespace: Arc<Whitespace>,
+}
+
+impl Assignment {
+    // GETTERS ----------------------------------------------------------------
+
+    pub fn target(&self) -> &Arc<Expression> {
+        &self.target
+    }
+
+    pub fn operator(&self) -> AssignmentOperator {
+        self.operator
+    }
+
+    pub fn expression(&self) -> &Arc<Expression> {
+        &self.expression
+    }
+
+    pub fn pre_operator_whitespace(&self) -> &Arc<Whitespace> {
+        &self.pre_operator_whitespace
+    }
+
+    pub fn pre_expression_whitespace(&self) -> &Arc<Whitespace> {
+        &self.pre_expression_whitespace
+    }
+
+    // STATIC METHODS ---------------------------------------------------------
+
+    /// Parses an assignment to `target`, which must be placed just before the current
+    /// position of the reader. Returns `NotFound` if there is no assignment operator.
+    pub fn parse(
+        reader: &mut Reader,
+        context: &mut ParserContext,
+        target: &Arc<Expression>,
+    ) -> ParserResult<Assignment> {
+        cursor_manager(reader, |reader, _init_cursor| {
+            let pre_operator_whitespace = Whitespace::parse_inline_or_default(reader, context);
+            let operator_cursor = reader.save_cursor();
+            let operator = AssignmentOperator::parse(reader)?;
+
+            if !Self::is_assignable(target) {
+                context.add_message(generate_error_log(
+                    ParserError::InvalidAssignmentTarget,
+                    "Only variables, members and indexes can be assigned".to_string(),
+                    |log| {
+                        generate_source_code(log, reader, |doc| {
+                            doc.highlight_section_str(
+                                target.span().start_cursor().byte_offset()
+                                    ..target.span().end_cursor().byte_offset(),
+                                Some("This expression cannot be assigned"),
+                                Some(Color::Magenta),
+                            )
+                            .highlight_section(
+                                operator_cursor.byte_offset()..reader.byte_offset(),
+                                None,
+                                None,
+                            )
+                        })
+                    },
+                ));
+
+                return Err(ParserResultError::Error);
+            }
+
+            Self::check_mutability(reader, context, target)?;
+
+            let pre_expression_whitespace = Whitespace::parse_multiline_or_default(reader, context);
+
+            let expression = match Expression::parse(reader, context) {
+                Ok(v) => v,
+                Err(ParserResultError::Error) => return Err(ParserResultError::Error),
+                Err(ParserResultError::NotFound) => {
+                    context.add_message(generate_error_log(
+                        ParserError::MissingExpressionInAssignment,
+                        format!(
+                            "An expression is expected after the assign operator '{}'",
+                            operator.as_str()
+                        ),
+                        |log| {
+                            generate_source_code(log, reader, |doc| {
+                                doc.highlight_section(
+                                    target.span().start_cursor().byte_offset()
+                                        ..pre_expression_whitespace
+                                            .span()
+                                            .start_cursor()
+                                            .byte_offset(),
+                                    None,
+                                    Some(Color::Magenta),
+                                )
+                                .highlight_cursor_str(
+                                    pre_expression_whitespace
+                                        .span()
+                                        .start_cursor()
+                                        .byte_offset(),
+                                    Some("Insert an expression here"),
+                                    None,
+                                )
+                            })
+                        },
+                    ));
+
+                    return Err(ParserResultError::Error);
+                }
+            };
+
+            Ok(Assignment {
+                span: Arc::new(reader.substring_to_current(target.span().start_cursor())),
+                target: target.clone(),
+                operator,
+                expression: Arc::new(expression),
+                pre_operator_whitespace: Arc::new(pre_operator_whitespace),
+                pre_expression_whitespace: Arc::new(pre_expression_whitespace),
+            })
+        })
+    }
+
+    /// Checks that the variable assigned by `target`, if any, is mutable.
+    /// Members and indexes are not checked because they do not reassign the variable.
+    fn check_mutability(
+        reader: &mut Reader,
+        context: &mut ParserContext,
+        target: &Arc<Expression>,
+    ) -> ParserResult<()> {
+        let name = match target.as_ref() {
+            Expression::VariableAccess(v) => v,
+            _ => return Ok(()),
+        };
+
+        let binding = match context.find_binding(name.content()) {
+            Some(v) => v.clone(),
+            None => return Ok(()),
+        };
+
+        let message = match binding.mutability() {
+            Mutability::Mutable => return Ok(()),
+            Mutability::Immutable => format!(
+                "The variable '{}' is immutable so it cannot be reassigned",
+                name.content()
+            ),
+            Mutability::Constant => {
+                format!("The constant '{}' cannot be reassigned", name.content())
+            }
+        };
+
+        context.add_message(generate_error_log(
+            ParserError::ReassignmentOfImmutableVariable,
+            message,
+            |log| {
+                generate_source_code(log, reader, |doc| {
+                    let doc = doc.highlight_section_str(
+                        binding.span().start_cursor().byte_offset()
+                            ..binding.span().end_cursor().byte_offset(),
+                        Some("Declared here"),
+                        None,
+                    );
+
+                    let doc = if binding.mutability() == Mutability::Immutable {
+                        doc.highlight_cursor_str(
+                            binding.span().start_cursor().byte_offset(),
+                            Some("Insert 'mut ' here to allow reassignments"),
+                            None,
+                        )
+                    } else {
+                        doc
+                    };
+
+                    doc.highlight_section(
+                        name.span().start_cursor().byte_offset()..reader.byte_offset(),
+                        None,
+                        Some(Color::Magenta),
+                    )
+                })
+            },
+        ));
+
+        Err(ParserResultError::Error)
+    }
+
+    /// Whether `expression` can be the target of an assignment or not.
+    pub fn is_assignable(expression: &Expression) -> bool {
+        matches!(
+            expression,
+            Expression::VariableAccess(_) | Expression::MemberAccess(_) | Expression::Index(_)
+        )
+    }
+}
+
+impl ParserNode for Assignment {
+    fn span(&self) -> &Arc<Span> {
+        &self.span
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use crate::parsers::file::MosfetFile;
+    use crate::parsers::statements::Statement;
+    use crate::test::assert_error;
+
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        for (text, target, operator, expression) in &[
+            ("a = 1", "a", AssignmentOperator::Assign, "1"),
+            ("a.b +=\n  c * 2", "a.b", AssignmentOperator::Add, "c * 2"),
+            ("list[i] **= 2", "list[i]", AssignmentOperator::Power, "2"),
+            ("f(x).y <<= 1", "f(x).y", AssignmentOperator::ShiftLeft, "1"),
+            ("a ^= b == c", "a", AssignmentOperator::BitXor, "b == c"),
+        ] {
+            let mut reader = Reader::from_str(text);
+            let mut context = ParserContext::default();
+            let statement =
+                Statement::parse(&mut reader, &mut context).expect("The parser must succeed");
+
+            if let Statement::Assignment(assignment) = statement {
+                assert_eq!(assignment.content(), *text, "The content is incorrect");
+                assert_eq!(
+                    assignment.target.content(),
+                    *target,
+                    "The target is incorrect"
+                );
+                assert_eq!(assignment.operator, *operator, "The operator is incorrect");
+                assert_eq!(
+                    assignment.expression.content(),
+                    *expression,
+                    "The expression is incorrect"
+                );
+            } else {
+                panic!("The statement type is incorrect");
+            }
+        }
+    }
+
+    #[test]
+    fn test_parse_operators() {
+        for (token, operator) in OPERATORS {
+            let mut reader = Reader::from_str(token);
+            let result = AssignmentOperator::parse(&mut reader).expect("The parser must succeed");
+
+            assert_eq!(result, *operator, "The operator is incorrect");
+            assert_eq!(result.as_str(), *token, "The as_str is incorrect");
+        }
+    }
+
+    #[test]
+    fn test_parse_not_an_assignment() {
+        for (text, expression) in &[("a == 1", "a == 1"), ("a + 1", "a + 1"), ("a\n= 1", "a")] {
+            let mut reader = Reader::from_str(text);
+            let mut context = ParserContext::default();
+            let statement =
+                Statement::parse(&mut reader, &mut context).expect("The parser must succeed");
+
+            if let Statement::ExpressionStatement(statement) = statement {
+                assert_eq!(
+                    statement.content(),
+                    *expression,
+                    "The expression is incorrect"
+                );
+            } else {
+                panic!("The statement type is incorrect");
+            }
+        }
+    }
+
+    #[test]
+    fn test_parse_err_invalid_target() {
+        for text in &["a + b = 1", "f() = 1", "1 = a", "-a += 1", "(a) = 1"] {
+            let mut reader = Reader::from_str(text);
+            let mut context = ParserContext::default();
+            let error = Statement::parse(&mut reader, &mut context)
+                .expect_err("The parser must not succeed");
+
+            assert_error(&context, &error, ParserError::InvalidAssignmentTarget);
+        }
+    }
+
+    #[test]
+    fn test_parse_err_missing_expression() {
+        for text in &["a =", "a -= \n"] {
+            let mut reader = Reader::from_str(text);
+            let mut context = ParserContext::default();
+            let error = Statement::parse(&mut reader, &mut context)
+                .expect_err("The parser must not succeed");
+
+            assert_error(&context, &error, ParserError::MissingExpressionInAssignment);
+        }
+    }
+
+    #[test]
+    fn test_parse_mutable_variables() {
+        for text in &[
+            "let mut a = 1\na = 2",
+            "let a = 1\nlet b = {\n  let mut a = 2\n  a += 1\n  a\n}",
+            "let a = [1]\na[0] = 2\na.b = 3",
+            "let f = (a) -> {\n  let mut a = a\n  a = 2\n}",
+            "undeclared = 1",
+            "let mut [a, ...b] = list\nb = []",
+        ] {
+            let mut reader = Reader::from_str(text);
+            let mut context = ParserContext::default();
+            MosfetFile::parse(&mut reader, &mut context).expect("The parser must succeed");
+        }
+    }
+
+    #[test]
+    fn test_parse_err_immutable_variable() {
+        for text in &[
+            "let a = 1\na = 2",
+            "const A = 1\nA += 1",
+            "let (a, b) = pair\nb = 1",
+            "let mut a = 1\nlet b = {\n  let a = 2\n  a = 3\n}",
+            "let a = 1\nlet b = {\n  let mut a = 2\n  a\n}\na = 4",
+            "let mut a = 1\nlet f = (a) -> { a = 2 }",
+            "let mut a = 1\nlet b = match 1 { a -> { a = 2 } }",
+        ] {
+            let mut reader = Reader::from_str(text);
+            let mut context = ParserContext::default();
+            let error = MosfetFile::parse(&mut reader, &mut context)
+                .expect_err("The parser must not succeed");
+
+            assert_error(
+                &context,
+                &error,
+                ParserError::ReassignmentOfImmutableVariable,
+            );
+        }
+    }
+}
@@ -0,0 +1,201 @@
+use std::sync::Arc;
+
+use crate::context::ParserContext;
+use crate::io::{Reader, Span};
+use crate::parsers::commons::identifier::Identifier;
+use crate::parsers::commons::whitespaces::Whitespace;
+use crate::parsers::expressions::Expression;
+use crate::parsers::result::ParserResult;
+use crate::parsers::statements::LoopLabel;
+use crate::parsers::utils::cursor_manager;
+use crate::parsers::ParserResultError;
+use crate::{ParserError, ParserNode};
+
+static KEYWORD: &str = "break";
+
+/// A statement that exits a loop, e.g. `break`, `break 'outer` or `break 'outer value`.
+///
+/// The label and the value must be placed in the same line as the keyword.
+#[derive(Debug)]
+pub struct BreakStatement {
+    span: Arc<Span>,
+    label: Option<Arc<LoopLabel>>,
+    value: Option<Arc<Expression>>,
+    pre_label_whitespace: Option<Arc<Whitespace>>,
+    pre_value_whitespace: Option<Arc<Whitespace>>,
+}
+
+impl BreakStatement {
+    // GETTERS ----------------------------------------------------------------
+
+    pub fn label(&self) -> &Option<Arc<LoopLabel>> {
+        &self.label
+    }
+
+    pub fn value(&self) -> &Option<Arc<Expression>> {
+        &self.value
+    }
+
+    pub fn pre_label_whitespace(&self) -> &Option<Arc<Whitespace>> {
+        &self.pre_label_whitespace
+    }
+
+    pub fn pre_value_whitespace(&self) -> &Option<Arc<Whitespace>> {
+        &self.pre_value_whitespace
+    }
+
+    // STATIC METHODS ---------------------------------------------------------
+
+    /// Parses a break statement.
+    pub fn parse(reader: &mut Reader, context: &mut ParserContext) -> ParserResult<BreakStatement> {
+        cursor_manager(reader, |reader, init_cursor| {
+            if !Identifier::parse_keyword(reader, context, KEYWORD) {
+                return Err(ParserResultError::NotFound);
+            }
+
+            // Label.
+            let pre_label_cursor = reader.save_cursor();
+            let pre_label_whitespace = Whitespace::parse_inline_or_default(reader, context);
+
+            let (label, pre_label_whitespace) = match LoopLabel::parse(reader, context) {
+                Ok(v) => (Some(Arc::new(v)), Some(Arc::new(pre_label_whitespace))),
+                Err(ParserResultError::NotFound) => {
+                    reader.restore(pre_label_cursor);
+                    (None, None)
+                }
+                Err(ParserResultError::Error) => return Err(ParserResultError::Error),
+            };
+
+            // Value.
+            let pre_value_cursor = reader.save_cursor();
+            let pre_value_whitespace = Whitespace::parse_inline_or_default(reader, context);
+
+            let (value, pre_value_whitespace) = match Expression::parse(reader, context) {
+                Ok(v) => (Some(Arc::new(v)), Some(Arc::new(pre_value_whitespace))),
+                Err(ParserResultError::NotFound) => {
+                    reader.restore(pre_value_cursor);
+                    (None, None)
+                }
+                Err(ParserResultError::Error) => return Err(ParserResultError::Error),
+            };
+
+            let span = Arc::new(reader.substring_to_current(init_cursor));
+
+            LoopLabel::check_target(
+                reader,
+                context,
+                &span,
+                &label,
+                ParserError::BreakOutsideLoop,
+                KEYWORD,
+            )?;
+
+            Ok(BreakStatement {
+                span,
+                label,
+                value,
+                pre_label_whitespace,
+                pre_value_whitespace,
+            })
+        })
+    }
+}
+
+impl ParserNode for BreakStatement {
+    fn span(&self) -> &Arc<Span> {
+        &self.span
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use crate::parsers::file::MosfetFile;
+    use crate::test::{assert_error, assert_not_found};
+
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        for (text, label, value) in &[
+            ("break\n1", None, None),
+            ("break 'a", Some("'a"), None),
+            ("break  a + 1", None, Some("a + 1")),
+            ("break 'a a + 1", Some("'a"), Some("a + 1")),
+        ] {
+            let mut reader = Reader::from_str(text);
+            let mut context = ParserContext::default();
+            context.enter_loop(Some("a".to_string()));
+            let statement =
+                BreakStatement::parse(&mut reader, &mut context).expect("The parser must succeed");
+
+            assert_eq!(
+                statement.label.as_ref().map(|v| v.content()),
+                *label,
+                "The label is incorrect"
+            );
+            assert_eq!(
+                statement.value.as_ref().map(|v| v.content()),
+                *value,
+                "The value is incorrect"
+            );
+        }
+    }
+
+    #[test]
+    fn test_parse_err_not_found() {
+        for text in &["breaks", "-"] {
+            let mut reader = Reader::from_str(text);
+            let mut context = ParserContext::default();
+            let error = BreakStatement::parse(&mut reader, &mut context)
+                .expect_err("The parser must not succeed");
+
+            assert_not_found(&context, &error, 0);
+        }
+    }
+
+    #[test]
+    fn test_parse_err_outside_loop() {
+        for text in &[
+            "break",
+            "let a = {\n  break\n}",
+            "loop {\n  let f = () -> {\n    break\n  }\n}",
+        ] {
+            let mut reader = Reader::from_str(text);
+            let mut context = ParserContext::default();
+            let error = MosfetFile::parse(&mut reader, &mut context)
+                .expect_err("The parser must not succeed");
+
+            assert_error(&context, &error, ParserError::BreakOutsideLoop);
+        }
+
+        for text in &[
+            "loop {\n  break\n}",
+            "'outer: for a in b {\n  while c {\n    break 'outer 1\n  }\n}",
+            "fn f() {\n  loop {\n    if a {\n      break\n    }\n  }\n}",
+        ] {
+            let mut reader = Reader::from_str(text);
+            let mut context = ParserContext::default();
+            MosfetFile::parse(&mut reader, &mut context).expect("The parser must succeed");
+        }
+    }
+
+    #[test]
+    fn test_parse_err_undefined_label() {
+        for text in &[
+            "loop {\n  break 'a\n}",
+            "'a: loop {}\nloop {\n  break 'a\n}",
+            "'a: loop {\n  let f = () -> {\n    loop {\n      break 'a\n    }\n  }\n}",
+        ] {
+            let mut reader = Reader::from_str(text);
+            let mut context = ParserContext::default();
+            let error = MosfetFile::parse(&mut reader, &mut context)
+                .expect_err("The parser must not succeed");
+
+            assert_error(&context, &error, ParserError::UndefinedLoopLabel);
+        }
+    }
+}
@@ -0,0 +1,145 @@
+use std::sync::Arc;
+
+use crate::context::ParserContext;
+use crate::io::{Reader, Span};
+use crate::parsers::commons::identifier::Identifier;
+use crate::parsers::commons::whitespaces::Whitespace;
+use crate::parsers::result::ParserResult;
+use crate::parsers::statements::LoopLabel;
+use crate::parsers::utils::cursor_manager;
+use crate::parsers::ParserResultError;
+use crate::{ParserError, ParserNode};
+
+static KEYWORD: &str = "continue";
+
+/// A statement that skips to the next iteration of a loop, e.g. `continue` or `continue 'outer`.
+///
+/// The label must be placed in the same line as the keyword.
+#[derive(Debug)]
+pub struct ContinueStatement {
+    span: Arc<Span>,
+    label: Option<Arc<LoopLabel>>,
+    pre_label_whitespace: Option<Arc<Whitespace>>,
+}
+
+impl ContinueStatement {
+    // GETTERS ----------------------------------------------------------------
+
+    pub fn label(&self) -> &Option<Arc<LoopLabel>> {
+        &self.label
+    }
+
+    pub fn pre_label_whitespace(&self) -> &Option<Arc<Whitespace>> {
+        &self.pre_label_whitespace
+    }
+
+    // STATIC METHODS ---------------------------------------------------------
+
+    /// Parses a continue statement.
+    pub fn parse(
+        reader: &mut Reader,
+        context: &mut ParserContext,
+    ) -> ParserResult<ContinueStatement> {
+        cursor_manager(reader, |reader, init_cursor| {
+            if !Identifier::parse_keyword(reader, context, KEYWORD) {
+                return Err(ParserResultError::NotFound);
+            }
+
+            let pre_label_cursor = reader.save_cursor();
+            let pre_label_whitespace = Whitespace::parse_inline_or_default(reader, context);
+
+            let (label, pre_label_whitespace) = match LoopLabel::parse(reader, context) {
+                Ok(v) => (Some(Arc::new(v)), Some(Arc::new(pre_label_whitespace))),
+                Err(ParserResultError::NotFound) => {
+                    reader.restore(pre_label_cursor);
+                    (None, None)
+                }
+                Err(ParserResultError::Error) => return Err(ParserResultError::Error),
+            };
+
+            let span = Arc::new(reader.substring_to_current(init_cursor));
+
+            LoopLabel::check_target(
+                reader,
+                context,
+                &span,
+                &label,
+                ParserError::ContinueOutsideLoop,
+                KEYWORD,
+            )?;
+
+            Ok(ContinueStatement {
+                span,
+                label,
+                pre_label_whitespace,
+            })
+        })
+    }
+}
+
+impl ParserNode for ContinueStatement {
+    fn span(&self) -> &Arc<Span> {
+        &self.span
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use crate::parsers::file::MosfetFile;
+    use crate::test::{assert_error, assert_not_found};
+
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        for (text, label) in &[("continue\n'a", None), ("continue  'a", Some("'a"))] {
+            let mut reader = Reader::from_str(text);
+            let mut context = ParserContext::default();
+            context.enter_loop(Some("a".to_string()));
+            let statement = ContinueStatement::parse(&mut reader, &mut context)
+                .expect("The parser must succeed");
+
+            assert_eq!(
+                statement.label.as_ref().map(|v| v.content()),
+                *label,
+                "The label is incorrect"
+            );
+        }
+    }
+
+    #[test]
+    fn test_parse_err_not_found() {
+        let mut reader = Reader::from_str("continues");
+        let mut context = ParserContext::default();
+        let error = ContinueStatement::parse(&mut reader, &mut context)
+            .expect_err("The parser must not succeed");
+
+        assert_not_found(&context, &error, 0);
+    }
+
+    #[test]
+    fn test_parse_err_outside_loop() {
+        for text in &["continue", "fn f() {\n  continue\n}"] {
+            let mut reader = Reader::from_str(text);
+            let mut context = ParserContext::default();
+            let error = MosfetFile::parse(&mut reader, &mut context)
+                .expect_err("The parser must not succeed");
+
+            assert_error(&context, &error, ParserError::ContinueOutsideLoop);
+        }
+    }
+
+    #[test]
+    fn test_parse_err_undefined_label() {
+        let mut reader = Reader::from_str("'a: loop {}\nwhile b {\n  continue 'a\n}");
+        let mut context = ParserContext::default();
+        let error =
+            MosfetFile::parse(&mut reader, &mut context).expect_err("The parser must not succeed");
+
+        assert_error(&context, &error, ParserError::UndefinedLoopLabel);
+    }
+}
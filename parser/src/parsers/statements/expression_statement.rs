@@ -0,0 +1,170 @@
+use std::sync::Arc;
+
+use doclog::Color;
+
+use crate::context::ParserContext;
+use crate::io::{Reader, Span};
+use crate::parsers::expressions::literals::Literal;
+use crate::parsers::expressions::Expression;
+use crate::parsers::utils::{generate_source_code, generate_warning_log};
+use crate::{ParserNode, ParserWarning};
+
+/// An expression evaluated only for its effects, e.g. `print(x)` or `counter.increment()`.
+#[derive(Debug)]
+pub struct ExpressionStatement {
+    expression: Arc<Expression>,
+}
+
+impl ExpressionStatement {
+    // CONSTRUCTORS -----------------------------------------------------------
+
+    /// Builds a new `ExpressionStatement` warning if the `expression` has no effect.
+    pub fn new(
+        reader: &Reader,
+        context: &mut ParserContext,
+        expression: Arc<Expression>,
+    ) -> ExpressionStatement {
+        let statement = ExpressionStatement { expression };
+        statement.check_without_effect(reader, context);
+        statement
+    }
+
+    // GETTERS ----------------------------------------------------------------
+
+    pub fn expression(&self) -> &Arc<Expression> {
+        &self.expression
+    }
+
+    // METHODS ----------------------------------------------------------------
+
+    /// Warns about expressions whose value is discarded without any effect,
+    /// like a lone literal or variable access.
+    fn check_without_effect(&self, reader: &Reader, context: &mut ParserContext) {
+        if context.ignore().statement_without_effect {
+            return;
+        }
+
+        // Lists, maps and interpolated strings can contain expressions with effects.
+        let without_effect = match self.expression.as_ref() {
+            Expression::Literal(literal) => match literal.as_ref() {
+                Literal::List(_) | Literal::Map(_) => false,
+                Literal::String(string) => !string.has_interpolations(),
+                _ => true,
+            },
+            Expression::VariableAccess(_) => true,
+            _ => false,
+        };
+
+        if !without_effect {
+            return;
+        }
+
+        let span = self.span();
+        context.add_message(generate_warning_log(
+            ParserWarning::StatementWithoutEffect,
+            "This statement has no effect".to_string(),
+            |log| {
+                generate_source_code(log, reader, |doc| {
+                    doc.highlight_section_str(
+                        span.start_cursor().byte_offset()..span.end_cursor().byte_offset(),
+                        Some("Remove this expression or use its value"),
+                        Some(Color::Magenta),
+                    )
+                })
+            },
+        ));
+    }
+}
+
+impl ParserNode for ExpressionStatement {
+    fn span(&self) -> &Arc<Span> {
+        self.expression.span()
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use crate::parsers::file::MosfetFile;
+    use crate::parsers::statements::Statement;
+    use crate::test::assert_warning;
+    use crate::ParserIgnoreConfig;
+
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        for text in &[
+            "print(x)",
+            "counter.increment()",
+            "if a { b() }",
+            "[f()]",
+            "\"${f()}\"",
+        ] {
+            let mut reader = Reader::from_str(text);
+            let mut context = ParserContext::default();
+            let statement =
+                Statement::parse(&mut reader, &mut context).expect("The parser must succeed");
+
+            if let Statement::ExpressionStatement(statement) = statement {
+                assert_eq!(statement.content(), *text, "The content is incorrect");
+            } else {
+                panic!("The statement type is incorrect");
+            }
+
+            assert_eq!(context.messages().len(), 0, "There must no be messages");
+        }
+    }
+
+    #[test]
+    fn test_parse_in_file() {
+        let mut reader = Reader::from_str("let a = 1\nprint(a)\na.b()");
+        let mut context = ParserContext::default();
+        let file = MosfetFile::parse(&mut reader, &mut context).expect("The parser must succeed");
+
+        assert_eq!(
+            file.statements().len(),
+            3,
+            "The statements length is incorrect"
+        );
+        assert!(
+            matches!(file.statements()[2], Statement::ExpressionStatement(_)),
+            "The last statement is incorrect"
+        );
+    }
+
+    #[test]
+    fn test_warning_without_effect() {
+        for text in &["1", "a", "\"text\"", "print(a)\na\nprint(b)"] {
+            let mut reader = Reader::from_str(text);
+            let mut context = ParserContext::default();
+            MosfetFile::parse(&mut reader, &mut context).expect("The parser must succeed");
+
+            assert_warning(&context, ParserWarning::StatementWithoutEffect);
+        }
+    }
+
+    #[test]
+    fn test_warning_without_effect_ignores_block_value() {
+        let mut reader = Reader::from_str("let a = {\n  print(b)\n  b\n}");
+        let mut context = ParserContext::default();
+        MosfetFile::parse(&mut reader, &mut context).expect("The parser must succeed");
+
+        assert_eq!(context.messages().len(), 0, "There must no be messages");
+    }
+
+    #[test]
+    fn test_ignore_warning_without_effect() {
+        let mut reader = Reader::from_str("a");
+        let mut ignore = ParserIgnoreConfig::new();
+        ignore.statement_without_effect = true;
+
+        let mut context = ParserContext::new(ignore);
+        MosfetFile::parse(&mut reader, &mut context).expect("The parser must succeed");
+
+        assert_eq!(context.messages().len(), 0, "There must no be messages");
+    }
+}
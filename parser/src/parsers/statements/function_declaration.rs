@@ -0,0 +1,695 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use doclog::Color;
+
+use crate::context::{Binding, Mutability, ParserContext};
+use crate::io::{Reader, Span};
+use crate::parsers::commons::identifier::Identifier;
+use crate::parsers::commons::sequences::{Sequence, SequenceElement};
+use crate::parsers::commons::whitespaces::Whitespace;
+use crate::parsers::expressions::blocks::{Block, BLOCK_OPEN_TOKEN};
+use crate::parsers::expressions::lambdas::{ARROW_TOKEN, PARAMETERS_DEFINITION, TYPE_SEPARATOR};
+use crate::parsers::expressions::Expression;
+use crate::parsers::types::TypeExpression;
+use crate::parsers::utils::{
+    cursor_manager, generate_error_log, generate_source_code, report_missing,
+};
+use crate::parsers::{ParserResult, ParserResultError};
+use crate::{ParserError, ParserNode};
+
+pub static FN_KEYWORD: &str = "fn";
+static DEFAULT_VALUE_SEPARATOR: &str = "=";
+
+/// A named function, e.g. `fn add(a: Int, b: Int = 1) -> Int { a + b }`.
+///
+/// The parameter list must be placed just after the name, without whitespaces,
+/// and the body is always a block.
+#[derive(Debug)]
+pub struct FunctionDeclaration {
+    span: Arc<Span>,
+    name: Arc<Identifier>,
+    parameters: Sequence<FunctionParameter>,
+    return_type: Option<Arc<TypeExpression>>,
+    body: Arc<Block>,
+    pre_name_whitespace: Arc<Whitespace>,
+    pre_arrow_whitespace: Option<Arc<Whitespace>>,
+    pre_return_type_whitespace: Option<Arc<Whitespace>>,
+    pre_body_whitespace: Arc<Whitespace>,
+}
+
+impl FunctionDeclaration {
+    // GETTERS ----------------------------------------------------------------
+
+    pub fn name(&self) -> &Arc<Identifier> {
+        &self.name
+    }
+
+    /// The parameters of the function with their surrounding whitespaces.
+    pub fn parameters(&self) -> &Vec<SequenceElement<FunctionParameter>> {
+        self.parameters.elements()
+    }
+
+    pub fn parameter_sequence(&self) -> &Sequence<FunctionParameter> {
+        &self.parameters
+    }
+
+    pub fn return_type(&self) -> &Option<Arc<TypeExpression>> {
+        &self.return_type
+    }
+
+    pub fn body(&self) -> &Arc<Block> {
+        &self.body
+    }
+
+    pub fn pre_name_whitespace(&self) -> &Arc<Whitespace> {
+        &self.pre_name_whitespace
+    }
+
+    pub fn pre_arrow_whitespace(&self) -> &Option<Arc<Whitespace>> {
+        &self.pre_arrow_whitespace
+    }
+
+    pub fn pre_return_type_whitespace(&self) -> &Option<Arc<Whitespace>> {
+        &self.pre_return_type_whitespace
+    }
+
+    pub fn pre_body_whitespace(&self) -> &Arc<Whitespace> {
+        &self.pre_body_whitespace
+    }
+
+    // STATIC METHODS ---------------------------------------------------------
+
+    /// Parses a function declaration.
+    pub fn parse(
+        reader: &mut Reader,
+        context: &mut ParserContext,
+    ) -> ParserResult<FunctionDeclaration> {
+        cursor_manager(reader, |reader, init_cursor| {
+            if !Identifier::parse_keyword(reader, context, FN_KEYWORD) {
+                return Err(ParserResultError::NotFound);
+            }
+
+            let pre_name_whitespace = Whitespace::parse_multiline_or_default(reader, context);
+
+            let name = match Identifier::parse(reader, context) {
+                Ok(v) => Arc::new(v),
+                Err(ParserResultError::Error) => return Err(ParserResultError::Error),
+                Err(ParserResultError::NotFound) => {
+                    report_missing(
+                        reader,
+                        context,
+                        init_cursor,
+                        ParserError::MissingNameInFunctionDeclaration,
+                        "The function name is missing",
+                        "Insert an identifier here",
+                    );
+
+                    return Err(ParserResultError::Error);
+                }
+            };
+
+            let parameters = match Self::parse_parameters(reader, context) {
+                Ok(v) => v,
+                Err(ParserResultError::Error) => return Err(ParserResultError::Error),
+                Err(ParserResultError::NotFound) => {
+                    report_missing(
+                        reader,
+                        context,
+                        init_cursor,
+                        ParserError::MissingParametersInFunctionDeclaration,
+                        "The parameter list is required after the function name",
+                        "Insert the parameter list here, just after the name",
+                    );
+
+                    return Err(ParserResultError::Error);
+                }
+            };
+
+            // Return type.
+            let pre_arrow_cursor = reader.save_cursor();
+            let pre_arrow_whitespace = Whitespace::parse_inline_or_default(reader, context);
+
+            let (return_type, pre_arrow_whitespace, pre_return_type_whitespace) =
+                if reader.read(ARROW_TOKEN) {
+                    let pre_return_type_whitespace =
+                        Whitespace::parse_multiline_or_default(reader, context);
+
+                    let return_type = match TypeExpression::parse(reader, context) {
+                        Ok(v) => v,
+                        Err(ParserResultError::Error) => return Err(ParserResultError::Error),
+                        Err(ParserResultError::NotFound) => {
+                            report_missing(
+                                reader,
+                                context,
+                                init_cursor,
+                                ParserError::MissingReturnTypeInFunctionDeclaration,
+                                "A return type is expected after the arrow",
+                                "Insert a type here",
+                            );
+
+                            return Err(ParserResultError::Error);
+                        }
+                    };
+
+                    (
+                        Some(Arc::new(return_type)),
+                        Some(Arc::new(pre_arrow_whitespace)),
+                        Some(Arc::new(pre_return_type_whitespace)),
+                    )
+                } else {
+                    reader.restore(pre_arrow_cursor);
+                    (None, None, None)
+                };
+
+            // Declared before the body to allow recursive calls.
+            context.declare(
+                name.content().to_string(),
+                Binding::new(name.span().clone(), Mutability::Immutable),
+            );
+
+            let pre_body_whitespace = Whitespace::parse_inline_or_default(reader, context);
+
+            // The parameters are only visible inside the body.
+            context.push_scope();
+
+            for parameter in parameters.elements() {
+                let parameter_name = parameter.node().name();
+                context.declare(
+                    parameter_name.content().to_string(),
+                    Binding::new(parameter_name.span().clone(), Mutability::Immutable),
+                );
+            }
+
+            context.enter_function(init_cursor.byte_offset());
+            let body = Block::parse(reader, context);
+            context.exit_function();
+            context.pop_scope();
+
+            let body = match body {
+                Ok(v) => v,
+                Err(ParserResultError::Error) => return Err(ParserResultError::Error),
+                Err(ParserResultError::NotFound) => {
+                    report_missing(
+                        reader,
+                        context,
+                        init_cursor,
+                        ParserError::MissingBodyInFunctionDeclaration,
+                        "A block is expected as the body of the function",
+                        &format!("Insert a block '{} }}' here", BLOCK_OPEN_TOKEN),
+                    );
+
+                    return Err(ParserResultError::Error);
+                }
+            };
+
+            let span = Arc::new(reader.substring_to_current(init_cursor));
+            Ok(FunctionDeclaration {
+                span,
+                name,
+                parameters,
+                return_type,
+                body: Arc::new(body),
+                pre_name_whitespace: Arc::new(pre_name_whitespace),
+                pre_arrow_whitespace,
+                pre_return_type_whitespace,
+                pre_body_whitespace: Arc::new(pre_body_whitespace),
+            })
+        })
+    }
+
+    /// Parses a parameter list checking that parameters without default values are placed
+    /// before the others and that they are not repeated.
+    fn parse_parameters(
+        reader: &mut Reader,
+        context: &mut ParserContext,
+    ) -> ParserResult<Sequence<FunctionParameter>> {
+        let parameters = Sequence::parse(
+            reader,
+            context,
+            &PARAMETERS_DEFINITION,
+            FunctionParameter::parse,
+        )?;
+
+        let mut first_with_default: Option<&Arc<FunctionParameter>> = None;
+        let mut names: HashMap<&str, &Arc<FunctionParameter>> = HashMap::new();
+
+        for element in parameters.elements() {
+            let parameter = element.node();
+
+            if parameter.default_value.is_some() {
+                if first_with_default.is_none() {
+                    first_with_default = Some(parameter);
+                }
+            } else if let Some(with_default) = first_with_default {
+                context.add_message(generate_error_log(
+                    ParserError::RequiredParameterAfterDefaultParameter,
+                    "Parameters without a default value must be placed before the others"
+                        .to_string(),
+                    |log| {
+                        generate_source_code(log, reader, |doc| {
+                            doc.highlight_section_str(
+                                with_default.span().start_cursor().byte_offset()
+                                    ..with_default.span().end_cursor().byte_offset(),
+                                Some("First parameter with a default value"),
+                                None,
+                            )
+                            .highlight_section_str(
+                                parameter.span().start_cursor().byte_offset()
+                                    ..parameter.span().end_cursor().byte_offset(),
+                                Some("Move this parameter or give it a default value"),
+                                Some(Color::Magenta),
+                            )
+                        })
+                    },
+                ));
+
+                return Err(ParserResultError::Error);
+            }
+
+            if let Some(previous) = names.insert(parameter.name.content(), parameter) {
+                context.add_message(generate_error_log(
+                    ParserError::DuplicatedParameterInFunctionDeclaration,
+                    format!(
+                        "The parameter '{}' is declared more than once",
+                        parameter.name.content()
+                    ),
+                    |log| {
+                        generate_source_code(log, reader, |doc| {
+                            doc.highlight_section_str(
+                                previous.span().start_cursor().byte_offset()
+                                    ..previous.span().end_cursor().byte_offset(),
+                                Some("First declared here"),
+                                None,
+                            )
+                            .highlight_section_str(
+                                parameter.name.span().start_cursor().byte_offset()
+                                    ..parameter.name.span().end_cursor().byte_offset(),
+                                Some("Rename this parameter"),
+                                Some(Color::Magenta),
+                            )
+                        })
+                    },
+                ));
+
+                return Err(ParserResultError::Error);
+            }
+        }
+
+        Ok(parameters)
+    }
+}
+
+impl ParserNode for FunctionDeclaration {
+    fn span(&self) -> &Arc<Span> {
+        &self.span
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+/// A parameter of a `FunctionDeclaration` with an optional type and default value,
+/// e.g. `a`, `a: Int` or `a: Int = 1`.
+#[derive(Debug)]
+pub struct FunctionParameter {
+    span: Arc<Span>,
+    name: Arc<Identifier>,
+    type_annotation: Option<Arc<TypeExpression>>,
+    default_value: Option<Arc<Expression>>,
+    pre_colon_whitespace: Option<Arc<Whitespace>>,
+    pre_type_whitespace: Option<Arc<Whitespace>>,
+    pre_assign_operator_whitespace: Option<Arc<Whitespace>>,
+    pre_default_value_whitespace: Option<Arc<Whitespace>>,
+}
+
+impl FunctionParameter {
+    // GETTERS ----------------------------------------------------------------
+
+    pub fn name(&self) -> &Arc<Identifier> {
+        &self.name
+    }
+
+    pub fn type_annotation(&self) -> &Option<Arc<TypeExpression>> {
+        &self.type_annotation
+    }
+
+    pub fn default_value(&self) -> &Option<Arc<Expression>> {
+        &self.default_value
+    }
+
+    pub fn pre_colon_whitespace(&self) -> &Option<Arc<Whitespace>> {
+        &self.pre_colon_whitespace
+    }
+
+    pub fn pre_type_whitespace(&self) -> &Option<Arc<Whitespace>> {
+        &self.pre_type_whitespace
+    }
+
+    pub fn pre_assign_operator_whitespace(&self) -> &Option<Arc<Whitespace>> {
+        &self.pre_assign_operator_whitespace
+    }
+
+    pub fn pre_default_value_whitespace(&self) -> &Option<Arc<Whitespace>> {
+        &self.pre_default_value_whitespace
+    }
+
+    // STATIC METHODS ---------------------------------------------------------
+
+    /// Parses a `FunctionParameter`.
+    pub fn parse(
+        reader: &mut Reader,
+        context: &mut ParserContext,
+    ) -> ParserResult<FunctionParameter> {
+        cursor_manager(reader, |reader, init_cursor| {
+            let name = Identifier::parse(reader, context)?;
+
+            // Type annotation.
+            let pre_colon_cursor = reader.save_cursor();
+            let pre_colon_whitespace = Whitespace::parse_multiline_or_default(reader, context);
+
+            let (type_annotation, pre_colon_whitespace, pre_type_whitespace) = if reader
+                .read(TYPE_SEPARATOR)
+            {
+                let pre_type_whitespace = Whitespace::parse_multiline_or_default(reader, context);
+
+                let type_annotation = match TypeExpression::parse(reader, context) {
+                    Ok(v) => v,
+                    Err(ParserResultError::Error) => return Err(ParserResultError::Error),
+                    Err(ParserResultError::NotFound) => {
+                        report_missing(
+                            reader,
+                            context,
+                            init_cursor,
+                            ParserError::MissingTypeInParameter,
+                            "A type is expected after the colon",
+                            "Insert a type here",
+                        );
+
+                        return Err(ParserResultError::Error);
+                    }
+                };
+
+                (
+                    Some(Arc::new(type_annotation)),
+                    Some(Arc::new(pre_colon_whitespace)),
+                    Some(Arc::new(pre_type_whitespace)),
+                )
+            } else {
+                reader.restore(pre_colon_cursor);
+                (None, None, None)
+            };
+
+            // Default value.
+            let pre_assign_operator_cursor = reader.save_cursor();
+            let pre_assign_operator_whitespace =
+                Whitespace::parse_multiline_or_default(reader, context);
+
+            let (default_value, pre_assign_operator_whitespace, pre_default_value_whitespace) =
+                if reader.read(DEFAULT_VALUE_SEPARATOR) {
+                    let pre_default_value_whitespace =
+                        Whitespace::parse_multiline_or_default(reader, context);
+
+                    let default_value = match Expression::parse(reader, context) {
+                        Ok(v) => v,
+                        Err(ParserResultError::Error) => return Err(ParserResultError::Error),
+                        Err(ParserResultError::NotFound) => {
+                            report_missing(
+                                reader,
+                                context,
+                                init_cursor,
+                                ParserError::MissingDefaultValueInParameter,
+                                "An expression is expected as the default value of the parameter",
+                                "Insert an expression here",
+                            );
+
+                            return Err(ParserResultError::Error);
+                        }
+                    };
+
+                    (
+                        Some(Arc::new(default_value)),
+                        Some(Arc::new(pre_assign_operator_whitespace)),
+                        Some(Arc::new(pre_default_value_whitespace)),
+                    )
+                } else {
+                    reader.restore(pre_assign_operator_cursor);
+                    (None, None, None)
+                };
+
+            let span = Arc::new(reader.substring_to_current(init_cursor));
+            Ok(FunctionParameter {
+                span,
+                name: Arc::new(name),
+                type_annotation,
+                default_value,
+                pre_colon_whitespace,
+                pre_type_whitespace,
+                pre_assign_operator_whitespace,
+                pre_default_value_whitespace,
+            })
+        })
+    }
+}
+
+impl ParserNode for FunctionParameter {
+    fn span(&self) -> &Arc<Span> {
+        &self.span
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use crate::parsers::file::MosfetFile;
+    use crate::test::{assert_error, assert_not_found};
+
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let mut reader =
+            Reader::from_str("fn  add(a: Int, b: Int = 1) ->\n  Int {\n  return a + b\n}/rest");
+        let mut context = ParserContext::default();
+        let declaration =
+            FunctionDeclaration::parse(&mut reader, &mut context).expect("The parser must succeed");
+
+        assert_eq!(
+            declaration.content(),
+            "fn  add(a: Int, b: Int = 1) ->\n  Int {\n  return a + b\n}",
+            "The content is incorrect"
+        );
+        assert_eq!(declaration.name.content(), "add", "The name is incorrect");
+        assert_eq!(
+            declaration.return_type.as_ref().map(|v| v.content()),
+            Some("Int"),
+            "The return_type is incorrect"
+        );
+        assert_eq!(
+            declaration.body.statements().len(),
+            1,
+            "The body is incorrect"
+        );
+        assert_eq!(
+            declaration.pre_name_whitespace.content(),
+            "  ",
+            "The pre_name_whitespace is incorrect"
+        );
+        assert_eq!(
+            declaration
+                .pre_arrow_whitespace
+                .as_ref()
+                .map(|v| v.content()),
+            Some(" "),
+            "The pre_arrow_whitespace is incorrect"
+        );
+        assert_eq!(
+            declaration
+                .pre_return_type_whitespace
+                .as_ref()
+                .map(|v| v.content()),
+            Some("\n  "),
+            "The pre_return_type_whitespace is incorrect"
+        );
+        assert_eq!(
+            declaration.pre_body_whitespace.content(),
+            " ",
+            "The pre_body_whitespace is incorrect"
+        );
+
+        let parameters = declaration.parameters();
+        assert_eq!(parameters.len(), 2, "The parameters length is incorrect");
+
+        let parameter = parameters[0].node();
+        assert_eq!(
+            parameter.content(),
+            "a: Int",
+            "The first parameter is incorrect"
+        );
+        assert!(
+            parameter.default_value.is_none(),
+            "The first default_value must be empty"
+        );
+
+        let parameter = parameters[1].node();
+        assert_eq!(
+            parameter.content(),
+            "b: Int = 1",
+            "The second parameter is incorrect"
+        );
+        assert_eq!(
+            parameter.type_annotation.as_ref().map(|v| v.content()),
+            Some("Int"),
+            "The second type_annotation is incorrect"
+        );
+        assert_eq!(
+            parameter.default_value.as_ref().map(|v| v.content()),
+            Some("1"),
+            "The second default_value is incorrect"
+        );
+    }
+
+    #[test]
+    fn test_parse_minimal() {
+        for text in &[
+            "fn f() {}",
+            "fn f(a, b = 2,) { a }",
+            "fn f() -> (Int, Int)? {}",
+        ] {
+            let mut reader = Reader::from_str(text);
+            let mut context = ParserContext::default();
+            let declaration = FunctionDeclaration::parse(&mut reader, &mut context)
+                .expect("The parser must succeed");
+
+            assert_eq!(declaration.content(), *text, "The content is incorrect");
+        }
+    }
+
+    #[test]
+    fn test_parse_bindings() {
+        let mut reader = Reader::from_str("fn f(a) {\n  let mut b = a\n  b = f(b)\n}");
+        let mut context = ParserContext::default();
+        FunctionDeclaration::parse(&mut reader, &mut context).expect("The parser must succeed");
+
+        assert!(
+            context.find_binding("f").is_some(),
+            "The function must be declared"
+        );
+        assert!(
+            context.find_binding("a").is_none(),
+            "The parameters must not leak"
+        );
+        assert!(
+            !context.is_in_function(),
+            "The function context must be closed"
+        );
+
+        let mut reader = Reader::from_str("fn f(a) {\n  a = 1\n}");
+        let mut context = ParserContext::default();
+        let error = FunctionDeclaration::parse(&mut reader, &mut context)
+            .expect_err("The parser must not succeed");
+
+        assert_error(
+            &context,
+            &error,
+            ParserError::ReassignmentOfImmutableVariable,
+        );
+    }
+
+    #[test]
+    fn test_parse_as_statement() {
+        let mut reader = Reader::from_str("fn f() {\n  return 1\n}\nlet a = f()");
+        let mut context = ParserContext::default();
+        let file = MosfetFile::parse(&mut reader, &mut context).expect("The parser must succeed");
+
+        assert_eq!(
+            file.statements().len(),
+            2,
+            "The statements length is incorrect"
+        );
+    }
+
+    #[test]
+    fn test_parse_err_not_found() {
+        for text in &["f()", "fnf() {}"] {
+            let mut reader = Reader::from_str(text);
+            let mut context = ParserContext::default();
+            let error = FunctionDeclaration::parse(&mut reader, &mut context)
+                .expect_err("The parser must not succeed");
+
+            assert_not_found(&context, &error, 0);
+        }
+    }
+
+    #[test]
+    fn test_parse_err_missing() {
+        for (text, error_kind) in &[
+            ("fn () {}", ParserError::MissingNameInFunctionDeclaration),
+            (
+                "fn f {}",
+                ParserError::MissingParametersInFunctionDeclaration,
+            ),
+            (
+                "fn f (a) {}",
+                ParserError::MissingParametersInFunctionDeclaration,
+            ),
+            (
+                "fn f() -> {}",
+                ParserError::MissingReturnTypeInFunctionDeclaration,
+            ),
+            ("fn f()", ParserError::MissingBodyInFunctionDeclaration),
+            ("fn f() a", ParserError::MissingBodyInFunctionDeclaration),
+            ("fn f(a:) {}", ParserError::MissingTypeInParameter),
+            ("fn f(a =) {}", ParserError::MissingDefaultValueInParameter),
+        ] {
+            let mut reader = Reader::from_str(text);
+            let mut context = ParserContext::default();
+            let error = FunctionDeclaration::parse(&mut reader, &mut context)
+                .expect_err("The parser must not succeed");
+
+            assert_error(&context, &error, *error_kind);
+        }
+    }
+
+    #[test]
+    fn test_parse_err_required_after_default() {
+        let mut reader = Reader::from_str("fn f(a = 1, b) {}");
+        let mut context = ParserContext::default();
+        let error = FunctionDeclaration::parse(&mut reader, &mut context)
+            .expect_err("The parser must not succeed");
+
+        assert_error(
+            &context,
+            &error,
+            ParserError::RequiredParameterAfterDefaultParameter,
+        );
+    }
+
+    #[test]
+    fn test_parse_err_duplicated_parameter() {
+        let mut reader = Reader::from_str("fn f(a, b, a: Int) {}");
+        let mut context = ParserContext::default();
+        let error = FunctionDeclaration::parse(&mut reader, &mut context)
+            .expect_err("The parser must not succeed");
+
+        assert_error(
+            &context,
+            &error,
+            ParserError::DuplicatedParameterInFunctionDeclaration,
+        );
+    }
+
+    #[test]
+    fn test_parse_err_parameters_without_end_token() {
+        let mut reader = Reader::from_str("fn f(a, b {}");
+        let mut context = ParserContext::default();
+        let error = FunctionDeclaration::parse(&mut reader, &mut context)
+            .expect_err("The parser must not succeed");
+
+        assert_error(&context, &error, ParserError::ParametersWithoutEndToken);
+    }
+}
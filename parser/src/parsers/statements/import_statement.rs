@@ -0,0 +1,506 @@
+use std::sync::Arc;
+
+use doclog::Color;
+
+use crate::context::{Binding, Mutability, ParserContext};
+use crate::io::{Reader, Span};
+use crate::parsers::commons::identifier::Identifier;
+use crate::parsers::commons::sequences::{Sequence, SequenceDefinition, SequenceElement};
+use crate::parsers::commons::whitespaces::Whitespace;
+use crate::parsers::utils::{
+    cursor_manager, generate_error_log, generate_source_code, report_missing,
+};
+use crate::parsers::{ParserResult, ParserResultError};
+use crate::{ParserError, ParserNode};
+
+pub static IMPORT_KEYWORD: &str = "import";
+pub static AS_KEYWORD: &str = "as";
+pub static IMPORT_PATH_SEPARATOR: &str = ".";
+pub static IMPORT_ITEMS_DEFINITION: SequenceDefinition = SequenceDefinition {
+    open_token: "{",
+    close_token: "}",
+    name: "import item list",
+    missing_separator_error: ParserError::MissingCommaInImportItems,
+    without_end_token_error: ParserError::ImportItemsWithoutEndToken,
+};
+
+/// A statement that imports a module, e.g. `import a.b.c`, or some of its items,
+/// e.g. `import a.b.{x, y as z}`. Imports are only allowed at the top level of a file.
+///
+/// The module is found from the directory of the program's entry file unless its path
+/// starts with dots, e.g. `import .sibling` or `import ..parent.module`, in which case it
+/// is found from the directory of the file that contains the import.
+#[derive(Debug)]
+pub struct ImportStatement {
+    span: Arc<Span>,
+    path: Arc<ImportPath>,
+    items: Option<Sequence<ImportItem>>,
+    pre_path_whitespace: Arc<Whitespace>,
+}
+
+impl ImportStatement {
+    // GETTERS ----------------------------------------------------------------
+
+    pub fn path(&self) -> &Arc<ImportPath> {
+        &self.path
+    }
+
+    /// The imported items with their surrounding whitespaces, if any.
+    pub fn items(&self) -> Option<&Vec<SequenceElement<ImportItem>>> {
+        self.items.as_ref().map(|v| v.elements())
+    }
+
+    pub fn item_sequence(&self) -> &Option<Sequence<ImportItem>> {
+        &self.items
+    }
+
+    pub fn pre_path_whitespace(&self) -> &Arc<Whitespace> {
+        &self.pre_path_whitespace
+    }
+
+    /// The names declared by the import: the name or alias of every item or,
+    /// without items, the last name of the module path.
+    pub fn bindings(&self) -> Vec<&Arc<Identifier>> {
+        match &self.items {
+            Some(items) => items
+                .elements()
+                .iter()
+                .map(|v| v.node().binding())
+                .collect(),
+            None => self.path.segments.last().into_iter().collect(),
+        }
+    }
+
+    // STATIC METHODS ---------------------------------------------------------
+
+    /// Parses an import statement.
+    pub fn parse(
+        reader: &mut Reader,
+        context: &mut ParserContext,
+    ) -> ParserResult<ImportStatement> {
+        cursor_manager(reader, |reader, init_cursor| {
+            if !Identifier::parse_keyword(reader, context, IMPORT_KEYWORD) {
+                return Err(ParserResultError::NotFound);
+            }
+
+            let pre_path_whitespace = Whitespace::parse_multiline_or_default(reader, context);
+
+            let path = match ImportPath::parse(reader, context) {
+                Ok(v) => Arc::new(v),
+                Err(ParserResultError::Error) => return Err(ParserResultError::Error),
+                Err(ParserResultError::NotFound) => {
+                    report_missing(
+                        reader,
+                        context,
+                        init_cursor,
+                        ParserError::MissingPathInImportStatement,
+                        "The path of the module to import is missing",
+                        "Insert a module path here, e.g. a.b.c",
+                    );
+
+                    return Err(ParserResultError::Error);
+                }
+            };
+
+            // Items.
+            let items = if reader.read(IMPORT_PATH_SEPARATOR) {
+                let items = match Sequence::parse(
+                    reader,
+                    context,
+                    &IMPORT_ITEMS_DEFINITION,
+                    ImportItem::parse,
+                ) {
+                    Ok(v) => v,
+                    Err(ParserResultError::Error) => return Err(ParserResultError::Error),
+                    Err(ParserResultError::NotFound) => {
+                        report_missing(
+                            reader,
+                            context,
+                            init_cursor,
+                            ParserError::MissingNameInImportPath,
+                            "A name or a list of items is expected after the dot",
+                            "Insert a name or a list of items here",
+                        );
+
+                        return Err(ParserResultError::Error);
+                    }
+                };
+
+                if items.elements().is_empty() {
+                    context.add_message(generate_error_log(
+                        ParserError::EmptyImportItems,
+                        "The import item list requires at least one item".to_string(),
+                        |log| {
+                            generate_source_code(log, reader, |doc| {
+                                let items_span = items.span();
+
+                                doc.highlight_section(
+                                    init_cursor.byte_offset()
+                                        ..items_span.start_cursor().byte_offset(),
+                                    None,
+                                    Some(Color::Magenta),
+                                )
+                                .highlight_section_str(
+                                    items_span.start_cursor().byte_offset()
+                                        ..items_span.end_cursor().byte_offset(),
+                                    Some("Insert an item here or import the whole module"),
+                                    None,
+                                )
+                            })
+                        },
+                    ));
+
+                    return Err(ParserResultError::Error);
+                }
+
+                Some(items)
+            } else {
+                None
+            };
+
+            let span = Arc::new(reader.substring_to_current(init_cursor));
+
+            if !context.is_at_top_level() {
+                context.add_message(generate_error_log(
+                    ParserError::MisplacedImportStatement,
+                    "Import statements are only allowed at the top level of a file".to_string(),
+                    |log| {
+                        generate_source_code(log, reader, |doc| {
+                            doc.highlight_section_str(
+                                span.start_cursor().byte_offset()..span.end_cursor().byte_offset(),
+                                Some("Move this statement to the top level of the file"),
+                                Some(Color::Magenta),
+                            )
+                        })
+                    },
+                ));
+
+                return Err(ParserResultError::Error);
+            }
+
+            let statement = ImportStatement {
+                span,
+                path,
+                items,
+                pre_path_whitespace: Arc::new(pre_path_whitespace),
+            };
+
+            for name in statement.bindings() {
+                context.declare(
+                    name.content().to_string(),
+                    Binding::new(name.span().clone(), Mutability::Immutable),
+                );
+            }
+
+            Ok(statement)
+        })
+    }
+}
+
+impl ParserNode for ImportStatement {
+    fn span(&self) -> &Arc<Span> {
+        &self.span
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+/// The path of an imported module, e.g. `a.b.c` or `..a.b`, without whitespaces.
+///
+/// Every leading dot after the first one goes up one directory.
+#[derive(Debug)]
+pub struct ImportPath {
+    span: Arc<Span>,
+    leading_dots: usize,
+    segments: Vec<Arc<Identifier>>,
+}
+
+impl ImportPath {
+    // GETTERS ----------------------------------------------------------------
+
+    /// The number of leading dots of the path, i.e. zero if the path is not relative.
+    pub fn leading_dots(&self) -> usize {
+        self.leading_dots
+    }
+
+    pub fn is_relative(&self) -> bool {
+        self.leading_dots > 0
+    }
+
+    pub fn segments(&self) -> &Vec<Arc<Identifier>> {
+        &self.segments
+    }
+
+    // STATIC METHODS ---------------------------------------------------------
+
+    /// Parses an `ImportPath`.
+    pub fn parse(reader: &mut Reader, context: &mut ParserContext) -> ParserResult<ImportPath> {
+        cursor_manager(reader, |reader, init_cursor| {
+            let mut leading_dots = 0;
+            while reader.read(IMPORT_PATH_SEPARATOR) {
+                leading_dots += 1;
+            }
+
+            let mut segments = vec![Arc::new(Identifier::parse(reader, context)?)];
+
+            loop {
+                let pre_separator_cursor = reader.save_cursor();
+
+                if !reader.read(IMPORT_PATH_SEPARATOR) {
+                    break;
+                }
+
+                match Identifier::parse(reader, context) {
+                    Ok(v) => segments.push(Arc::new(v)),
+                    Err(_) => {
+                        // The separator can precede the item list.
+                        reader.restore(pre_separator_cursor);
+                        break;
+                    }
+                }
+            }
+
+            let span = Arc::new(reader.substring_to_current(init_cursor));
+            Ok(ImportPath {
+                span,
+                leading_dots,
+                segments,
+            })
+        })
+    }
+}
+
+impl ParserNode for ImportPath {
+    fn span(&self) -> &Arc<Span> {
+        &self.span
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+/// An item of an `ImportStatement` with an optional alias, e.g. `x` or `y as z`.
+#[derive(Debug)]
+pub struct ImportItem {
+    span: Arc<Span>,
+    name: Arc<Identifier>,
+    alias: Option<Arc<Identifier>>,
+    pre_as_keyword_whitespace: Option<Arc<Whitespace>>,
+    pre_alias_whitespace: Option<Arc<Whitespace>>,
+}
+
+impl ImportItem {
+    // GETTERS ----------------------------------------------------------------
+
+    pub fn name(&self) -> &Arc<Identifier> {
+        &self.name
+    }
+
+    pub fn alias(&self) -> &Option<Arc<Identifier>> {
+        &self.alias
+    }
+
+    pub fn pre_as_keyword_whitespace(&self) -> &Option<Arc<Whitespace>> {
+        &self.pre_as_keyword_whitespace
+    }
+
+    pub fn pre_alias_whitespace(&self) -> &Option<Arc<Whitespace>> {
+        &self.pre_alias_whitespace
+    }
+
+    /// The name declared by the item, i.e. its alias or, without it, its name.
+    pub fn binding(&self) -> &Arc<Identifier> {
+        self.alias.as_ref().unwrap_or(&self.name)
+    }
+
+    // STATIC METHODS ---------------------------------------------------------
+
+    /// Parses an `ImportItem`.
+    pub fn parse(reader: &mut Reader, context: &mut ParserContext) -> ParserResult<ImportItem> {
+        cursor_manager(reader, |reader, init_cursor| {
+            let name = Arc::new(Identifier::parse(reader, context)?);
+
+            let pre_as_keyword_cursor = reader.save_cursor();
+            let pre_as_keyword_whitespace = Whitespace::parse_multiline_or_default(reader, context);
+
+            if !Identifier::parse_keyword(reader, context, AS_KEYWORD) {
+                reader.restore(pre_as_keyword_cursor);
+
+                return Ok(ImportItem {
+                    span: name.span().clone(),
+                    name,
+                    alias: None,
+                    pre_as_keyword_whitespace: None,
+                    pre_alias_whitespace: None,
+                });
+            }
+
+            let pre_alias_whitespace = Whitespace::parse_multiline_or_default(reader, context);
+
+            let alias = match Identifier::parse(reader, context) {
+                Ok(v) => Arc::new(v),
+                Err(ParserResultError::Error) => return Err(ParserResultError::Error),
+                Err(ParserResultError::NotFound) => {
+                    report_missing(
+                        reader,
+                        context,
+                        init_cursor,
+                        ParserError::MissingAliasInImportItem,
+                        "An alias is expected after the 'as' keyword",
+                        "Insert an identifier here",
+                    );
+
+                    return Err(ParserResultError::Error);
+                }
+            };
+
+            let span = Arc::new(reader.substring_to_current(init_cursor));
+            Ok(ImportItem {
+                span,
+                name,
+                alias: Some(alias),
+                pre_as_keyword_whitespace: Some(Arc::new(pre_as_keyword_whitespace)),
+                pre_alias_whitespace: Some(Arc::new(pre_alias_whitespace)),
+            })
+        })
+    }
+}
+
+impl ParserNode for ImportItem {
+    fn span(&self) -> &Arc<Span> {
+        &self.span
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use crate::parsers::file::MosfetFile;
+    use crate::test::{assert_error, assert_not_found};
+
+    use super::*;
+
+    #[test]
+    fn test_parse_module() {
+        for (text, leading_dots, segments) in &[
+            ("import a", 0, vec!["a"]),
+            ("import a.b.c", 0, vec!["a", "b", "c"]),
+            ("import .sibling", 1, vec!["sibling"]),
+            ("import\n  ..parent.module", 2, vec!["parent", "module"]),
+        ] {
+            let mut reader = Reader::from_str(text);
+            let mut context = ParserContext::default();
+            let statement =
+                ImportStatement::parse(&mut reader, &mut context).expect("The parser must succeed");
+
+            assert_eq!(statement.content(), *text, "The content is incorrect");
+            assert_eq!(
+                statement.path.leading_dots, *leading_dots,
+                "The leading dots are incorrect"
+            );
+            assert_eq!(
+                statement
+                    .path
+                    .segments
+                    .iter()
+                    .map(|v| v.content())
+                    .collect::<Vec<_>>(),
+                *segments,
+                "The segments are incorrect"
+            );
+            assert!(statement.items.is_none(), "The items must be empty");
+
+            let binding = segments.last().unwrap();
+            assert!(
+                context.find_binding(binding).is_some(),
+                "The module must be declared"
+            );
+        }
+    }
+
+    #[test]
+    fn test_parse_items() {
+        let mut reader = Reader::from_str("import a.b.{x, y as z,\n}");
+        let mut context = ParserContext::default();
+        let statement =
+            ImportStatement::parse(&mut reader, &mut context).expect("The parser must succeed");
+
+        assert_eq!(statement.path.content(), "a.b", "The path is incorrect");
+
+        let items = statement.items().expect("The items must be present");
+        assert_eq!(items.len(), 2, "The items length is incorrect");
+        assert_eq!(
+            items[0].node().content(),
+            "x",
+            "The first item is incorrect"
+        );
+        assert_eq!(
+            items[1].node().content(),
+            "y as z",
+            "The second item is incorrect"
+        );
+        assert_eq!(
+            statement
+                .bindings()
+                .iter()
+                .map(|v| v.content())
+                .collect::<Vec<_>>(),
+            vec!["x", "z"],
+            "The bindings are incorrect"
+        );
+        assert!(
+            context.find_binding("y").is_none(),
+            "The aliased item must not be declared"
+        );
+    }
+
+    #[test]
+    fn test_parse_err_not_found() {
+        for text in &["imports a", "-"] {
+            let mut reader = Reader::from_str(text);
+            let mut context = ParserContext::default();
+            let error = ImportStatement::parse(&mut reader, &mut context)
+                .expect_err("The parser must not succeed");
+
+            assert_not_found(&context, &error, 0);
+        }
+    }
+
+    #[test]
+    fn test_parse_err_missing_parts() {
+        for (text, expected) in &[
+            ("import", ParserError::MissingPathInImportStatement),
+            ("import ..", ParserError::MissingPathInImportStatement),
+            ("import a.", ParserError::MissingNameInImportPath),
+            ("import a.{}", ParserError::EmptyImportItems),
+            ("import a.{x y}", ParserError::MissingCommaInImportItems),
+            ("import a.{x", ParserError::ImportItemsWithoutEndToken),
+            ("import a.{x as}", ParserError::MissingAliasInImportItem),
+        ] {
+            let mut reader = Reader::from_str(text);
+            let mut context = ParserContext::default();
+            let error = ImportStatement::parse(&mut reader, &mut context)
+                .expect_err("The parser must not succeed");
+
+            assert_error(&context, &error, *expected);
+        }
+    }
+
+    #[test]
+    fn test_parse_err_misplaced() {
+        for text in &["let a = {\n  import b\n  b\n}", "fn f() {\n  import b\n}"] {
+            let mut reader = Reader::from_str(text);
+            let mut context = ParserContext::default();
+            let error = MosfetFile::parse(&mut reader, &mut context)
+                .expect_err("The parser must not succeed");
+
+            assert_error(&context, &error, ParserError::MisplacedImportStatement);
+        }
+    }
+}
@@ -0,0 +1,756 @@
+use std::sync::Arc;
+
+use doclog::Color;
+
+use crate::context::{Binding, Mutability, ParserContext};
+use crate::io::{Cursor, Reader, Span};
+use crate::parsers::commons::identifier::Identifier;
+use crate::parsers::commons::whitespaces::Whitespace;
+use crate::parsers::expressions::blocks::{Block, BLOCK_OPEN_TOKEN};
+use crate::parsers::expressions::Expression;
+use crate::parsers::patterns::Pattern;
+use crate::parsers::utils::{
+    cursor_manager, generate_error_log, generate_source_code, report_missing,
+};
+use crate::parsers::{ParserResult, ParserResultError};
+use crate::{ParserError, ParserNode};
+
+pub static LOOP_LABEL_TOKEN: &str = "'";
+static LOOP_LABEL_SEPARATOR: &str = ":";
+pub static WHILE_KEYWORD: &str = "while";
+pub static LOOP_KEYWORD: &str = "loop";
+pub static FOR_KEYWORD: &str = "for";
+pub static IN_KEYWORD: &str = "in";
+
+/// The optional label of a loop and the whitespace between it and the keyword.
+type LabelDeclaration = (Option<Arc<LoopLabel>>, Option<Arc<Whitespace>>);
+
+/// The name of a loop, e.g. `'outer`, used to break out of nested loops.
+///
+/// It is declared before the loop followed by a colon, e.g. `'outer: loop { }`.
+#[derive(Debug)]
+pub struct LoopLabel {
+    span: Arc<Span>,
+    name: Arc<Identifier>,
+}
+
+impl LoopLabel {
+    // GETTERS ----------------------------------------------------------------
+
+    pub fn name(&self) -> &Arc<Identifier> {
+        &self.name
+    }
+
+    // STATIC METHODS ---------------------------------------------------------
+
+    /// Parses a `LoopLabel`.
+    pub fn parse(reader: &mut Reader, context: &mut ParserContext) -> ParserResult<LoopLabel> {
+        cursor_manager(reader, |reader, init_cursor| {
+            if !reader.read(LOOP_LABEL_TOKEN) {
+                return Err(ParserResultError::NotFound);
+            }
+
+            let name = Arc::new(Identifier::parse(reader, context)?);
+
+            let span = Arc::new(reader.substring_to_current(init_cursor));
+            Ok(LoopLabel { span, name })
+        })
+    }
+
+    /// Parses the declaration of a `LoopLabel`, i.e. the label followed by a colon.
+    fn parse_declaration(
+        reader: &mut Reader,
+        context: &mut ParserContext,
+    ) -> ParserResult<LoopLabel> {
+        cursor_manager(reader, |reader, _init_cursor| {
+            let label = Self::parse(reader, context)?;
+
+            if !reader.read(LOOP_LABEL_SEPARATOR) {
+                return Err(ParserResultError::NotFound);
+            }
+
+            Ok(label)
+        })
+    }
+
+    /// Parses the optional label of a loop followed by its `keyword`.
+    fn parse_with_keyword(
+        reader: &mut Reader,
+        context: &mut ParserContext,
+        keyword: &str,
+    ) -> ParserResult<LabelDeclaration> {
+        cursor_manager(reader, |reader, _init_cursor| {
+            let (label, pre_keyword_whitespace) = match Self::parse_declaration(reader, context) {
+                Ok(v) => (
+                    Some(Arc::new(v)),
+                    Some(Arc::new(Whitespace::parse_multiline_or_default(
+                        reader, context,
+                    ))),
+                ),
+                Err(ParserResultError::NotFound) => (None, None),
+                Err(ParserResultError::Error) => return Err(ParserResultError::Error),
+            };
+
+            if !Identifier::parse_keyword(reader, context, keyword) {
+                return Err(ParserResultError::NotFound);
+            }
+
+            Ok((label, pre_keyword_whitespace))
+        })
+    }
+
+    /// Reports a label declaration that does not precede any loop,
+    /// returning `NotFound` if there is no such declaration.
+    pub fn check_without_loop(
+        reader: &mut Reader,
+        context: &mut ParserContext,
+    ) -> ParserResult<()> {
+        let label = Self::parse_declaration(reader, context)?;
+
+        context.add_message(generate_error_log(
+            ParserError::LabelWithoutLoop,
+            format!("The label {} does not belong to any loop", label.content()),
+            |log| {
+                generate_source_code(log, reader, |doc| {
+                    doc.highlight_section_str(
+                        label.span.start_cursor().byte_offset()..reader.byte_offset(),
+                        Some("Remove this label or place a loop just after it"),
+                        Some(Color::Magenta),
+                    )
+                })
+            },
+        ));
+
+        Err(ParserResultError::Error)
+    }
+
+    /// Checks that the `break` or `continue` statement in `span` is inside a loop
+    /// and that its `label`, if any, names one of the loops that contain it.
+    ///
+    /// The loops outside the current function or lambda cannot be targeted.
+    pub fn check_target(
+        reader: &Reader,
+        context: &mut ParserContext,
+        span: &Span,
+        label: &Option<Arc<LoopLabel>>,
+        outside_loop_error: ParserError,
+        keyword: &str,
+    ) -> ParserResult<()> {
+        if !context.is_in_loop() {
+            let context_start = context.function_start().unwrap_or(0);
+            let statement_start = span.start_cursor().byte_offset();
+
+            context.add_message(generate_error_log(
+                outside_loop_error,
+                format!("'{}' is only allowed inside loops", keyword),
+                |log| {
+                    generate_source_code(log, reader, |doc| {
+                        let doc = if context_start < statement_start {
+                            doc.highlight_section(
+                                context_start..statement_start,
+                                None,
+                                Some(Color::Magenta),
+                            )
+                        } else {
+                            doc
+                        };
+
+                        doc.highlight_section_str(
+                            statement_start..span.end_cursor().byte_offset(),
+                            Some("Move this statement into a loop"),
+                            Some(Color::Magenta),
+                        )
+                    })
+                },
+            ));
+
+            return Err(ParserResultError::Error);
+        }
+
+        if let Some(label) = label {
+            if !context.is_loop_label_defined(label.name.content()) {
+                context.add_message(generate_error_log(
+                    ParserError::UndefinedLoopLabel,
+                    format!(
+                        "The label {} does not name any loop that contains this statement",
+                        label.content()
+                    ),
+                    |log| {
+                        generate_source_code(log, reader, |doc| {
+                            doc.highlight_section_str(
+                                label.span.start_cursor().byte_offset()
+                                    ..label.span.end_cursor().byte_offset(),
+                                Some("Use the label of an enclosing loop"),
+                                Some(Color::Magenta),
+                            )
+                        })
+                    },
+                ));
+
+                return Err(ParserResultError::Error);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Parses the body of a loop labelled with `label`, reporting the error
+    /// from `init_cursor`, i.e. the start of the loop, if it is missing.
+    fn parse_body(
+        reader: &mut Reader,
+        context: &mut ParserContext,
+        init_cursor: &Cursor,
+        label: &Option<Arc<LoopLabel>>,
+    ) -> ParserResult<Block> {
+        context.enter_loop(label.as_ref().map(|v| v.name.content().to_string()));
+        let body = Block::parse(reader, context);
+        context.exit_loop();
+
+        match body {
+            Ok(v) => Ok(v),
+            Err(ParserResultError::NotFound) => {
+                context.add_message(generate_error_log(
+                    ParserError::MissingBlockInLoop,
+                    format!(
+                        "A block starting with '{}' is expected as the body of the loop",
+                        BLOCK_OPEN_TOKEN
+                    ),
+                    |log| {
+                        generate_source_code(log, reader, |doc| {
+                            doc.highlight_section(
+                                init_cursor.byte_offset()..reader.byte_offset(),
+                                None,
+                                Some(Color::Magenta),
+                            )
+                            .highlight_cursor_str(
+                                reader.byte_offset(),
+                                Some("Insert a block here"),
+                                None,
+                            )
+                        })
+                    },
+                ));
+
+                Err(ParserResultError::Error)
+            }
+            Err(ParserResultError::Error) => Err(ParserResultError::Error),
+        }
+    }
+}
+
+impl ParserNode for LoopLabel {
+    fn span(&self) -> &Arc<Span> {
+        &self.span
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+/// A loop that executes its body while a condition is true, e.g. `while a < 10 { }`.
+#[derive(Debug)]
+pub struct WhileStatement {
+    span: Arc<Span>,
+    label: Option<Arc<LoopLabel>>,
+    condition: Arc<Expression>,
+    body: Arc<Block>,
+    pre_keyword_whitespace: Option<Arc<Whitespace>>,
+    pre_condition_whitespace: Arc<Whitespace>,
+    pre_body_whitespace: Arc<Whitespace>,
+}
+
+impl WhileStatement {
+    // GETTERS ----------------------------------------------------------------
+
+    pub fn label(&self) -> &Option<Arc<LoopLabel>> {
+        &self.label
+    }
+
+    pub fn condition(&self) -> &Arc<Expression> {
+        &self.condition
+    }
+
+    pub fn body(&self) -> &Arc<Block> {
+        &self.body
+    }
+
+    pub fn pre_keyword_whitespace(&self) -> &Option<Arc<Whitespace>> {
+        &self.pre_keyword_whitespace
+    }
+
+    pub fn pre_condition_whitespace(&self) -> &Arc<Whitespace> {
+        &self.pre_condition_whitespace
+    }
+
+    pub fn pre_body_whitespace(&self) -> &Arc<Whitespace> {
+        &self.pre_body_whitespace
+    }
+
+    // STATIC METHODS ---------------------------------------------------------
+
+    /// Parses a `WhileStatement`.
+    pub fn parse(reader: &mut Reader, context: &mut ParserContext) -> ParserResult<WhileStatement> {
+        cursor_manager(reader, |reader, init_cursor| {
+            let (label, pre_keyword_whitespace) =
+                LoopLabel::parse_with_keyword(reader, context, WHILE_KEYWORD)?;
+
+            let pre_condition_whitespace = Whitespace::parse_multiline_or_default(reader, context);
+
+            let condition = match Expression::parse_before_block(reader, context) {
+                Ok(v) => Arc::new(v),
+                Err(ParserResultError::Error) => return Err(ParserResultError::Error),
+                Err(ParserResultError::NotFound) => {
+                    report_missing(
+                        reader,
+                        context,
+                        init_cursor,
+                        ParserError::MissingConditionInWhileStatement,
+                        "A condition is expected after the 'while' keyword",
+                        "Insert an expression here",
+                    );
+
+                    return Err(ParserResultError::Error);
+                }
+            };
+
+            let pre_body_whitespace = Whitespace::parse_multiline_or_default(reader, context);
+            let body = Arc::new(LoopLabel::parse_body(reader, context, init_cursor, &label)?);
+
+            let span = Arc::new(reader.substring_to_current(init_cursor));
+            Ok(WhileStatement {
+                span,
+                label,
+                condition,
+                body,
+                pre_keyword_whitespace,
+                pre_condition_whitespace: Arc::new(pre_condition_whitespace),
+                pre_body_whitespace: Arc::new(pre_body_whitespace),
+            })
+        })
+    }
+}
+
+impl ParserNode for WhileStatement {
+    fn span(&self) -> &Arc<Span> {
+        &self.span
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+/// A loop that executes its body until a `break` statement is reached, e.g. `loop { }`.
+#[derive(Debug)]
+pub struct LoopStatement {
+    span: Arc<Span>,
+    label: Option<Arc<LoopLabel>>,
+    body: Arc<Block>,
+    pre_keyword_whitespace: Option<Arc<Whitespace>>,
+    pre_body_whitespace: Arc<Whitespace>,
+}
+
+impl LoopStatement {
+    // GETTERS ----------------------------------------------------------------
+
+    pub fn label(&self) -> &Option<Arc<LoopLabel>> {
+        &self.label
+    }
+
+    pub fn body(&self) -> &Arc<Block> {
+        &self.body
+    }
+
+    pub fn pre_keyword_whitespace(&self) -> &Option<Arc<Whitespace>> {
+        &self.pre_keyword_whitespace
+    }
+
+    pub fn pre_body_whitespace(&self) -> &Arc<Whitespace> {
+        &self.pre_body_whitespace
+    }
+
+    // STATIC METHODS ---------------------------------------------------------
+
+    /// Parses a `LoopStatement`.
+    pub fn parse(reader: &mut Reader, context: &mut ParserContext) -> ParserResult<LoopStatement> {
+        cursor_manager(reader, |reader, init_cursor| {
+            let (label, pre_keyword_whitespace) =
+                LoopLabel::parse_with_keyword(reader, context, LOOP_KEYWORD)?;
+
+            let pre_body_whitespace = Whitespace::parse_multiline_or_default(reader, context);
+            let body = Arc::new(LoopLabel::parse_body(reader, context, init_cursor, &label)?);
+
+            let span = Arc::new(reader.substring_to_current(init_cursor));
+            Ok(LoopStatement {
+                span,
+                label,
+                body,
+                pre_keyword_whitespace,
+                pre_body_whitespace: Arc::new(pre_body_whitespace),
+            })
+        })
+    }
+}
+
+impl ParserNode for LoopStatement {
+    fn span(&self) -> &Arc<Span> {
+        &self.span
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+/// A loop that executes its body for each element of an iterable,
+/// e.g. `for (key, value) in map { }`.
+///
+/// The bindings of the pattern are only visible inside the body.
+#[derive(Debug)]
+pub struct ForStatement {
+    span: Arc<Span>,
+    label: Option<Arc<LoopLabel>>,
+    pattern: Arc<Pattern>,
+    iterable: Arc<Expression>,
+    body: Arc<Block>,
+    pre_keyword_whitespace: Option<Arc<Whitespace>>,
+    pre_pattern_whitespace: Arc<Whitespace>,
+    pre_in_keyword_whitespace: Arc<Whitespace>,
+    pre_iterable_whitespace: Arc<Whitespace>,
+    pre_body_whitespace: Arc<Whitespace>,
+}
+
+impl ForStatement {
+    // GETTERS ----------------------------------------------------------------
+
+    pub fn label(&self) -> &Option<Arc<LoopLabel>> {
+        &self.label
+    }
+
+    pub fn pattern(&self) -> &Arc<Pattern> {
+        &self.pattern
+    }
+
+    pub fn iterable(&self) -> &Arc<Expression> {
+        &self.iterable
+    }
+
+    pub fn body(&self) -> &Arc<Block> {
+        &self.body
+    }
+
+    pub fn pre_keyword_whitespace(&self) -> &Option<Arc<Whitespace>> {
+        &self.pre_keyword_whitespace
+    }
+
+    pub fn pre_pattern_whitespace(&self) -> &Arc<Whitespace> {
+        &self.pre_pattern_whitespace
+    }
+
+    pub fn pre_in_keyword_whitespace(&self) -> &Arc<Whitespace> {
+        &self.pre_in_keyword_whitespace
+    }
+
+    pub fn pre_iterable_whitespace(&self) -> &Arc<Whitespace> {
+        &self.pre_iterable_whitespace
+    }
+
+    pub fn pre_body_whitespace(&self) -> &Arc<Whitespace> {
+        &self.pre_body_whitespace
+    }
+
+    // STATIC METHODS ---------------------------------------------------------
+
+    /// Parses a `ForStatement`.
+    pub fn parse(reader: &mut Reader, context: &mut ParserContext) -> ParserResult<ForStatement> {
+        cursor_manager(reader, |reader, init_cursor| {
+            let (label, pre_keyword_whitespace) =
+                LoopLabel::parse_with_keyword(reader, context, FOR_KEYWORD)?;
+
+            let pre_pattern_whitespace = Whitespace::parse_multiline_or_default(reader, context);
+
+            let pattern = match Pattern::parse(reader, context) {
+                Ok(v) => Arc::new(v),
+                Err(ParserResultError::Error) => return Err(ParserResultError::Error),
+                Err(ParserResultError::NotFound) => {
+                    report_missing(
+                        reader,
+                        context,
+                        init_cursor,
+                        ParserError::MissingPatternInForStatement,
+                        "A pattern is expected after the 'for' keyword",
+                        "Insert a pattern here",
+                    );
+
+                    return Err(ParserResultError::Error);
+                }
+            };
+
+            let pre_in_keyword_whitespace = Whitespace::parse_multiline_or_default(reader, context);
+
+            if !Identifier::parse_keyword(reader, context, IN_KEYWORD) {
+                report_missing(
+                    reader,
+                    context,
+                    init_cursor,
+                    ParserError::MissingInKeywordInForStatement,
+                    "The 'in' keyword is expected after the pattern",
+                    "Insert the 'in' keyword here",
+                );
+
+                return Err(ParserResultError::Error);
+            }
+
+            let pre_iterable_whitespace = Whitespace::parse_multiline_or_default(reader, context);
+
+            let iterable = match Expression::parse_before_block(reader, context) {
+                Ok(v) => Arc::new(v),
+                Err(ParserResultError::Error) => return Err(ParserResultError::Error),
+                Err(ParserResultError::NotFound) => {
+                    report_missing(
+                        reader,
+                        context,
+                        init_cursor,
+                        ParserError::MissingIterableInForStatement,
+                        "An expression to iterate over is expected after the 'in' keyword",
+                        "Insert an expression here",
+                    );
+
+                    return Err(ParserResultError::Error);
+                }
+            };
+
+            let pre_body_whitespace = Whitespace::parse_multiline_or_default(reader, context);
+
+            // The bindings of the pattern are only visible inside the body.
+            context.push_scope();
+
+            for name in pattern.bindings() {
+                context.declare(
+                    name.content().to_string(),
+                    Binding::new(name.span().clone(), Mutability::Immutable),
+                );
+            }
+
+            let body = LoopLabel::parse_body(reader, context, init_cursor, &label);
+            context.pop_scope();
+            let body = Arc::new(body?);
+
+            let span = Arc::new(reader.substring_to_current(init_cursor));
+            Ok(ForStatement {
+                span,
+                label,
+                pattern,
+                iterable,
+                body,
+                pre_keyword_whitespace,
+                pre_pattern_whitespace: Arc::new(pre_pattern_whitespace),
+                pre_in_keyword_whitespace: Arc::new(pre_in_keyword_whitespace),
+                pre_iterable_whitespace: Arc::new(pre_iterable_whitespace),
+                pre_body_whitespace: Arc::new(pre_body_whitespace),
+            })
+        })
+    }
+}
+
+impl ParserNode for ForStatement {
+    fn span(&self) -> &Arc<Span> {
+        &self.span
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use crate::parsers::file::MosfetFile;
+    use crate::parsers::statements::Statement;
+    use crate::test::{assert_error, assert_not_found};
+
+    use super::*;
+
+    #[test]
+    fn test_parse_label() {
+        let mut reader = Reader::from_str("'outer: loop");
+        let mut context = ParserContext::default();
+        let label = LoopLabel::parse(&mut reader, &mut context).expect("The parser must succeed");
+
+        assert_eq!(label.content(), "'outer", "The content is incorrect");
+        assert_eq!(label.name.content(), "outer", "The name is incorrect");
+    }
+
+    #[test]
+    fn test_parse_while() {
+        let mut reader = Reader::from_str("'a:\nwhile a < 10 {\n  a += 1\n}");
+        let mut context = ParserContext::default();
+        let statement =
+            WhileStatement::parse(&mut reader, &mut context).expect("The parser must succeed");
+
+        assert_eq!(
+            statement.content(),
+            "'a:\nwhile a < 10 {\n  a += 1\n}",
+            "The content is incorrect"
+        );
+        assert_eq!(
+            statement.label.as_ref().map(|v| v.content()),
+            Some("'a"),
+            "The label is incorrect"
+        );
+        assert_eq!(
+            statement
+                .pre_keyword_whitespace
+                .as_ref()
+                .map(|v| v.content()),
+            Some("\n"),
+            "The pre_keyword_whitespace is incorrect"
+        );
+        assert_eq!(
+            statement.condition.content(),
+            "a < 10",
+            "The condition is incorrect"
+        );
+        assert_eq!(
+            statement.body.content(),
+            "{\n  a += 1\n}",
+            "The body is incorrect"
+        );
+    }
+
+    #[test]
+    fn test_parse_loop() {
+        let mut reader = Reader::from_str("loop {}");
+        let mut context = ParserContext::default();
+        let statement =
+            LoopStatement::parse(&mut reader, &mut context).expect("The parser must succeed");
+
+        assert_eq!(statement.content(), "loop {}", "The content is incorrect");
+        assert!(statement.label.is_none(), "The label must be empty");
+        assert_eq!(
+            statement.pre_body_whitespace.content(),
+            " ",
+            "The pre_body_whitespace is incorrect"
+        );
+    }
+
+    #[test]
+    fn test_parse_for() {
+        let mut reader = Reader::from_str("for (k, v) in map {\n  k\n}");
+        let mut context = ParserContext::default();
+        let statement =
+            ForStatement::parse(&mut reader, &mut context).expect("The parser must succeed");
+
+        assert_eq!(
+            statement.content(),
+            "for (k, v) in map {\n  k\n}",
+            "The content is incorrect"
+        );
+        assert_eq!(
+            statement.pattern.content(),
+            "(k, v)",
+            "The pattern is incorrect"
+        );
+        assert_eq!(
+            statement.iterable.content(),
+            "map",
+            "The iterable is incorrect"
+        );
+        assert!(
+            context.find_binding("k").is_none(),
+            "The pattern bindings must not be visible after the loop"
+        );
+    }
+
+    #[test]
+    fn test_parse_open_range_head() {
+        for (text, head) in &[
+            ("for i in 0.. { print(i) }", "0.."),
+            ("while a == b.. {}", "a == b.."),
+        ] {
+            let mut reader = Reader::from_str(text);
+            let mut context = ParserContext::default();
+            let statement =
+                Statement::parse(&mut reader, &mut context).expect("The parser must succeed");
+
+            let (head_content, body_content) = match &statement {
+                Statement::ForStatement(v) => (v.iterable.content(), v.body.content()),
+                Statement::WhileStatement(v) => (v.condition.content(), v.body.content()),
+                _ => panic!("The statement type is incorrect"),
+            };
+
+            assert_eq!(statement.content(), *text, "The content is incorrect");
+            assert_eq!(head_content, *head, "The head is incorrect");
+            assert!(body_content.starts_with('{'), "The body is incorrect");
+        }
+    }
+
+    #[test]
+    fn test_parse_err_not_found() {
+        for text in &["'a: b", "'a loop {}", "loops {}", "-"] {
+            let mut reader = Reader::from_str(text);
+            let mut context = ParserContext::default();
+            let error = LoopStatement::parse(&mut reader, &mut context)
+                .expect_err("The parser must not succeed");
+
+            assert_not_found(&context, &error, 0);
+        }
+    }
+
+    #[test]
+    fn test_parse_err_missing_condition() {
+        for text in &["while", "while {}"] {
+            let mut reader = Reader::from_str(text);
+            let mut context = ParserContext::default();
+            let error = WhileStatement::parse(&mut reader, &mut context)
+                .expect_err("The parser must not succeed");
+
+            assert_error(
+                &context,
+                &error,
+                ParserError::MissingConditionInWhileStatement,
+            );
+        }
+    }
+
+    #[test]
+    fn test_parse_err_missing_for_parts() {
+        for (text, expected) in &[
+            ("for", ParserError::MissingPatternInForStatement),
+            ("for a", ParserError::MissingInKeywordInForStatement),
+            ("for a in {}", ParserError::MissingIterableInForStatement),
+        ] {
+            let mut reader = Reader::from_str(text);
+            let mut context = ParserContext::default();
+            let error = ForStatement::parse(&mut reader, &mut context)
+                .expect_err("The parser must not succeed");
+
+            assert_error(&context, &error, *expected);
+        }
+    }
+
+    #[test]
+    fn test_parse_err_missing_block() {
+        for text in &["loop", "while a", "for a in b c"] {
+            let mut reader = Reader::from_str(text);
+            let mut context = ParserContext::default();
+            let error = MosfetFile::parse(&mut reader, &mut context)
+                .expect_err("The parser must not succeed");
+
+            assert_error(&context, &error, ParserError::MissingBlockInLoop);
+        }
+    }
+
+    #[test]
+    fn test_parse_err_label_without_loop() {
+        for text in &["'a: b", "'a:\nlet b = 1", "loop {\n  'a:\n}"] {
+            let mut reader = Reader::from_str(text);
+            let mut context = ParserContext::default();
+            let error = MosfetFile::parse(&mut reader, &mut context)
+                .expect_err("The parser must not succeed");
+
+            assert_error(&context, &error, ParserError::LabelWithoutLoop);
+        }
+    }
+}
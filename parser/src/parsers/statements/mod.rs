@@ -1,21 +1,48 @@
 use std::sync::Arc;
 
+pub use assignment::*;
+pub use break_statement::*;
+pub use continue_statement::*;
+pub use expression_statement::*;
+pub use function_declaration::*;
+pub use import_statement::*;
+pub use loops::*;
 pub use return_statement::*;
 pub use variable_declaration::*;
 
 use crate::context::ParserContext;
 use crate::io::{Reader, Span};
+use crate::parsers::commons::whitespaces::Whitespace;
+use crate::parsers::expressions::conditionals::IfExpression;
+use crate::parsers::expressions::Expression;
+use crate::parsers::utils::{generate_error_log, generate_source_code};
 use crate::parsers::{ParserResult, ParserResultError};
-use crate::ParserNode;
+use crate::{ParserError, ParserNode};
 
+mod assignment;
+mod break_statement;
+mod continue_statement;
+mod expression_statement;
+mod function_declaration;
+mod import_statement;
+mod loops;
 mod return_statement;
 mod variable_declaration;
 
 /// A statement in the Mosfet language, like a variable declaration.
 #[derive(Debug)]
 pub enum Statement {
+    ExpressionStatement(Arc<ExpressionStatement>),
+    ImportStatement(Arc<ImportStatement>),
     VariableDeclaration(Arc<VariableDeclaration>),
+    FunctionDeclaration(Arc<FunctionDeclaration>),
+    Assignment(Arc<Assignment>),
     ReturnStatement(Arc<ReturnStatement>),
+    WhileStatement(Arc<WhileStatement>),
+    LoopStatement(Arc<LoopStatement>),
+    ForStatement(Arc<ForStatement>),
+    BreakStatement(Arc<BreakStatement>),
+    ContinueStatement(Arc<ContinueStatement>),
 }
 
 impl Statement {
@@ -24,36 +51,241 @@ impl Statement {
     /// The span of the node.
     pub fn span(&self) -> &Span {
         match self {
+            Statement::ExpressionStatement(n) => n.span(),
+            Statement::ImportStatement(n) => n.span(),
             Statement::VariableDeclaration(n) => n.span(),
+            Statement::FunctionDeclaration(n) => n.span(),
+            Statement::Assignment(n) => n.span(),
             Statement::ReturnStatement(n) => n.span(),
+            Statement::WhileStatement(n) => n.span(),
+            Statement::LoopStatement(n) => n.span(),
+            Statement::ForStatement(n) => n.span(),
+            Statement::BreakStatement(n) => n.span(),
+            Statement::ContinueStatement(n) => n.span(),
         }
     }
 
     // STATIC METHODS ---------------------------------------------------------
 
-    /// Parses a statement.
+    /// Parses a statement, including an expression as an `ExpressionStatement`.
     pub fn parse(reader: &mut Reader, context: &mut ParserContext) -> ParserResult<Statement> {
+        match Self::parse_or_expression(reader, context)? {
+            StatementOrExpression::Statement(statement) => Ok(statement),
+            StatementOrExpression::Expression(expression) => Ok(Statement::ExpressionStatement(
+                Arc::new(ExpressionStatement::new(reader, context, expression)),
+            )),
+        }
+    }
+
+    /// Parses a statement or, if there is none, an expression that is not followed
+    /// by an assignment operator, so that expressions are only parsed once.
+    fn parse_or_expression(
+        reader: &mut Reader,
+        context: &mut ParserContext,
+    ) -> ParserResult<StatementOrExpression> {
+        match ImportStatement::parse(reader, context) {
+            Ok(node) => {
+                return Ok(StatementOrExpression::Statement(
+                    Statement::ImportStatement(Arc::new(node)),
+                ))
+            }
+            Err(ParserResultError::NotFound) => { /* Ignore because not found */ }
+            Err(ParserResultError::Error) => return Err(ParserResultError::Error),
+        }
+
         match VariableDeclaration::parse(reader, context) {
-            Ok(node) => return Ok(Statement::VariableDeclaration(Arc::new(node))),
+            Ok(node) => {
+                return Ok(StatementOrExpression::Statement(
+                    Statement::VariableDeclaration(Arc::new(node)),
+                ))
+            }
+            Err(ParserResultError::NotFound) => { /* Ignore because not found */ }
+            Err(ParserResultError::Error) => return Err(ParserResultError::Error),
+        }
+
+        match FunctionDeclaration::parse(reader, context) {
+            Ok(node) => {
+                return Ok(StatementOrExpression::Statement(
+                    Statement::FunctionDeclaration(Arc::new(node)),
+                ))
+            }
             Err(ParserResultError::NotFound) => { /* Ignore because not found */ }
             Err(ParserResultError::Error) => return Err(ParserResultError::Error),
         }
 
         match ReturnStatement::parse(reader, context) {
-            Ok(node) => return Ok(Statement::ReturnStatement(Arc::new(node))),
+            Ok(node) => {
+                return Ok(StatementOrExpression::Statement(
+                    Statement::ReturnStatement(Arc::new(node)),
+                ))
+            }
+            Err(ParserResultError::NotFound) => { /* Ignore because not found */ }
+            Err(ParserResultError::Error) => return Err(ParserResultError::Error),
+        }
+
+        match WhileStatement::parse(reader, context) {
+            Ok(node) => {
+                return Ok(StatementOrExpression::Statement(Statement::WhileStatement(
+                    Arc::new(node),
+                )))
+            }
+            Err(ParserResultError::NotFound) => { /* Ignore because not found */ }
+            Err(ParserResultError::Error) => return Err(ParserResultError::Error),
+        }
+
+        match LoopStatement::parse(reader, context) {
+            Ok(node) => {
+                return Ok(StatementOrExpression::Statement(Statement::LoopStatement(
+                    Arc::new(node),
+                )))
+            }
+            Err(ParserResultError::NotFound) => { /* Ignore because not found */ }
+            Err(ParserResultError::Error) => return Err(ParserResultError::Error),
+        }
+
+        match ForStatement::parse(reader, context) {
+            Ok(node) => {
+                return Ok(StatementOrExpression::Statement(Statement::ForStatement(
+                    Arc::new(node),
+                )))
+            }
+            Err(ParserResultError::NotFound) => { /* Ignore because not found */ }
+            Err(ParserResultError::Error) => return Err(ParserResultError::Error),
+        }
+
+        match BreakStatement::parse(reader, context) {
+            Ok(node) => {
+                return Ok(StatementOrExpression::Statement(Statement::BreakStatement(
+                    Arc::new(node),
+                )))
+            }
+            Err(ParserResultError::NotFound) => { /* Ignore because not found */ }
+            Err(ParserResultError::Error) => return Err(ParserResultError::Error),
+        }
+
+        match ContinueStatement::parse(reader, context) {
+            Ok(node) => {
+                return Ok(StatementOrExpression::Statement(
+                    Statement::ContinueStatement(Arc::new(node)),
+                ))
+            }
             Err(ParserResultError::NotFound) => { /* Ignore because not found */ }
             Err(ParserResultError::Error) => return Err(ParserResultError::Error),
         }
 
-        Err(ParserResultError::NotFound)
+        let expression = Arc::new(Expression::parse(reader, context)?);
+
+        match Assignment::parse(reader, context, &expression) {
+            Ok(node) => Ok(StatementOrExpression::Statement(Statement::Assignment(
+                Arc::new(node),
+            ))),
+            Err(ParserResultError::NotFound) => Ok(StatementOrExpression::Expression(expression)),
+            Err(ParserResultError::Error) => Err(ParserResultError::Error),
+        }
+    }
+
+    /// Parses a list of statements separated by new lines, including the whitespaces
+    /// before the first and after the last one. This is the body of files and blocks.
+    ///
+    /// An expression that ends the list is returned along with the statements instead
+    /// of as an `ExpressionStatement`, because it can be the value of a block.
+    pub fn parse_list(
+        reader: &mut Reader,
+        context: &mut ParserContext,
+    ) -> ParserResult<(Vec<Statement>, Option<Arc<Expression>>)> {
+        let mut statements: Vec<Statement> = Vec::new();
+        let mut last_expression: Option<Arc<Expression>> = None;
+
+        loop {
+            let pre_whitespace_cursor = reader.save_cursor();
+            let whitespace = Whitespace::parse_multiline(reader, context);
+
+            let node = match Self::parse_or_expression(reader, context) {
+                Ok(v) => v,
+                Err(ParserResultError::NotFound) => {
+                    // An 'else' here cannot belong to any previous 'if' expression.
+                    if let Err(ParserResultError::Error) =
+                        IfExpression::check_dangling_else(reader, context)
+                    {
+                        return Err(ParserResultError::Error);
+                    }
+
+                    // A label here does not precede any loop.
+                    if let Err(ParserResultError::Error) =
+                        LoopLabel::check_without_loop(reader, context)
+                    {
+                        return Err(ParserResultError::Error);
+                    }
+
+                    // Keep the whitespaces after the last expression for the caller.
+                    if last_expression.is_some() {
+                        reader.restore(pre_whitespace_cursor);
+                    }
+
+                    return Ok((statements, last_expression));
+                }
+                Err(ParserResultError::Error) => return Err(ParserResultError::Error),
+            };
+
+            // Check whitespace is multiline to prevent two statements in the same line.
+            let last_span: Option<&Span> = match &last_expression {
+                Some(expression) => Some(expression.span()),
+                None => statements.last().map(|v| v.span()),
+            };
+
+            if let Some(last_span) = last_span {
+                if !whitespace
+                    .as_ref()
+                    .map(|ws| ws.is_multiline())
+                    .unwrap_or(false)
+                {
+                    context.add_message(generate_error_log(
+                        ParserError::TwoStatementsInSameLineInFile,
+                        "Two statements in the same line are forbidden".to_string(),
+                        |log| {
+                            generate_source_code(log, reader, |doc| {
+                                doc.highlight_cursor_str(
+                                    last_span.end_cursor().byte_offset(),
+                                    Some("Insert a new line (\\n) here"),
+                                    None,
+                                )
+                            })
+                        },
+                    ));
+
+                    return Err(ParserResultError::Error);
+                }
+            }
+
+            // The previous expression does not end the list so it is a statement.
+            if let Some(expression) = last_expression.take() {
+                statements.push(Statement::ExpressionStatement(Arc::new(
+                    ExpressionStatement::new(reader, context, expression),
+                )));
+            }
+
+            match node {
+                StatementOrExpression::Statement(statement) => statements.push(statement),
+                StatementOrExpression::Expression(expression) => last_expression = Some(expression),
+            }
+        }
     }
 }
 
 impl ParserNode for Statement {
     fn span(&self) -> &Arc<Span> {
         match self {
+            Statement::ExpressionStatement(n) => n.span(),
+            Statement::ImportStatement(n) => n.span(),
             Statement::VariableDeclaration(n) => n.span(),
+            Statement::FunctionDeclaration(n) => n.span(),
+            Statement::Assignment(n) => n.span(),
             Statement::ReturnStatement(n) => n.span(),
+            Statement::WhileStatement(n) => n.span(),
+            Statement::LoopStatement(n) => n.span(),
+            Statement::ForStatement(n) => n.span(),
+            Statement::BreakStatement(n) => n.span(),
+            Statement::ContinueStatement(n) => n.span(),
         }
     }
 }
@@ -62,6 +294,16 @@ impl ParserNode for Statement {
 // ----------------------------------------------------------------------------
 // ----------------------------------------------------------------------------
 
+/// The result of parsing a statement that can also be an expression.
+enum StatementOrExpression {
+    Statement(Statement),
+    Expression(Arc<Expression>),
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
 #[cfg(test)]
 mod tests {
     use crate::parsers::expressions::Expression;
@@ -78,9 +320,9 @@ mod tests {
 
         if let Statement::VariableDeclaration(declaration) = statement {
             assert_eq!(
-                declaration.name().content(),
+                declaration.pattern().content(),
                 "test",
-                "The name is incorrect"
+                "The pattern is incorrect"
             );
 
             if let Expression::VariableAccess(identifier) = declaration.expression().as_ref() {
@@ -97,6 +339,7 @@ mod tests {
     fn test_parse_variable_access() {
         let mut reader = Reader::from_str("return test");
         let mut context = ParserContext::default();
+        context.enter_function(0);
         let statement =
             Statement::parse(&mut reader, &mut context).expect("The parser must succeed");
 
@@ -115,9 +358,61 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_parse_list() {
+        let mut reader = Reader::from_str("\n  let mut a = 1\n\n  a += 1\n  let b = a\n  }");
+        let mut context = ParserContext::default();
+        let (statements, expression) =
+            Statement::parse_list(&mut reader, &mut context).expect("The parser must succeed");
+
+        assert_eq!(statements.len(), 3, "The statements length is incorrect");
+        assert!(expression.is_none(), "The expression must be empty");
+        assert_eq!(
+            reader.remaining_content(),
+            "}",
+            "The remaining content is incorrect"
+        );
+    }
+
+    #[test]
+    fn test_parse_list_empty() {
+        let mut reader = Reader::from_str("  }");
+        let mut context = ParserContext::default();
+        let (statements, expression) =
+            Statement::parse_list(&mut reader, &mut context).expect("The parser must succeed");
+
+        assert!(statements.is_empty(), "The statements length is incorrect");
+        assert!(expression.is_none(), "The expression must be empty");
+        assert_eq!(
+            reader.remaining_content(),
+            "}",
+            "The remaining content is incorrect"
+        );
+    }
+
+    #[test]
+    fn test_parse_list_with_expression() {
+        let mut reader = Reader::from_str("\n  a = 1\n  a + 1\n}");
+        let mut context = ParserContext::default();
+        let (statements, expression) =
+            Statement::parse_list(&mut reader, &mut context).expect("The parser must succeed");
+
+        assert_eq!(statements.len(), 1, "The statements length is incorrect");
+        assert_eq!(
+            expression.as_ref().map(|v| v.content()),
+            Some("a + 1"),
+            "The expression is incorrect"
+        );
+        assert_eq!(
+            reader.remaining_content(),
+            "\n}",
+            "The remaining content is incorrect"
+        );
+    }
+
     #[test]
     fn test_parse_err_not_found() {
-        let mut reader = Reader::from_str("-");
+        let mut reader = Reader::from_str("+");
         let mut context = ParserContext::default();
         let error =
             Statement::parse(&mut reader, &mut context).expect_err("The parser must not succeed");
@@ -1,5 +1,7 @@
 use std::sync::Arc;
 
+use doclog::Color;
+
 use crate::context::ParserContext;
 use crate::io::{Reader, Span};
 use crate::parsers::commons::identifier::Identifier;
@@ -12,7 +14,8 @@ use crate::{ParserError, ParserNode};
 
 static KEYWORD: &str = "return";
 
-/// A return statement with a compulsory expression.
+/// A return statement with a compulsory expression. It is only allowed inside
+/// the body of functions and lambdas.
 #[derive(Debug)]
 pub struct ReturnStatement {
     span: Arc<Span>,
@@ -47,7 +50,8 @@ impl ReturnStatement {
 
             let expression = match Expression::parse(reader, context) {
                 Ok(v) => v,
-                Err(_) => {
+                Err(ParserResultError::Error) => return Err(ParserResultError::Error),
+                Err(ParserResultError::NotFound) => {
                     context.add_message(generate_error_log(
                         ParserError::MissingExpressionInReturnStatement,
                         "An expression was expected to specify the value to return".to_string(),
@@ -67,6 +71,25 @@ impl ReturnStatement {
             };
 
             let span = Arc::new(reader.substring_to_current(&init_cursor));
+
+            if !context.is_in_function() {
+                context.add_message(generate_error_log(
+                    ParserError::ReturnOutsideFunction,
+                    "Return statements are only allowed inside functions".to_string(),
+                    |log| {
+                        generate_source_code(log, reader, |doc| {
+                            doc.highlight_section_str(
+                                span.start_cursor().byte_offset()..span.end_cursor().byte_offset(),
+                                Some("Move this statement into a function"),
+                                Some(Color::Magenta),
+                            )
+                        })
+                    },
+                ));
+
+                return Err(ParserResultError::Error);
+            }
+
             Ok(ReturnStatement {
                 span,
                 expression: Arc::new(expression),
@@ -88,6 +111,7 @@ impl ParserNode for ReturnStatement {
 
 #[cfg(test)]
 mod tests {
+    use crate::parsers::file::MosfetFile;
     use crate::test::{assert_error, assert_not_found};
     use crate::ParserError;
 
@@ -98,6 +122,7 @@ mod tests {
         // With whitespaces.
         let mut reader = Reader::from_str("return    test");
         let mut context = ParserContext::default();
+        context.enter_function(0);
         let statement =
             ReturnStatement::parse(&mut reader, &mut context).expect("The parser must succeed");
 
@@ -126,6 +151,7 @@ mod tests {
     fn test_parse_err_missing_expression() {
         let mut reader = Reader::from_str("return");
         let mut context = ParserContext::default();
+        context.enter_function(0);
         let error = ReturnStatement::parse(&mut reader, &mut context)
             .expect_err("The parser must not succeed");
 
@@ -135,4 +161,26 @@ mod tests {
             ParserError::MissingExpressionInReturnStatement,
         );
     }
+
+    #[test]
+    fn test_parse_err_outside_function() {
+        for text in &["return a", "let a = {\n  return 1\n}"] {
+            let mut reader = Reader::from_str(text);
+            let mut context = ParserContext::default();
+            let error = MosfetFile::parse(&mut reader, &mut context)
+                .expect_err("The parser must not succeed");
+
+            assert_error(&context, &error, ParserError::ReturnOutsideFunction);
+        }
+
+        for text in &[
+            "fn f() {\n  return 1\n}",
+            "let f = () -> {\n  return 1\n}",
+            "fn f() {\n  let g = () -> {\n    return 1\n  }\n  return g\n}",
+        ] {
+            let mut reader = Reader::from_str(text);
+            let mut context = ParserContext::default();
+            MosfetFile::parse(&mut reader, &mut context).expect("The parser must succeed");
+        }
+    }
 }
@@ -2,26 +2,43 @@ use std::sync::Arc;
 
 use doclog::Color;
 
-use crate::context::ParserContext;
-use crate::io::{Reader, Span};
+use crate::context::{Binding, Mutability, ParserContext};
+use crate::io::{Cursor, Reader, Span};
 use crate::parsers::commons::identifier::Identifier;
 use crate::parsers::commons::whitespaces::Whitespace;
+use crate::parsers::expressions::lambdas::TYPE_SEPARATOR;
 use crate::parsers::expressions::Expression;
+use crate::parsers::patterns::Pattern;
 use crate::parsers::result::ParserResult;
+use crate::parsers::types::TypeExpression;
 use crate::parsers::utils::{cursor_manager, generate_error_log, generate_source_code};
 use crate::parsers::ParserResultError;
 use crate::{ParserError, ParserNode};
 
-static KEYWORD: &str = "let";
+static LET_KEYWORD: &str = "let";
+static CONST_KEYWORD: &str = "const";
+static MUT_KEYWORD: &str = "mut";
 static ASSIGN_OPERATOR: &str = "=";
 
-/// A variable declaration with a compulsory expression.
+/// A variable declaration with a compulsory expression, e.g. `let a = 1`.
+/// The name can be replaced by a pattern to destructure the value, e.g. `let (a, b) = pair`.
+///
+/// The variable can be made mutable with `let mut a = 1` or declared as a constant
+/// with `const A = 1`, whose expression is evaluated at compile time.
+/// The type of the variable can be annotated after its name, e.g. `let a: Int = 1`.
 #[derive(Debug)]
 pub struct VariableDeclaration {
     span: Arc<Span>,
-    name: Arc<Identifier>,
+    mutability: Mutability,
+    keyword_span: Arc<Span>,
+    mut_keyword_span: Option<Arc<Span>>,
+    pattern: Arc<Pattern>,
+    type_annotation: Option<Arc<TypeExpression>>,
     expression: Arc<Expression>,
-    pre_name_whitespace: Arc<Whitespace>,
+    pre_mut_keyword_whitespace: Option<Arc<Whitespace>>,
+    pre_pattern_whitespace: Arc<Whitespace>,
+    pre_colon_whitespace: Option<Arc<Whitespace>>,
+    pre_type_whitespace: Option<Arc<Whitespace>>,
     pre_assign_operator_whitespace: Arc<Whitespace>,
     pre_expression_whitespace: Arc<Whitespace>,
 }
@@ -29,16 +46,52 @@ pub struct VariableDeclaration {
 impl VariableDeclaration {
     // GETTERS ----------------------------------------------------------------
 
-    pub fn name(&self) -> &Arc<Identifier> {
-        &self.name
+    pub fn mutability(&self) -> Mutability {
+        self.mutability
+    }
+
+    /// The span of the `let` or `const` keyword.
+    pub fn keyword_span(&self) -> &Arc<Span> {
+        &self.keyword_span
+    }
+
+    pub fn mut_keyword_span(&self) -> &Option<Arc<Span>> {
+        &self.mut_keyword_span
+    }
+
+    /// The pattern that binds the variables, e.g. `a` or `(a, b)`.
+    pub fn pattern(&self) -> &Arc<Pattern> {
+        &self.pattern
+    }
+
+    pub fn type_annotation(&self) -> &Option<Arc<TypeExpression>> {
+        &self.type_annotation
     }
 
     pub fn expression(&self) -> &Arc<Expression> {
         &self.expression
     }
 
-    pub fn pre_name_whitespace(&self) -> &Arc<Whitespace> {
-        &self.pre_name_whitespace
+    /// Whether the expression must be evaluated at compile time or not, i.e. whether
+    /// the declaration is a constant.
+    pub fn is_compile_time(&self) -> bool {
+        self.mutability == Mutability::Constant
+    }
+
+    pub fn pre_mut_keyword_whitespace(&self) -> &Option<Arc<Whitespace>> {
+        &self.pre_mut_keyword_whitespace
+    }
+
+    pub fn pre_pattern_whitespace(&self) -> &Arc<Whitespace> {
+        &self.pre_pattern_whitespace
+    }
+
+    pub fn pre_colon_whitespace(&self) -> &Option<Arc<Whitespace>> {
+        &self.pre_colon_whitespace
+    }
+
+    pub fn pre_type_whitespace(&self) -> &Option<Arc<Whitespace>> {
+        &self.pre_type_whitespace
     }
 
     pub fn pre_assign_operator_whitespace(&self) -> &Arc<Whitespace> {
@@ -57,29 +110,84 @@ impl VariableDeclaration {
         context: &mut ParserContext,
     ) -> ParserResult<VariableDeclaration> {
         cursor_manager(reader, |reader, init_cursor| {
-            if !Identifier::parse_keyword(reader, context, KEYWORD) {
-                return Err(ParserResultError::NotFound);
+            if Identifier::parse_keyword(reader, context, MUT_KEYWORD) {
+                Self::report_misplaced_modifier(
+                    reader,
+                    context,
+                    init_cursor,
+                    format!(
+                        "The '{}' modifier must be placed after the '{}' keyword",
+                        MUT_KEYWORD, LET_KEYWORD
+                    ),
+                );
+
+                return Err(ParserResultError::Error);
             }
 
-            let pre_name_whitespace = Whitespace::parse_multiline_or_default(reader, context);
+            let mutability = if Identifier::parse_keyword(reader, context, LET_KEYWORD) {
+                Mutability::Immutable
+            } else if Identifier::parse_keyword(reader, context, CONST_KEYWORD) {
+                Mutability::Constant
+            } else {
+                return Err(ParserResultError::NotFound);
+            };
+
+            let keyword_span = Arc::new(reader.substring_to_current(init_cursor));
 
-            let name = match Identifier::parse(reader, context) {
+            // Modifiers.
+            let pre_mut_keyword_cursor = reader.save_cursor();
+            let pre_mut_keyword_whitespace =
+                Whitespace::parse_multiline_or_default(reader, context);
+            let mut_keyword_cursor = reader.save_cursor();
+
+            let (mutability, mut_keyword_span, pre_mut_keyword_whitespace) =
+                if Identifier::parse_keyword(reader, context, MUT_KEYWORD) {
+                    if mutability == Mutability::Constant {
+                        Self::report_misplaced_modifier(
+                            reader,
+                            context,
+                            &mut_keyword_cursor,
+                            "Constants cannot be mutable".to_string(),
+                        );
+
+                        return Err(ParserResultError::Error);
+                    }
+
+                    (
+                        Mutability::Mutable,
+                        Some(Arc::new(reader.substring_to_current(&mut_keyword_cursor))),
+                        Some(Arc::new(pre_mut_keyword_whitespace)),
+                    )
+                } else {
+                    reader.restore(pre_mut_keyword_cursor);
+                    (mutability, None, None)
+                };
+
+            let pre_pattern_whitespace = Whitespace::parse_multiline_or_default(reader, context);
+
+            Self::check_extra_modifiers(reader, context, mutability)?;
+
+            let pattern = match Pattern::parse(reader, context) {
                 Ok(v) => v,
-                Err(_) => {
+                Err(ParserResultError::Error) => return Err(ParserResultError::Error),
+                Err(ParserResultError::NotFound) => {
                     context.add_message(generate_error_log(
-                        ParserError::MissingNameInVariableDeclaration,
-                        "The variable name is missing".to_string(),
+                        ParserError::MissingPatternInVariableDeclaration,
+                        "The variable name or pattern is missing".to_string(),
                         |log| {
                             generate_source_code(log, &reader, |doc| {
                                 doc.highlight_section(
                                     init_cursor.byte_offset()
-                                        ..pre_name_whitespace.span().start_cursor().byte_offset(),
+                                        ..pre_pattern_whitespace
+                                            .span()
+                                            .start_cursor()
+                                            .byte_offset(),
                                     None,
                                     Some(Color::Magenta),
                                 )
                                 .highlight_cursor_str(
-                                    pre_name_whitespace.span().start_cursor().byte_offset(),
-                                    Some("Insert an identifier here"),
+                                    pre_pattern_whitespace.span().start_cursor().byte_offset(),
+                                    Some("Insert an identifier or a pattern here"),
                                     None,
                                 )
                             })
@@ -90,6 +198,56 @@ impl VariableDeclaration {
                 }
             };
 
+            // Type annotation.
+            let pre_colon_cursor = reader.save_cursor();
+            let pre_colon_whitespace = Whitespace::parse_multiline_or_default(reader, context);
+
+            let (type_annotation, pre_colon_whitespace, pre_type_whitespace) = if reader
+                .read(TYPE_SEPARATOR)
+            {
+                let pre_type_whitespace = Whitespace::parse_multiline_or_default(reader, context);
+
+                let type_annotation = match TypeExpression::parse(reader, context) {
+                    Ok(v) => v,
+                    Err(ParserResultError::Error) => return Err(ParserResultError::Error),
+                    Err(ParserResultError::NotFound) => {
+                        context.add_message(generate_error_log(
+                            ParserError::MissingTypeInVariableDeclaration,
+                            "A type is expected after the colon".to_string(),
+                            |log| {
+                                generate_source_code(log, reader, |doc| {
+                                    doc.highlight_section(
+                                        init_cursor.byte_offset()
+                                            ..pre_type_whitespace
+                                                .span()
+                                                .start_cursor()
+                                                .byte_offset(),
+                                        None,
+                                        Some(Color::Magenta),
+                                    )
+                                    .highlight_cursor_str(
+                                        pre_type_whitespace.span().start_cursor().byte_offset(),
+                                        Some("Insert a type here"),
+                                        None,
+                                    )
+                                })
+                            },
+                        ));
+
+                        return Err(ParserResultError::Error);
+                    }
+                };
+
+                (
+                    Some(Arc::new(type_annotation)),
+                    Some(Arc::new(pre_colon_whitespace)),
+                    Some(Arc::new(pre_type_whitespace)),
+                )
+            } else {
+                reader.restore(pre_colon_cursor);
+                (None, None, None)
+            };
+
             let pre_assign_operator_whitespace =
                 Whitespace::parse_multiline_or_default(reader, context);
 
@@ -131,7 +289,8 @@ impl VariableDeclaration {
 
             let expression = match Expression::parse(reader, context) {
                 Ok(v) => v,
-                Err(_) => {
+                Err(ParserResultError::Error) => return Err(ParserResultError::Error),
+                Err(ParserResultError::NotFound) => {
                     context.add_message(generate_error_log(
                         ParserError::MissingExpressionInVariableDeclaration,
                         "An expression is expected after the assign operator".to_string(),
@@ -162,17 +321,109 @@ impl VariableDeclaration {
                 }
             };
 
+            for binding in pattern.bindings() {
+                context.declare(
+                    binding.content().to_string(),
+                    Binding::new(binding.span().clone(), mutability),
+                );
+            }
+
             let span = Arc::new(reader.substring_to_current(&init_cursor));
             Ok(VariableDeclaration {
                 span,
-                name: Arc::new(name),
+                mutability,
+                keyword_span,
+                mut_keyword_span,
+                pattern: Arc::new(pattern),
+                type_annotation,
                 expression: Arc::new(expression),
-                pre_name_whitespace: Arc::new(pre_name_whitespace),
+                pre_mut_keyword_whitespace,
+                pre_pattern_whitespace: Arc::new(pre_pattern_whitespace),
+                pre_colon_whitespace,
+                pre_type_whitespace,
                 pre_expression_whitespace: Arc::new(pre_expression_whitespace),
                 pre_assign_operator_whitespace: Arc::new(pre_assign_operator_whitespace),
             })
         })
     }
+
+    /// Reports the modifiers placed between the keywords and the name that are
+    /// either repeated, e.g. `let mut mut a`, or not allowed there, e.g. `let const a`.
+    fn check_extra_modifiers(
+        reader: &mut Reader,
+        context: &mut ParserContext,
+        mutability: Mutability,
+    ) -> ParserResult<()> {
+        let modifier_cursor = reader.save_cursor();
+
+        // A first 'mut' would have already been read as the modifier.
+        if Identifier::parse_keyword(reader, context, MUT_KEYWORD) {
+            Self::report_duplicated_modifier(reader, context, &modifier_cursor, MUT_KEYWORD);
+            return Err(ParserResultError::Error);
+        }
+
+        if Identifier::parse_keyword(reader, context, CONST_KEYWORD) {
+            if mutability == Mutability::Constant {
+                Self::report_duplicated_modifier(reader, context, &modifier_cursor, CONST_KEYWORD);
+            } else {
+                Self::report_misplaced_modifier(
+                    reader,
+                    context,
+                    &modifier_cursor,
+                    format!(
+                        "The '{}' keyword cannot be combined with '{}'",
+                        CONST_KEYWORD, LET_KEYWORD
+                    ),
+                );
+            }
+
+            return Err(ParserResultError::Error);
+        }
+
+        Ok(())
+    }
+
+    fn report_duplicated_modifier(
+        reader: &Reader,
+        context: &mut ParserContext,
+        modifier_cursor: &Cursor,
+        modifier: &str,
+    ) {
+        context.add_message(generate_error_log(
+            ParserError::DuplicatedModifierInVariableDeclaration,
+            format!("The '{}' modifier is duplicated", modifier),
+            |log| {
+                generate_source_code(log, reader, |doc| {
+                    doc.highlight_section_str(
+                        modifier_cursor.byte_offset()..reader.byte_offset(),
+                        Some("Remove this modifier"),
+                        Some(Color::Magenta),
+                    )
+                })
+            },
+        ));
+    }
+
+    fn report_misplaced_modifier(
+        reader: &Reader,
+        context: &mut ParserContext,
+        modifier_cursor: &Cursor,
+        message: String,
+    ) {
+        context.add_message(generate_error_log(
+            ParserError::MisplacedModifierInVariableDeclaration,
+            message,
+            |log| {
+                generate_source_code(log, reader, |doc| {
+                    doc.highlight_section_str(
+                        modifier_cursor.byte_offset()..reader.byte_offset(),
+                        Some("Remove this modifier"),
+                        Some(Color::Magenta),
+                    )
+                })
+            },
+        ));
+    }
 }
 
 impl ParserNode for VariableDeclaration {
@@ -200,7 +451,11 @@ mod tests {
         let declaration =
             VariableDeclaration::parse(&mut reader, &mut context).expect("The parser must succeed");
 
-        assert_eq!(declaration.name.content(), "test", "The name is incorrect");
+        assert_eq!(
+            declaration.pattern.content(),
+            "test",
+            "The name is incorrect"
+        );
         if let Expression::VariableAccess(identifier) = declaration.expression.as_ref() {
             assert_eq!(identifier.content(), "a", "The literal access is incorrect");
         } else {
@@ -213,7 +468,11 @@ mod tests {
         let declaration =
             VariableDeclaration::parse(&mut reader, &mut context).expect("The parser must succeed");
 
-        assert_eq!(declaration.name.content(), "test", "The name is incorrect");
+        assert_eq!(
+            declaration.pattern.content(),
+            "test",
+            "The name is incorrect"
+        );
         if let Expression::VariableAccess(identifier) = declaration.expression.as_ref() {
             assert_eq!(identifier.content(), "a", "The literal access is incorrect");
         } else {
@@ -221,6 +480,167 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_parse_modifiers() {
+        for (text, mutability, keyword, mut_keyword) in &[
+            ("let a = 1", Mutability::Immutable, "let", None),
+            ("let  mut\n  a = 1", Mutability::Mutable, "let", Some("mut")),
+            ("const A = 1", Mutability::Constant, "const", None),
+        ] {
+            let mut reader = Reader::from_str(text);
+            let mut context = ParserContext::default();
+            let declaration = VariableDeclaration::parse(&mut reader, &mut context)
+                .expect("The parser must succeed");
+
+            assert_eq!(declaration.content(), *text, "The content is incorrect");
+            assert_eq!(
+                declaration.mutability, *mutability,
+                "The mutability is incorrect"
+            );
+            assert_eq!(
+                declaration.keyword_span.content(),
+                *keyword,
+                "The keyword_span is incorrect"
+            );
+            assert_eq!(
+                declaration.mut_keyword_span.as_ref().map(|v| v.content()),
+                *mut_keyword,
+                "The mut_keyword_span is incorrect"
+            );
+            assert_eq!(
+                declaration.is_compile_time(),
+                *mutability == Mutability::Constant,
+                "The is_compile_time is incorrect"
+            );
+            assert_eq!(
+                context
+                    .find_binding(declaration.pattern.content())
+                    .map(|v| v.mutability()),
+                Some(*mutability),
+                "The binding is incorrect"
+            );
+        }
+
+        let mut reader = Reader::from_str("let  mut\n  a = 1");
+        let mut context = ParserContext::default();
+        let declaration =
+            VariableDeclaration::parse(&mut reader, &mut context).expect("The parser must succeed");
+
+        assert_eq!(
+            declaration
+                .pre_mut_keyword_whitespace
+                .as_ref()
+                .map(|v| v.content()),
+            Some("  "),
+            "The pre_mut_keyword_whitespace is incorrect"
+        );
+        assert_eq!(
+            declaration.pre_pattern_whitespace.content(),
+            "\n  ",
+            "The pre_pattern_whitespace is incorrect"
+        );
+    }
+
+    #[test]
+    fn test_parse_patterns() {
+        for (text, bindings) in &[
+            ("let (a, b) = pair", vec!["a", "b"]),
+            ("let [head, ...rest] = list", vec!["head", "rest"]),
+            ("let { x, y } = point", vec!["x", "y"]),
+            ("let mut ({ x: a }, _) = value", vec!["a"]),
+            ("let [a, b]: List<Int> = list", vec!["a", "b"]),
+        ] {
+            let mut reader = Reader::from_str(text);
+            let mut context = ParserContext::default();
+            let declaration = VariableDeclaration::parse(&mut reader, &mut context)
+                .expect("The parser must succeed");
+
+            assert_eq!(declaration.content(), *text, "The content is incorrect");
+            assert_eq!(
+                declaration
+                    .pattern
+                    .bindings()
+                    .iter()
+                    .map(|v| v.content())
+                    .collect::<Vec<_>>(),
+                *bindings,
+                "The bindings are incorrect"
+            );
+
+            for binding in bindings {
+                assert_eq!(
+                    context.find_binding(binding).map(|v| v.mutability()),
+                    Some(declaration.mutability),
+                    "The binding {} is not declared",
+                    binding
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_parse_type_annotation() {
+        for (text, type_annotation) in &[
+            ("let a: Int = 1", "Int"),
+            ("let mut xs : List<Int> = []", "List<Int>"),
+            ("const F: (Int) -> Int? = (a) -> a", "(Int) -> Int?"),
+        ] {
+            let mut reader = Reader::from_str(text);
+            let mut context = ParserContext::default();
+            let declaration = VariableDeclaration::parse(&mut reader, &mut context)
+                .expect("The parser must succeed");
+
+            assert_eq!(declaration.content(), *text, "The content is incorrect");
+            assert_eq!(
+                declaration.type_annotation.as_ref().map(|v| v.content()),
+                Some(*type_annotation),
+                "The type_annotation is incorrect"
+            );
+        }
+
+        let mut reader = Reader::from_str("let a \n :\n  Int = 1");
+        let mut context = ParserContext::default();
+        let declaration =
+            VariableDeclaration::parse(&mut reader, &mut context).expect("The parser must succeed");
+
+        assert_eq!(
+            declaration
+                .pre_colon_whitespace
+                .as_ref()
+                .map(|v| v.content()),
+            Some(" \n "),
+            "The pre_colon_whitespace is incorrect"
+        );
+        assert_eq!(
+            declaration
+                .pre_type_whitespace
+                .as_ref()
+                .map(|v| v.content()),
+            Some("\n  "),
+            "The pre_type_whitespace is incorrect"
+        );
+        assert_eq!(
+            declaration.pre_assign_operator_whitespace.content(),
+            " ",
+            "The pre_assign_operator_whitespace is incorrect"
+        );
+
+        // Without annotation.
+        let mut reader = Reader::from_str("let a = 1");
+        let mut context = ParserContext::default();
+        let declaration =
+            VariableDeclaration::parse(&mut reader, &mut context).expect("The parser must succeed");
+
+        assert!(
+            declaration.type_annotation.is_none(),
+            "The type_annotation must be empty"
+        );
+        assert!(
+            declaration.pre_colon_whitespace.is_none(),
+            "The pre_colon_whitespace must be empty"
+        );
+    }
+
     #[test]
     fn test_parse_err_not_found() {
         let mut reader = Reader::from_str("-");
@@ -232,17 +652,19 @@ mod tests {
     }
 
     #[test]
-    fn test_parse_err_missing_variable_name() {
-        let mut reader = Reader::from_str("let");
-        let mut context = ParserContext::default();
-        let error = VariableDeclaration::parse(&mut reader, &mut context)
-            .expect_err("The parser must not succeed");
-
-        assert_error(
-            &context,
-            &error,
-            ParserError::MissingNameInVariableDeclaration,
-        );
+    fn test_parse_err_missing_pattern() {
+        for text in &["let", "let let = 1", "let return = 1"] {
+            let mut reader = Reader::from_str(text);
+            let mut context = ParserContext::default();
+            let error = VariableDeclaration::parse(&mut reader, &mut context)
+                .expect_err("The parser must not succeed");
+
+            assert_error(
+                &context,
+                &error,
+                ParserError::MissingPatternInVariableDeclaration,
+            );
+        }
     }
 
     #[test]
@@ -272,4 +694,81 @@ mod tests {
             ParserError::MissingExpressionInVariableDeclaration,
         );
     }
+
+    #[test]
+    fn test_parse_err_duplicated_modifier() {
+        for text in &["let mut mut a = 1", "const const A = 1"] {
+            let mut reader = Reader::from_str(text);
+            let mut context = ParserContext::default();
+            let error = VariableDeclaration::parse(&mut reader, &mut context)
+                .expect_err("The parser must not succeed");
+
+            assert_error(
+                &context,
+                &error,
+                ParserError::DuplicatedModifierInVariableDeclaration,
+            );
+        }
+    }
+
+    #[test]
+    fn test_parse_err_misplaced_modifier() {
+        for text in &[
+            "mut a = 1",
+            "const mut A = 1",
+            "let const a = 1",
+            "let mut const a = 1",
+        ] {
+            let mut reader = Reader::from_str(text);
+            let mut context = ParserContext::default();
+            let error = VariableDeclaration::parse(&mut reader, &mut context)
+                .expect_err("The parser must not succeed");
+
+            assert_error(
+                &context,
+                &error,
+                ParserError::MisplacedModifierInVariableDeclaration,
+            );
+        }
+    }
+
+    #[test]
+    fn test_parse_err_missing_type() {
+        for text in &["let a: = 1", "let a:"] {
+            let mut reader = Reader::from_str(text);
+            let mut context = ParserContext::default();
+            let error = VariableDeclaration::parse(&mut reader, &mut context)
+                .expect_err("The parser must not succeed");
+
+            assert_error(
+                &context,
+                &error,
+                ParserError::MissingTypeInVariableDeclaration,
+            );
+        }
+    }
+
+    #[test]
+    fn test_parse_err_duplicated_binding() {
+        for text in &["let (a, a) = pair", "let { x, y: x } = point"] {
+            let mut reader = Reader::from_str(text);
+            let mut context = ParserContext::default();
+            let error = VariableDeclaration::parse(&mut reader, &mut context)
+                .expect_err("The parser must not succeed");
+
+            assert_error(&context, &error, ParserError::DuplicatedBindingInPattern);
+        }
+    }
+
+    #[test]
+    fn test_parse_err_misplaced_rest() {
+        for text in &["let [...rest, last] = list", "let ...rest = list"] {
+            let mut reader = Reader::from_str(text);
+            let mut context = ParserContext::default();
+            let error = VariableDeclaration::parse(&mut reader, &mut context)
+                .expect_err("The parser must not succeed");
+
+            assert_error(&context, &error, ParserError::MisplacedRestPattern);
+        }
+    }
 }
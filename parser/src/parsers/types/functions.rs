@@ -0,0 +1,216 @@
+use std::sync::Arc;
+
+use doclog::Color;
+
+use crate::context::ParserContext;
+use crate::io::{Reader, Span};
+use crate::parsers::commons::sequences::{Sequence, SequenceElement};
+use crate::parsers::commons::whitespaces::Whitespace;
+use crate::parsers::expressions::lambdas::ARROW_TOKEN;
+use crate::parsers::expressions::tuples::TUPLE_DEFINITION;
+use crate::parsers::types::{TupleType, TypeExpression};
+use crate::parsers::utils::{generate_error_log, generate_source_code};
+use crate::parsers::{ParserResult, ParserResultError};
+use crate::{ParserError, ParserNode};
+
+/// The type of a function or lambda, e.g. `(Int, Str) -> Bool`.
+///
+/// The return type extends as far as possible, so `(Int) -> Int?` returns an optional
+/// while `((Int) -> Int)?` is an optional function.
+#[derive(Debug)]
+pub struct FunctionType {
+    span: Arc<Span>,
+    parameters: Sequence<TypeExpression>,
+    return_type: Arc<TypeExpression>,
+    pre_arrow_whitespace: Arc<Whitespace>,
+    pre_return_type_whitespace: Arc<Whitespace>,
+}
+
+impl FunctionType {
+    // GETTERS ----------------------------------------------------------------
+
+    /// The types of the parameters with their surrounding whitespaces.
+    pub fn parameters(&self) -> &Vec<SequenceElement<TypeExpression>> {
+        self.parameters.elements()
+    }
+
+    pub fn parameter_sequence(&self) -> &Sequence<TypeExpression> {
+        &self.parameters
+    }
+
+    pub fn return_type(&self) -> &Arc<TypeExpression> {
+        &self.return_type
+    }
+
+    pub fn pre_arrow_whitespace(&self) -> &Arc<Whitespace> {
+        &self.pre_arrow_whitespace
+    }
+
+    pub fn pre_return_type_whitespace(&self) -> &Arc<Whitespace> {
+        &self.pre_return_type_whitespace
+    }
+
+    // STATIC METHODS ---------------------------------------------------------
+
+    /// Parses a function type or, if the arrow is missing after the parentheses,
+    /// a tuple or a parenthesized type.
+    pub fn parse_or_tuple(
+        reader: &mut Reader,
+        context: &mut ParserContext,
+    ) -> ParserResult<TypeExpression> {
+        let parameters =
+            Sequence::parse(reader, context, &TUPLE_DEFINITION, TypeExpression::parse)?;
+
+        let pre_arrow_cursor = reader.save_cursor();
+        let pre_arrow_whitespace = Whitespace::parse_inline_or_default(reader, context);
+
+        if !reader.read(ARROW_TOKEN) {
+            reader.restore(pre_arrow_cursor);
+            return Ok(TupleType::from_sequence(parameters));
+        }
+
+        let pre_return_type_whitespace = Whitespace::parse_multiline_or_default(reader, context);
+
+        let return_type = match TypeExpression::parse(reader, context) {
+            Ok(v) => v,
+            Err(ParserResultError::Error) => return Err(ParserResultError::Error),
+            Err(ParserResultError::NotFound) => {
+                context.add_message(generate_error_log(
+                    ParserError::MissingReturnTypeInFunctionType,
+                    "A return type is expected after the arrow".to_string(),
+                    |log| {
+                        generate_source_code(log, reader, |doc| {
+                            doc.highlight_section(
+                                parameters.span().start_cursor().byte_offset()
+                                    ..pre_return_type_whitespace
+                                        .span()
+                                        .start_cursor()
+                                        .byte_offset(),
+                                None,
+                                Some(Color::Magenta),
+                            )
+                            .highlight_cursor_str(
+                                pre_return_type_whitespace
+                                    .span()
+                                    .start_cursor()
+                                    .byte_offset(),
+                                Some("Insert a type here"),
+                                None,
+                            )
+                        })
+                    },
+                ));
+
+                return Err(ParserResultError::Error);
+            }
+        };
+
+        Ok(TypeExpression::Function(Arc::new(FunctionType {
+            span: Arc::new(reader.substring_to_current(parameters.span().start_cursor())),
+            parameters,
+            return_type: Arc::new(return_type),
+            pre_arrow_whitespace: Arc::new(pre_arrow_whitespace),
+            pre_return_type_whitespace: Arc::new(pre_return_type_whitespace),
+        })))
+    }
+}
+
+impl ParserNode for FunctionType {
+    fn span(&self) -> &Arc<Span> {
+        &self.span
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use crate::test::assert_error;
+
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let mut reader = Reader::from_str("(Int, Str) ->\n  Bool rest");
+        let mut context = ParserContext::default();
+        let type_expression = FunctionType::parse_or_tuple(&mut reader, &mut context)
+            .expect("The parser must succeed");
+
+        if let TypeExpression::Function(function) = type_expression {
+            assert_eq!(
+                function.content(),
+                "(Int, Str) ->\n  Bool",
+                "The content is incorrect"
+            );
+            assert_eq!(
+                function
+                    .parameters()
+                    .iter()
+                    .map(|v| v.node().content())
+                    .collect::<Vec<_>>(),
+                vec!["Int", "Str"],
+                "The parameters are incorrect"
+            );
+            assert_eq!(
+                function.return_type.content(),
+                "Bool",
+                "The return_type is incorrect"
+            );
+            assert_eq!(
+                function.pre_arrow_whitespace.content(),
+                " ",
+                "The pre_arrow_whitespace is incorrect"
+            );
+            assert_eq!(
+                function.pre_return_type_whitespace.content(),
+                "\n  ",
+                "The pre_return_type_whitespace is incorrect"
+            );
+        } else {
+            panic!("The type is incorrect");
+        }
+    }
+
+    #[test]
+    fn test_parse_nested() {
+        for (text, return_type) in &[
+            ("() -> Nil", "Nil"),
+            ("(Int) -> (Int) -> Int", "(Int) -> Int"),
+            ("((Int) -> Int) -> Int?", "Int?"),
+        ] {
+            let mut reader = Reader::from_str(text);
+            let mut context = ParserContext::default();
+            let type_expression = FunctionType::parse_or_tuple(&mut reader, &mut context)
+                .expect("The parser must succeed");
+
+            if let TypeExpression::Function(function) = type_expression {
+                assert_eq!(function.content(), *text, "The content is incorrect");
+                assert_eq!(
+                    function.return_type.content(),
+                    *return_type,
+                    "The return_type is incorrect"
+                );
+            } else {
+                panic!("The type is incorrect");
+            }
+        }
+    }
+
+    #[test]
+    fn test_parse_err_missing_return_type() {
+        for text in &["(Int) ->", "(Int) -> +"] {
+            let mut reader = Reader::from_str(text);
+            let mut context = ParserContext::default();
+            let error = FunctionType::parse_or_tuple(&mut reader, &mut context)
+                .expect_err("The parser must not succeed");
+
+            assert_error(
+                &context,
+                &error,
+                ParserError::MissingReturnTypeInFunctionType,
+            );
+        }
+    }
+}
@@ -0,0 +1,141 @@
+use std::sync::Arc;
+
+pub use functions::*;
+pub use named::*;
+pub use optionals::*;
+pub use tuples::*;
+
+use crate::context::ParserContext;
+use crate::io::{Reader, Span};
+use crate::parsers::{ParserResult, ParserResultError};
+use crate::ParserNode;
+
+mod functions;
+mod named;
+mod optionals;
+mod tuples;
+
+/// A type in the Mosfet language, e.g. `Int`, `List<Int>` or `(Int, Str) -> Bool`.
+#[derive(Debug)]
+pub enum TypeExpression {
+    Named(Arc<NamedType>),
+    Parenthesized(Arc<ParenthesizedType>),
+    Tuple(Arc<TupleType>),
+    Function(Arc<FunctionType>),
+    Optional(Arc<OptionalType>),
+}
+
+impl TypeExpression {
+    // STATIC METHODS ---------------------------------------------------------
+
+    /// Parses a type.
+    pub fn parse(reader: &mut Reader, context: &mut ParserContext) -> ParserResult<TypeExpression> {
+        let type_expression = Self::parse_primary(reader, context)?;
+
+        if reader.continues_with(OPTIONAL_TOKEN) {
+            let node = OptionalType::parse(reader, context, type_expression)?;
+            return Ok(TypeExpression::Optional(Arc::new(node)));
+        }
+
+        Ok(type_expression)
+    }
+
+    /// Parses a type that is not optional.
+    fn parse_primary(
+        reader: &mut Reader,
+        context: &mut ParserContext,
+    ) -> ParserResult<TypeExpression> {
+        match NamedType::parse(reader, context) {
+            Ok(node) => return Ok(TypeExpression::Named(Arc::new(node))),
+            Err(ParserResultError::NotFound) => { /* Ignore because not found */ }
+            Err(ParserResultError::Error) => return Err(ParserResultError::Error),
+        }
+
+        match FunctionType::parse_or_tuple(reader, context) {
+            Ok(node) => return Ok(node),
+            Err(ParserResultError::NotFound) => { /* Ignore because not found */ }
+            Err(ParserResultError::Error) => return Err(ParserResultError::Error),
+        }
+
+        Err(ParserResultError::NotFound)
+    }
+}
+
+impl ParserNode for TypeExpression {
+    fn span(&self) -> &Arc<Span> {
+        match self {
+            TypeExpression::Named(n) => n.span(),
+            TypeExpression::Parenthesized(n) => n.span(),
+            TypeExpression::Tuple(n) => n.span(),
+            TypeExpression::Function(n) => n.span(),
+            TypeExpression::Optional(n) => n.span(),
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use crate::test::assert_not_found;
+
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        for text in &[
+            "Int",
+            "List<Int>",
+            "Map<Str, List<Int?>>",
+            "(Int, Str)",
+            "(Int)",
+            "(Int) -> Bool",
+            "Int?",
+            "((Int) -> Bool)?",
+        ] {
+            let mut reader = Reader::from_str(text);
+            let mut context = ParserContext::default();
+            let type_expression =
+                TypeExpression::parse(&mut reader, &mut context).expect("The parser must succeed");
+
+            assert_eq!(type_expression.content(), *text, "The content is incorrect");
+        }
+    }
+
+    #[test]
+    fn test_parse_optional() {
+        for (text, is_optional) in &[
+            ("Int?", true),
+            ("List<Int>?", true),
+            ("(Int, Str)?", true),
+            ("(Int) -> Bool?", false),
+            ("Int ?", false),
+        ] {
+            let mut reader = Reader::from_str(text);
+            let mut context = ParserContext::default();
+            let type_expression =
+                TypeExpression::parse(&mut reader, &mut context).expect("The parser must succeed");
+
+            assert_eq!(
+                matches!(type_expression, TypeExpression::Optional(_)),
+                *is_optional,
+                "The type is incorrect for: {}",
+                text
+            );
+        }
+    }
+
+    #[test]
+    fn test_parse_err_not_found() {
+        for text in &["+", "1", "?"] {
+            let mut reader = Reader::from_str(text);
+            let mut context = ParserContext::default();
+            let error = TypeExpression::parse(&mut reader, &mut context)
+                .expect_err("The parser must not succeed");
+
+            assert_not_found(&context, &error, 0);
+        }
+    }
+}
@@ -0,0 +1,214 @@
+use std::sync::Arc;
+
+use doclog::Color;
+
+use crate::context::ParserContext;
+use crate::io::{Reader, Span};
+use crate::parsers::commons::identifier::Identifier;
+use crate::parsers::commons::sequences::{Sequence, SequenceDefinition, SequenceElement};
+use crate::parsers::types::TypeExpression;
+use crate::parsers::utils::{cursor_manager, generate_error_log, generate_source_code};
+use crate::parsers::{ParserResult, ParserResultError};
+use crate::{ParserError, ParserNode};
+
+pub static GENERIC_ARGUMENTS_DEFINITION: SequenceDefinition = SequenceDefinition {
+    open_token: "<",
+    close_token: ">",
+    name: "generic argument list",
+    missing_separator_error: ParserError::MissingCommaInGenericArguments,
+    without_end_token_error: ParserError::GenericArgumentsWithoutEndToken,
+};
+
+/// A type referenced by its name with optional generic arguments, e.g. `Int` or `Map<Str, Int>`.
+///
+/// The generic argument list must be placed just after the name, without whitespaces.
+#[derive(Debug)]
+pub struct NamedType {
+    span: Arc<Span>,
+    name: Arc<Identifier>,
+    arguments: Option<Sequence<TypeExpression>>,
+}
+
+impl NamedType {
+    // GETTERS ----------------------------------------------------------------
+
+    pub fn name(&self) -> &Arc<Identifier> {
+        &self.name
+    }
+
+    /// The generic arguments of the type with their surrounding whitespaces.
+    pub fn arguments(&self) -> Option<&Vec<SequenceElement<TypeExpression>>> {
+        self.arguments.as_ref().map(|v| v.elements())
+    }
+
+    pub fn argument_sequence(&self) -> &Option<Sequence<TypeExpression>> {
+        &self.arguments
+    }
+
+    // STATIC METHODS ---------------------------------------------------------
+
+    /// Parses a `NamedType`.
+    pub fn parse(reader: &mut Reader, context: &mut ParserContext) -> ParserResult<NamedType> {
+        cursor_manager(reader, |reader, init_cursor| {
+            let name = Identifier::parse(reader, context)?;
+
+            let arguments = match Sequence::parse(
+                reader,
+                context,
+                &GENERIC_ARGUMENTS_DEFINITION,
+                TypeExpression::parse,
+            ) {
+                Ok(v) => Some(v),
+                Err(ParserResultError::NotFound) => None,
+                Err(ParserResultError::Error) => return Err(ParserResultError::Error),
+            };
+
+            if let Some(arguments) = &arguments {
+                if arguments.elements().is_empty() {
+                    context.add_message(generate_error_log(
+                        ParserError::EmptyGenericArguments,
+                        "The generic argument list requires at least one type".to_string(),
+                        |log| {
+                            generate_source_code(log, reader, |doc| {
+                                let arguments_span = arguments.span();
+
+                                doc.highlight_section(
+                                    init_cursor.byte_offset()
+                                        ..arguments_span.start_cursor().byte_offset(),
+                                    None,
+                                    Some(Color::Magenta),
+                                )
+                                .highlight_section_str(
+                                    arguments_span.start_cursor().byte_offset()
+                                        ..arguments_span.end_cursor().byte_offset(),
+                                    Some("Insert a type here or remove the list"),
+                                    None,
+                                )
+                            })
+                        },
+                    ));
+
+                    return Err(ParserResultError::Error);
+                }
+            }
+
+            let span = Arc::new(reader.substring_to_current(init_cursor));
+            Ok(NamedType {
+                span,
+                name: Arc::new(name),
+                arguments,
+            })
+        })
+    }
+}
+
+impl ParserNode for NamedType {
+    fn span(&self) -> &Arc<Span> {
+        &self.span
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use crate::test::{assert_error, assert_not_found};
+
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let mut reader = Reader::from_str("Int rest");
+        let mut context = ParserContext::default();
+        let named = NamedType::parse(&mut reader, &mut context).expect("The parser must succeed");
+
+        assert_eq!(named.content(), "Int", "The content is incorrect");
+        assert_eq!(named.name.content(), "Int", "The name is incorrect");
+        assert!(named.arguments.is_none(), "The arguments must be empty");
+    }
+
+    #[test]
+    fn test_parse_generic_arguments() {
+        for (text, arguments) in &[
+            ("List<Int>", vec!["Int"]),
+            ("Map< Str,\n  List<Int>, >", vec!["Str", "List<Int>"]),
+            ("Result<(Int, Str), Error?>", vec!["(Int, Str)", "Error?"]),
+        ] {
+            let mut reader = Reader::from_str(text);
+            let mut context = ParserContext::default();
+            let named =
+                NamedType::parse(&mut reader, &mut context).expect("The parser must succeed");
+
+            assert_eq!(named.content(), *text, "The content is incorrect");
+            assert_eq!(
+                named
+                    .arguments()
+                    .expect("The arguments are missing")
+                    .iter()
+                    .map(|v| v.node().content())
+                    .collect::<Vec<_>>(),
+                *arguments,
+                "The arguments are incorrect"
+            );
+        }
+
+        // The arguments must be placed just after the name.
+        let mut reader = Reader::from_str("List <Int>");
+        let mut context = ParserContext::default();
+        let named = NamedType::parse(&mut reader, &mut context).expect("The parser must succeed");
+
+        assert_eq!(named.content(), "List", "The content is incorrect");
+    }
+
+    #[test]
+    fn test_parse_err_not_found() {
+        let mut reader = Reader::from_str("(Int)");
+        let mut context = ParserContext::default();
+        let error =
+            NamedType::parse(&mut reader, &mut context).expect_err("The parser must not succeed");
+
+        assert_not_found(&context, &error, 0);
+    }
+
+    #[test]
+    fn test_parse_err_without_end_token() {
+        for text in &["List<Int", "Map<Str, Int"] {
+            let mut reader = Reader::from_str(text);
+            let mut context = ParserContext::default();
+            let error = NamedType::parse(&mut reader, &mut context)
+                .expect_err("The parser must not succeed");
+
+            assert_error(
+                &context,
+                &error,
+                ParserError::GenericArgumentsWithoutEndToken,
+            );
+        }
+    }
+
+    #[test]
+    fn test_parse_err_missing_comma() {
+        let mut reader = Reader::from_str("Map<Str Int>");
+        let mut context = ParserContext::default();
+        let error =
+            NamedType::parse(&mut reader, &mut context).expect_err("The parser must not succeed");
+
+        assert_error(
+            &context,
+            &error,
+            ParserError::MissingCommaInGenericArguments,
+        );
+    }
+
+    #[test]
+    fn test_parse_err_empty_arguments() {
+        let mut reader = Reader::from_str("List<>");
+        let mut context = ParserContext::default();
+        let error =
+            NamedType::parse(&mut reader, &mut context).expect_err("The parser must not succeed");
+
+        assert_error(&context, &error, ParserError::EmptyGenericArguments);
+    }
+}
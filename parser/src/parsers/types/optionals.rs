@@ -0,0 +1,83 @@
+use std::sync::Arc;
+
+use crate::context::ParserContext;
+use crate::io::{Reader, Span};
+use crate::parsers::types::TypeExpression;
+use crate::parsers::{ParserResult, ParserResultError};
+use crate::ParserNode;
+
+pub static OPTIONAL_TOKEN: &str = "?";
+
+/// A type that also accepts `nil`, e.g. `Int?`.
+///
+/// The token must be placed just after the type, without whitespaces.
+#[derive(Debug)]
+pub struct OptionalType {
+    span: Arc<Span>,
+    type_expression: Arc<TypeExpression>,
+}
+
+impl OptionalType {
+    // GETTERS ----------------------------------------------------------------
+
+    pub fn type_expression(&self) -> &Arc<TypeExpression> {
+        &self.type_expression
+    }
+
+    // STATIC METHODS ---------------------------------------------------------
+
+    /// Parses the optional token of `type_expression`, which must be placed just before
+    /// the current position of the reader.
+    pub fn parse(
+        reader: &mut Reader,
+        _context: &mut ParserContext,
+        type_expression: TypeExpression,
+    ) -> ParserResult<OptionalType> {
+        if !reader.read(OPTIONAL_TOKEN) {
+            return Err(ParserResultError::NotFound);
+        }
+
+        Ok(OptionalType {
+            span: Arc::new(reader.substring_to_current(type_expression.span().start_cursor())),
+            type_expression: Arc::new(type_expression),
+        })
+    }
+}
+
+impl ParserNode for OptionalType {
+    fn span(&self) -> &Arc<Span> {
+        &self.span
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let mut reader = Reader::from_str("List<Int?>? rest");
+        let mut context = ParserContext::default();
+        let type_expression =
+            TypeExpression::parse(&mut reader, &mut context).expect("The parser must succeed");
+
+        if let TypeExpression::Optional(optional) = type_expression {
+            assert_eq!(
+                optional.content(),
+                "List<Int?>?",
+                "The content is incorrect"
+            );
+            assert_eq!(
+                optional.type_expression.content(),
+                "List<Int?>",
+                "The type_expression is incorrect"
+            );
+        } else {
+            panic!("The type is incorrect");
+        }
+    }
+}
@@ -0,0 +1,169 @@
+use std::sync::Arc;
+
+use crate::io::Span;
+use crate::parsers::commons::sequences::Sequence;
+use crate::parsers::commons::whitespaces::Whitespace;
+use crate::parsers::types::TypeExpression;
+use crate::ParserNode;
+
+/// A type enclosed between parentheses to group it, e.g. `((Int) -> Int)?`.
+#[derive(Debug)]
+pub struct ParenthesizedType {
+    span: Arc<Span>,
+    type_expression: Arc<TypeExpression>,
+    pre_type_whitespace: Arc<Whitespace>,
+    post_type_whitespace: Arc<Whitespace>,
+}
+
+impl ParenthesizedType {
+    // GETTERS ----------------------------------------------------------------
+
+    pub fn type_expression(&self) -> &Arc<TypeExpression> {
+        &self.type_expression
+    }
+
+    pub fn pre_type_whitespace(&self) -> &Arc<Whitespace> {
+        &self.pre_type_whitespace
+    }
+
+    pub fn post_type_whitespace(&self) -> &Arc<Whitespace> {
+        &self.post_type_whitespace
+    }
+}
+
+impl ParserNode for ParenthesizedType {
+    fn span(&self) -> &Arc<Span> {
+        &self.span
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+/// The type of a tuple, e.g. `(Int, Str)`.
+///
+/// Follows the same rules as `TupleExpression`, so a tuple with only one element
+/// requires a trailing comma, e.g. `(Int,)`.
+#[derive(Debug)]
+pub struct TupleType {
+    sequence: Sequence<TypeExpression>,
+}
+
+impl TupleType {
+    // GETTERS ----------------------------------------------------------------
+
+    pub fn sequence(&self) -> &Sequence<TypeExpression> {
+        &self.sequence
+    }
+
+    // STATIC METHODS ---------------------------------------------------------
+
+    /// Builds a tuple or a parenthesized type from an already parsed `sequence`,
+    /// depending on the number of elements and the trailing comma.
+    pub fn from_sequence(sequence: Sequence<TypeExpression>) -> TypeExpression {
+        if sequence.elements().len() == 1 && !sequence.has_trailing_separator() {
+            let element = &sequence.elements()[0];
+
+            return TypeExpression::Parenthesized(Arc::new(ParenthesizedType {
+                span: sequence.span().clone(),
+                type_expression: element.node().clone(),
+                pre_type_whitespace: element.pre_node_whitespace().clone(),
+                post_type_whitespace: element.post_node_whitespace().clone(),
+            }));
+        }
+
+        TypeExpression::Tuple(Arc::new(TupleType { sequence }))
+    }
+}
+
+impl ParserNode for TupleType {
+    fn span(&self) -> &Arc<Span> {
+        self.sequence.span()
+    }
+}
+
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+// ----------------------------------------------------------------------------
+
+#[cfg(test)]
+mod tests {
+    use crate::context::ParserContext;
+    use crate::io::Reader;
+    use crate::test::assert_error;
+    use crate::ParserError;
+
+    use super::*;
+
+    #[test]
+    fn test_parse_parenthesized() {
+        let mut reader = Reader::from_str("( Int )");
+        let mut context = ParserContext::default();
+        let type_expression =
+            TypeExpression::parse(&mut reader, &mut context).expect("The parser must succeed");
+
+        if let TypeExpression::Parenthesized(parenthesized) = type_expression {
+            assert_eq!(
+                parenthesized.content(),
+                "( Int )",
+                "The content is incorrect"
+            );
+            assert_eq!(
+                parenthesized.type_expression.content(),
+                "Int",
+                "The type_expression is incorrect"
+            );
+        } else {
+            panic!("The type is incorrect");
+        }
+    }
+
+    #[test]
+    fn test_parse_tuple() {
+        for (text, length) in &[
+            ("()", 0),
+            ("(Int,)", 1),
+            ("(Int, Str?)", 2),
+            ("(\n  (Int, Int),\n  List<Str>,\n  Bool,\n)", 3),
+        ] {
+            let mut reader = Reader::from_str(text);
+            let mut context = ParserContext::default();
+            let type_expression =
+                TypeExpression::parse(&mut reader, &mut context).expect("The parser must succeed");
+
+            if let TypeExpression::Tuple(tuple) = type_expression {
+                assert_eq!(tuple.content(), *text, "The content is incorrect");
+                assert_eq!(
+                    tuple.sequence.elements().len(),
+                    *length,
+                    "The elements length is incorrect"
+                );
+            } else {
+                panic!("The type is incorrect");
+            }
+        }
+    }
+
+    #[test]
+    fn test_parse_err_without_end_token() {
+        for text in &["(Int", "(Int, 1)"] {
+            let mut reader = Reader::from_str(text);
+            let mut context = ParserContext::default();
+            let error = TypeExpression::parse(&mut reader, &mut context)
+                .expect_err("The parser must not succeed");
+
+            assert_error(&context, &error, ParserError::ParenthesisWithoutEndToken);
+        }
+    }
+
+    #[test]
+    fn test_parse_err_missing_comma() {
+        let mut reader = Reader::from_str("(Int Str)");
+        let mut context = ParserContext::default();
+        let error = TypeExpression::parse(&mut reader, &mut context)
+            .expect_err("The parser must not succeed");
+
+        assert_error(&context, &error, ParserError::MissingCommaInTuple);
+    }
+}
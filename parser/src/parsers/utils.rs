@@ -1,11 +1,12 @@
 use std::sync::Arc;
 
 use doclog::blocks::DocumentBlock;
-use doclog::Log;
+use doclog::{Color, Log};
 
 use crate::constants::LOG_CODE_TITLE;
 use crate::constants::LOG_ERROR_ID_TITLE;
 use crate::constants::LOG_WARNING_ID_TITLE;
+use crate::context::ParserContext;
 use crate::io::{Cursor, Reader};
 use crate::parsers::{ParserResult, ParserResultError};
 use crate::ParserError;
@@ -71,3 +72,29 @@ where
         })
     })
 }
+
+/// Reports an `error` because a part of a node started at `init_cursor` is missing
+/// at the current position of the reader.
+pub fn report_missing(
+    reader: &Reader,
+    context: &mut ParserContext,
+    init_cursor: &Cursor,
+    error: ParserError,
+    message: &str,
+    hint: &str,
+) {
+    context.add_message(generate_error_log(error, message.to_string(), |log| {
+        generate_source_code(log, reader, |doc| {
+            doc.highlight_section(
+                init_cursor.byte_offset()..reader.byte_offset(),
+                None,
+                Some(Color::Magenta),
+            )
+            .highlight_cursor(
+                reader.byte_offset(),
+                Some(Arc::new(hint.to_string())),
+                None,
+            )
+        })
+    }));
+}
@@ -4,8 +4,13 @@ use doclog::Log;
 use crate::constants::LOG_ERROR_ID_TITLE;
 use crate::constants::LOG_WARNING_ID_TITLE;
 use crate::context::ParserContext;
+use crate::parsers::commons::sequences::SequenceElement;
+use crate::parsers::expressions::calls::Argument;
+use crate::parsers::expressions::unary::UnaryOperator;
+use crate::parsers::expressions::Expression;
 use crate::parsers::ParserResultError;
 use crate::ParserError;
+use crate::ParserNode;
 use crate::ParserWarning;
 
 pub fn assert_warning(context: &ParserContext, warning_type: ParserWarning) {
@@ -51,7 +56,11 @@ pub fn assert_error(context: &ParserContext, error: &ParserResultError, error_ty
 
     assert_eq!(messages.len(), 1, "The messages length is incorrect");
 
-    let indent_block = messages[0].blocks().last().unwrap();
+    assert_error_message(&messages[0], error_type);
+}
+
+pub fn assert_error_message(message: &Log, error_type: ParserError) {
+    let indent_block = message.blocks().last().unwrap();
     let eid_block = match indent_block {
         LogBlock::Indent(v) => {
             let log = v.get_log();
@@ -91,3 +100,74 @@ pub fn assert_not_found(context: &ParserContext, error: &ParserResultError, offs
     );
     assert_eq!(offset, 0, "The offset is incorrect");
 }
+
+/// Prints an expression wrapping every operation between parentheses to check its structure.
+pub fn expression_to_string(expression: &Expression) -> String {
+    match expression {
+        Expression::Binary(v) => format!(
+            "({} {} {})",
+            expression_to_string(v.left()),
+            v.operator().as_str(),
+            expression_to_string(v.right())
+        ),
+        Expression::Unary(v) => match v.operator() {
+            UnaryOperator::Not => format!("(not {})", expression_to_string(v.expression())),
+            operator => format!(
+                "({}{})",
+                operator.as_str(),
+                expression_to_string(v.expression())
+            ),
+        },
+        Expression::Parenthesized(v) => expression_to_string(v.expression()),
+        Expression::Call(v) => format!(
+            "({}({}))",
+            expression_to_string(v.callee()),
+            arguments_to_string(v.arguments())
+        ),
+        Expression::MemberAccess(v) => format!(
+            "({}.{})",
+            expression_to_string(v.object()),
+            v.member().content()
+        ),
+        Expression::MethodCall(v) => format!(
+            "({}.{}({}))",
+            expression_to_string(v.object()),
+            v.method().content(),
+            arguments_to_string(v.arguments())
+        ),
+        Expression::Index(v) => format!(
+            "({}[{}])",
+            expression_to_string(v.object()),
+            expression_to_string(v.index())
+        ),
+        Expression::Range(v) => format!(
+            "({}{}{})",
+            v.start()
+                .as_ref()
+                .map(|v| expression_to_string(v))
+                .unwrap_or_default(),
+            v.operator_str(),
+            v.end()
+                .as_ref()
+                .map(|v| expression_to_string(v))
+                .unwrap_or_default()
+        ),
+        _ => expression.content().to_string(),
+    }
+}
+
+fn arguments_to_string(arguments: &[SequenceElement<Argument>]) -> String {
+    let arguments: Vec<_> = arguments
+        .iter()
+        .map(|v| match v.node().as_ref() {
+            Argument::Positional(v) => expression_to_string(v),
+            Argument::Named(v) => format!(
+                "{}: {}",
+                v.name().content(),
+                expression_to_string(v.value())
+            ),
+        })
+        .collect();
+
+    arguments.join(", ")
+}
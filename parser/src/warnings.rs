@@ -3,4 +3,6 @@
 pub enum ParserWarning {
     NumberWithLeadingZeroes,
     NumberWithTrailingZeroes,
+    MapWithDuplicatedKey,
+    StatementWithoutEffect,
 }